@@ -6,8 +6,12 @@ mod benchmarks;
 criterion_main!(
     //without any options set
     benchmarks::default::benches,
+    //without colors
+    benchmarks::plain::benches,
     //different size options
     benchmarks::size::benches,
+    //averaging the color of a tile
+    benchmarks::average_color::benches,
     //using the outline algorithm
     benchmarks::outline::benches,
     //using the outline algorithm with hysteresis and double threshold
@@ -0,0 +1,35 @@
+use criterion::{criterion_group, Criterion};
+use image::Rgba;
+
+/// Benchmarks for averaging the color of a large tile.
+///
+/// This compares the scalar implementation with the SIMD one, which is only available
+/// when the `simd` feature is enabled.
+fn average_color_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("average color");
+
+    //a large tile, as it would be used for a high resolution image
+    let pixels: Vec<Rgba<u8>> = (0..256 * 256u32)
+        .map(|i| {
+            Rgba::<u8>::from([
+                (i % 256) as u8,
+                (i / 256 % 256) as u8,
+                (i * 7 % 256) as u8,
+                255,
+            ])
+        })
+        .collect();
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| artem::pixel::average_color_scalar(criterion::black_box(&pixels)))
+    });
+
+    #[cfg(feature = "simd")]
+    group.bench_function("simd", |b| {
+        b.iter(|| artem::pixel::average_color_simd(criterion::black_box(&pixels)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, average_color_benchmark);
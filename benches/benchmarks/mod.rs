@@ -1,7 +1,11 @@
 ///Benchmark for the default configuration.
 pub mod default;
+//Benchmark for the uncolored output
+pub mod plain;
 //Benchmark for different size arguments
 pub mod size;
+//scalar and simd version of averaging colors
+pub mod average_color;
 //outline version without hysteresis
 pub mod outline;
 //outline version with hysteresis
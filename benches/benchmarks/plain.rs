@@ -0,0 +1,46 @@
+use crate::benchmarks::util;
+use criterion::{criterion_group, Criterion};
+
+/// Benchmarks for the uncolored output.
+///
+/// This uses the plain file target, so no colors are added and each tile results in a single char.
+fn plain_options_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("plain options");
+
+    let mut options = artem::options::OptionBuilder::new();
+    options.target(artem::options::TargetType::File);
+
+    //use lower sample size for faster benchmarking
+    //it should still take long enough to see relevant changes in performance
+    group.sample_size(10);
+
+    //test on different resolutions
+
+    group.bench_function("low resolution", |b| {
+        b.iter_batched(
+            util::load_low_res_image,
+            |data| artem::convert(data, options.build()),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("normal resolution", |b| {
+        b.iter_batched(
+            util::load_normal_res_image,
+            |data| artem::convert(data, options.build()),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("high resolution", |b| {
+        b.iter_batched(
+            util::load_high_res_image,
+            |data| artem::convert(data, options.build()),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, plain_options_benchmark);
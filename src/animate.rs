@@ -0,0 +1,193 @@
+//! Generating shell scripts, which play converted animations in the terminal.
+
+use std::{fmt::Write, fs::File, time::Duration};
+
+use image::{codecs::gif::GifDecoder, AnimationDecoder, DynamicImage, Frame, ImageFormat};
+
+/// Read all frames of the gif file at the given path.
+///
+/// On failure, an error message and the exit code are returned.
+pub fn load_frames(path: &str) -> Result<Vec<Frame>, (String, i32)> {
+    let file = File::open(path).map_err(|err| (format!("Could not open {path}: {err}"), 66))?;
+    GifDecoder::new(file)
+        .and_then(|decoder| decoder.into_frames().collect_frames())
+        .map_err(|err| (format!("Could not read frames of {path}: {err}"), 65))
+}
+
+/// Read all frames of the gif image in the given bytes.
+///
+/// The `source` names where the bytes came from, it is only used in the error message.
+/// On failure, an error message and the exit code are returned.
+pub fn decode_frames(bytes: &[u8], source: &str) -> Result<Vec<Frame>, (String, i32)> {
+    GifDecoder::new(bytes)
+        .and_then(|decoder| decoder.into_frames().collect_frames())
+        .map_err(|err| (format!("Could not read frames of {source}: {err}"), 65))
+}
+
+/// Return the frame with the given index of the animated image.
+///
+/// Only gif images are supported.
+/// On failure, for example when the index is out of range, an error message and the exit code are returned.
+pub fn select_frame(bytes: &[u8], index: usize) -> Result<DynamicImage, (String, i32)> {
+    if image::guess_format(bytes).ok() != Some(ImageFormat::Gif) {
+        return Err((
+            "Selecting a frame is only supported for gif images".to_string(),
+            65,
+        ));
+    }
+
+    let frames = GifDecoder::new(bytes)
+        .and_then(|decoder| decoder.into_frames().collect_frames())
+        .map_err(|err| (format!("Could not read frames: {err}"), 65))?;
+    let count = frames.len();
+
+    match frames.into_iter().nth(index) {
+        Some(frame) => Ok(DynamicImage::ImageRgba8(frame.into_buffer())),
+        None => Err((
+            format!("Frame {index} is out of range, the image has {count} frames"),
+            65,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test_select_frame {
+    use super::*;
+
+    #[test]
+    fn frames_differ() {
+        let bytes = test_gif(&[(0, 100), (255, 100)]);
+        let first = select_frame(&bytes, 0).unwrap();
+        let second = select_frame(&bytes, 1).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(
+            &image::Rgba([255, 255, 255, 255]),
+            second.as_rgba8().unwrap().get_pixel(0, 0)
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let bytes = test_gif(&[(0, 100), (255, 100)]);
+        assert_eq!(
+            Err((
+                "Frame 2 is out of range, the image has 2 frames".to_string(),
+                65
+            )),
+            select_frame(&bytes, 2)
+        );
+    }
+
+    #[test]
+    fn not_a_gif() {
+        let bytes = std::fs::read("assets/images/standard_test_img.png").unwrap();
+        assert!(select_frame(&bytes, 1).is_err());
+    }
+}
+
+/// Create a shell script, which prints the frames in order and waits for the delay of each frame.
+///
+/// Before each frame, the cursor is moved to the top left of the terminal, so the frames overwrite each other.
+pub fn script(frames: &[(String, Duration)]) -> String {
+    let mut script = String::from("#!/bin/sh\nprintf '\\033[2J'\n");
+    for (frame, delay) in frames {
+        //the quoted delimiter prevents the shell from interpreting the frame
+        let _ = write!(
+            script,
+            "printf '\\033[H'\ncat <<'ARTEM_FRAME'\n{frame}\nARTEM_FRAME\nsleep {}\n",
+            format_seconds(*delay)
+        );
+    }
+    script
+}
+
+#[cfg(test)]
+mod test_script {
+    use super::*;
+
+    #[test]
+    fn sleep_after_each_frame() {
+        let script = script(&[
+            ("ab".to_string(), Duration::from_millis(100)),
+            ("cd".to_string(), Duration::from_millis(1250)),
+        ]);
+        assert_eq!(
+            "#!/bin/sh\nprintf '\\033[2J'\n\
+            printf '\\033[H'\ncat <<'ARTEM_FRAME'\nab\nARTEM_FRAME\nsleep 0.1\n\
+            printf '\\033[H'\ncat <<'ARTEM_FRAME'\ncd\nARTEM_FRAME\nsleep 1.25\n",
+            script
+        );
+    }
+
+    #[test]
+    fn gif_delays() {
+        //encode a gif with varying delays
+        let bytes = test_gif(&[(0, 100), (255, 250), (128, 40)]);
+        let frames = GifDecoder::new(bytes.as_slice())
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+
+        let converted = artem::convert_frames(frames, artem::options::OptionBuilder::new().build());
+        let script = script(&converted);
+        assert_eq!(
+            vec!["sleep 0.1", "sleep 0.25", "sleep 0.04"],
+            script
+                .lines()
+                .filter(|line| line.starts_with("sleep"))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+/// Format the duration as seconds, without trailing zeros.
+fn format_seconds(duration: Duration) -> String {
+    let seconds = format!("{:.3}", duration.as_secs_f64());
+    seconds
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod test_format_seconds {
+    use super::*;
+
+    #[test]
+    fn fraction() {
+        assert_eq!("0.04", format_seconds(Duration::from_millis(40)));
+    }
+
+    #[test]
+    fn whole_seconds() {
+        assert_eq!("2", format_seconds(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!("0", format_seconds(Duration::ZERO));
+    }
+}
+
+/// Encode a gif for testing, every frame is a single gray value with a delay in milliseconds.
+#[cfg(test)]
+fn test_gif(frames: &[(u8, u32)]) -> Vec<u8> {
+    use image::{codecs::gif::GifEncoder, Delay, RgbaImage};
+
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut bytes);
+        for (value, delay) in frames {
+            encoder
+                .encode_frame(Frame::from_parts(
+                    RgbaImage::from_pixel(4, 4, image::Rgba([*value, *value, *value, 255])),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(*delay, 1),
+                ))
+                .unwrap();
+        }
+    }
+    bytes
+}
@@ -0,0 +1,98 @@
+//! Writing the converted frames of animations as entries of a zip archive.
+
+use std::{
+    io::{Seek, Write},
+    time::Duration,
+};
+
+use artem::options::TargetType;
+use zip::{result::ZipResult, write::FileOptions, ZipWriter};
+
+/// Return the file extension of the entries, which matches the target type.
+///
+/// # Examples
+/// ```
+/// assert_eq!("html", entry_extension(TargetType::HtmlFile(true, false)));
+/// ```
+pub fn entry_extension(target: TargetType) -> &'static str {
+    match target {
+        TargetType::HtmlFile(_, _) => "html",
+        TargetType::AnsiFile(_) => "ans",
+        TargetType::BBCode => "bbcode",
+        TargetType::Rle => "rle",
+        TargetType::JsonLines => "jsonl",
+        _ => "txt",
+    }
+}
+
+/// Return the name of the entry for the frame with the given index.
+///
+/// The names start at 1 and are padded with zeros, so they are sorted correctly.
+///
+/// # Examples
+/// ```
+/// assert_eq!("frame_0001.txt", entry_name(0, "txt"));
+/// ```
+pub fn entry_name(index: usize, extension: &str) -> String {
+    format!("frame_{:04}.{extension}", index + 1)
+}
+
+#[cfg(test)]
+mod test_entry_name {
+    use super::*;
+
+    #[test]
+    fn first_frame() {
+        assert_eq!("frame_0001.txt", entry_name(0, "txt"));
+    }
+
+    #[test]
+    fn more_than_padding() {
+        assert_eq!("frame_12345.html", entry_name(12344, "html"));
+    }
+}
+
+/// Write every frame as its own entry to a zip archive.
+///
+/// The delays of the frames are not stored, since the entries are plain files of the target type.
+/// The writer is returned after the archive has been finished.
+pub fn write_zip<W: Write + Seek>(
+    writer: W,
+    frames: &[(String, Duration)],
+    extension: &str,
+) -> ZipResult<W> {
+    let mut zip = ZipWriter::new(writer);
+    for (index, (frame, _)) in frames.iter().enumerate() {
+        zip.start_file(entry_name(index, extension), FileOptions::default())?;
+        zip.write_all(frame.as_bytes())?;
+    }
+    zip.finish()
+}
+
+#[cfg(test)]
+mod test_write_zip {
+    use std::io::{Cursor, Read};
+
+    use super::*;
+
+    #[test]
+    fn entry_for_every_frame() {
+        let frames = vec![
+            ("ab".to_string(), Duration::from_millis(100)),
+            ("cd".to_string(), Duration::from_millis(100)),
+            ("ef".to_string(), Duration::from_millis(100)),
+        ];
+        let cursor = write_zip(Cursor::new(Vec::new()), &frames, "txt").unwrap();
+
+        let mut archive = zip::ZipArchive::new(cursor).unwrap();
+        assert_eq!(3, archive.len());
+
+        let mut content = String::new();
+        archive
+            .by_name("frame_0002.txt")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!("cd", content);
+    }
+}
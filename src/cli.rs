@@ -25,10 +25,10 @@ pub fn build_cli() -> Command<'static> {
                     if cfg!(feature = "web_image")
                     {
                         //special help message with url help 
-                        "Paths or URLs to the target image. If the input is an URL, the image is downloaded and then converted. The original image is NOT altered."
+                        "Paths or URLs to the target image. If the input is an URL, the image is downloaded and then converted. The original image is NOT altered. Use - to read the image from stdin."
                     } else {
                         //normal help text with only paths
-                        "Paths to the target image. The original image is NOT altered."
+                        "Paths to the target image. The original image is NOT altered. Use - to read the image from stdin."
                     }
 
                 )
@@ -45,7 +45,39 @@ pub fn build_cli() -> Command<'static> {
                 //use "\" to keep this readable but still as a single line string
                 .help("Change the characters that are used to display the image.\
                 The first character should have the highest 'darkness' and the last should have the least (recommended to be a space ' '). \
-                A lower detail map is recommend for smaller images. Included characters can be used with the argument 0 | 1 | 2."),
+                A lower detail map is recommend for smaller images. Included characters can be used with the argument 0 | 1 | 2, \
+                or 3 for braille patterns, where the brightness selects the number of dots."),
+        )
+        .arg(
+            Arg::new("characters-file")
+                .long("characters-file")
+                .takes_value(true)
+                .value_hint(ValueHint::FilePath)
+                .conflicts_with("characters")
+                .help("Read the characters that are used to display the image from the first line of a file. \
+                The line is used exactly as it is, which avoids problems with escaping characters in the shell. \
+                This argument is conflicting with --characters."),
+        )
+        .arg(
+            Arg::new("pad-characters")
+                .long("pad-characters")
+                .help("Add a space as the lightest character, when only a single character is used. \
+                Otherwise every character of the image is the same, which results in a solid block."),
+        )
+        .arg(
+            Arg::new("check-width")
+                .long("check-width")
+                .help("Check the display width of the characters. If any of them is wide, for example full-width east asian characters, \
+                which take up two columns, the number of columns is halved to keep the size and the alignment of the image."),
+        )
+        .arg(
+            Arg::new("ramp-cycle")
+                .long("ramp-cycle")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_hint(ValueHint::Other)
+                .help("Use different characters for every row, cycling through all given ones, which results in a woven texture. \
+                It can be used multiple times, each value is ordered from darkest/densest to lightest, like --characters."),
         )
         .arg(
             Arg::new("size")
@@ -54,10 +86,11 @@ pub fn build_cli() -> Command<'static> {
                 .takes_value(true)
                 .default_value("80")
                 .value_hint(ValueHint::Other)
-                .conflicts_with_all(&["height", "width"])
+                .conflicts_with_all(&["height", "width", "fit-terminal", "max-width", "max-height", "columns", "rows"])
                 .help("Change the size of the output image. \
                 The minimum size is 20, the maximum 230. Values outside of the range will be \
-                ignored and changed to the nearest usable value. This argument is conflicting with --width and --height."),
+                ignored and changed to the nearest usable value. \
+                This argument is conflicting with --width, --height, --fit-terminal, --max-width, --max-height, --columns and --rows."),
         )
         .arg(
             Arg::new("height")
@@ -74,6 +107,67 @@ pub fn build_cli() -> Command<'static> {
                 .help("Use the terminal maximum terminal width to display the image. \
                 This argument is conflicting with --size and --height."),
         )
+        .arg(
+            Arg::new("fit-terminal")
+                .long("fit-terminal")
+                .conflicts_with_all(&["height", "width"])
+                .help("Use the largest size, at which the image still fits into the width and height of the terminal. \
+                This argument is conflicting with --size, --width and --height."),
+        )
+        .arg(
+            Arg::new("max-width")
+                .long("max-width")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with_all(&["height", "width", "fit-terminal"])
+                .help("The maximum number of columns of the output image, the aspect ratio is kept. \
+                It can be combined with --max-height, in which case the more constraining one is used. \
+                The minimum is 20, the maximum 230. This argument is conflicting with --size, --width, --height and --fit-terminal."),
+        )
+        .arg(
+            Arg::new("max-height")
+                .long("max-height")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with_all(&["height", "width", "fit-terminal"])
+                .help("The maximum number of rows of the output image, the aspect ratio is kept. \
+                It can be combined with --max-width, in which case the more constraining one is used. \
+                This argument is conflicting with --size, --width, --height and --fit-terminal."),
+        )
+        .arg(
+            Arg::new("columns")
+                .long("columns")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("rows")
+                .conflicts_with_all(&["height", "width", "fit-terminal", "max-width", "max-height"])
+                .help("The exact number of columns of the output image, the image is stretched to fill them together with --rows. \
+                This argument requires --rows and is conflicting with --size, --width, --height, --fit-terminal, --max-width and --max-height."),
+        )
+        .arg(
+            Arg::new("rows")
+                .long("rows")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("columns")
+                .conflicts_with_all(&["height", "width", "fit-terminal", "max-width", "max-height"])
+                .help("The exact number of rows of the output image, the image is stretched to fill them together with --columns. \
+                This argument requires --columns and is conflicting with --size, --width, --height, --fit-terminal, --max-width and --max-height."),
+        )
+        .arg(
+            Arg::new("max-display-width")
+                .long("max-display-width")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("The maximum number of columns a viewer can display without wrapping, including the border. \
+                Wider output is reduced in size until it fits."),
+        )
+        .arg(
+            Arg::new("no-wrap-warn")
+                .long("no-wrap-warn")
+                .requires("max-display-width")
+                .help("Only warn, if the output is wider than --max-display-width, instead of reducing its size."),
+        )
         .arg(
             Arg::new("scale")
                 .long("ratio")
@@ -82,6 +176,20 @@ pub fn build_cli() -> Command<'static> {
                 .value_hint(ValueHint::Other)
                 .help("Change the ratio between height and width, since ASCII characters are a bit higher than long. \
                 The value has to be between 0.1 and 1.0. It is not recommend to change this setting."),
+        )
+        .arg(
+            Arg::new("square-pixels")
+                .long("square-pixels")
+                .conflicts_with("scale")
+                .help("Ask the terminal for the size of its cells and derive the ratio from it, so the pixels of the image are square on the screen. \
+                This only works when printing to an interactive terminal, which answers the request, and requires the terminal feature. \
+                Otherwise the default ratio is used. This argument is conflicting with --ratio."),
+        )
+        .arg(
+            Arg::new("auto-ratio")
+                .long("auto-ratio")
+                .help("Derive the ratio from the aspect ratio of the image, which reduces the distortion of very wide or high images. \
+                The value of --ratio is used as the ratio between the width and height of a terminal character."),
         ).arg(
             Arg::new("flipX")
                 .long("flipX")
@@ -90,6 +198,11 @@ pub fn build_cli() -> Command<'static> {
             Arg::new("flipY")
                 .long("flipY")
                 .help("Flip the image along the Y-Axis/vertically."),
+        ).arg(
+            Arg::new("transpose")
+                .long("transpose")
+                .help("Output the columns of the image as rows, so reading the output from top to bottom walks the image from left to right. \
+                Unlike flipping, the characters themselves are transposed, so the output is as high as the image would be wide."),
         ).arg(
             Arg::new("centerX")
             .long("centerX")
@@ -98,6 +211,22 @@ pub fn build_cli() -> Command<'static> {
             Arg::new("centerY")
                 .long("centerY")
                 .help("Center the image along the Y-Axis/vertically in the terminal."),
+        ).arg(
+            Arg::new("align-x")
+                .long("align-x")
+                .takes_value(true)
+                .possible_values(["start", "center", "end"])
+                .conflicts_with("centerX")
+                .help("Place the image at the start (left), center or end (right) of the terminal along the X-Axis/horizontally. \
+                This argument is mutually exclusive with the centerX argument."),
+        ).arg(
+            Arg::new("align-y")
+                .long("align-y")
+                .takes_value(true)
+                .possible_values(["start", "center", "end"])
+                .conflicts_with("centerY")
+                .help("Place the image at the start (top), center or end (bottom) of the terminal along the Y-Axis/vertically. \
+                This argument is mutually exclusive with the centerY argument."),
         )
         .arg(
             Arg::new("output-file")
@@ -108,13 +237,91 @@ pub fn build_cli() -> Command<'static> {
                 .help("Output file for non-colored ascii. If the output file is a plaintext file, no color will be used. The use color, either use a file with an \
                 .ansi extension, or an .html file, to convert the output to html. \
                 .ansi files will consider environment variables when creating colored output, for example when COLORTERM is not set to truecolor,\
-                the resulting file will fallback to 8-bit colors."),
+                the resulting file will fallback to 8-bit colors. A .bbcode file will contain colored BBCode markup, which can be posted in forums. A .rle file will contain plain ascii, where every run of identical characters is written as <count>x<char>. \
+                A .jsonl file will contain a JSON object with the characters and colors of the cells for every row. \
+                With --animate, a .zip file will contain every frame as its own file, the extension before .zip, for example frames.html.zip, sets the type of the files. This requires the archive feature."),
+        )
+        .arg(
+            Arg::new("clipboard")
+                .long("clipboard")
+                .conflicts_with("output-file")
+                .help("Copy the converted image to the clipboard, instead of printing it. The copied image does not use colors, so it can be pasted anywhere. \
+                On Linux, the copied image is only kept after artem exits, if a clipboard manager is running. This requires the clipboard feature."),
+        )
+        .arg(
+            Arg::new("html-color")
+                .long("html-color")
+                .takes_value(true)
+                .possible_values(["hex", "hsl"])
+                .help("Format of the colors in html files. hsl colors can be themed using css filters. Defaults to hex."),
+        )
+        .arg(
+            Arg::new("html-fragment")
+                .long("html-fragment")
+                .help("Only write the pre element of the ascii image to html files, without the rest of the document, like the doctype and the head. \
+                This allows embedding the ascii image in an existing page. It has no effect on other outputs."),
+        )
+        .arg(
+            Arg::new("encoding")
+                .long("encoding")
+                .takes_value(true)
+                .possible_values(["utf8", "utf8-bom", "ascii"])
+                .requires("output-file")
+                .help("Encoding of the output file. utf8-bom adds a byte order mark, which is needed by some windows tools. \
+                ascii replaces the non-ascii characters, like the border, with similar ascii characters. Defaults to utf8. \
+                This will require the --output argument to be present as well."),
+        )
+        .arg(
+            Arg::new("sauce-title")
+                .long("sauce-title")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("output-file")
+                .help("Append a SAUCE record with this title to .ans files, which describes the art for ansi art viewers. \
+                The title can have up to 35 characters. This will require the --output argument to be present as well."),
+        )
+        .arg(
+            Arg::new("sauce-author")
+                .long("sauce-author")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("sauce-title")
+                .help("Name of the author in the SAUCE record, up to 20 characters. This will require the --sauce-title argument."),
+        )
+        .arg(
+            Arg::new("sauce-group")
+                .long("sauce-group")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("sauce-title")
+                .help("Name of the group in the SAUCE record, up to 20 characters. This will require the --sauce-title argument."),
         )
         .arg(
             Arg::new("invert-density")
                 .long("invert")
                 .help("Inverts the characters used for the image, so light characters will as dark ones. Can be useful if the image has a dark background."),
         )
+        .arg(
+            Arg::new("auto-invert")
+                .long("auto-invert")
+                .conflicts_with_all(&["invert-density", "both-themes"])
+                .help("Invert the characters automatically, if the edges of the image are dark. \
+                This argument is mutually exclusive with the invert and both-themes arguments."),
+        )
+        .arg(
+            Arg::new("detect-background")
+                .long("detect-background")
+                .conflicts_with_all(&["invert-density", "auto-invert", "both-themes"])
+                .help("Ask the terminal for its background color and use it to decide, if the characters should be inverted and dark colors brightened. \
+                This only works when printing to an interactive terminal, which answers the request, and requires the terminal feature. \
+                This argument is mutually exclusive with the invert, auto-invert and both-themes arguments."),
+        )
+        .arg(
+            Arg::new("both-themes")
+                .long("both-themes")
+                .help("Output the image twice, once normally and once with inverted characters, separated by a labeled line. \
+                This ensures that one of them looks right on both light and dark terminals."),
+        )
         .arg(
             Arg::new("background-color")
                 .long("background")
@@ -122,23 +329,383 @@ pub fn build_cli() -> Command<'static> {
                 .help("Sets the background of the ascii as the color. This will be ignored if the terminal does not support truecolor. \
                 This argument is mutually exclusive with the no-color argument."),
         )
+        .arg(
+            Arg::new("rounding")
+                .long("rounding")
+                .takes_value(true)
+                .possible_values(["floor", "round", "nearest"])
+                .help("Rounding of the luminosity to the characters. floor rounds down, round and nearest round to the nearest character, \
+                which distributes the characters more evenly. nearest rounds halfway values to the even character. Defaults to floor."),
+        )
+        .arg(
+            Arg::new("pattern-match")
+                .long("pattern-match")
+                .help("Split every character into 3x3 sub-pixels and use the glyph, which resembles their pattern best, for example a + for a cross. \
+                Characters without a clear pattern are selected from the characters as usual."),
+        )
+        .arg(
+            Arg::new("braille")
+                .long("braille")
+                .conflicts_with("pattern-match")
+                .help("Convert every character to a braille pattern, by splitting it into 2x4 dots and showing the dots, which are brighter than the --braille-threshold. \
+                This shows much finer details, especially together with --outline."),
+        )
+        .arg(
+            Arg::new("braille-threshold")
+                .long("braille-threshold")
+                .takes_value(true)
+                .requires("braille")
+                .default_value("128")
+                .value_hint(ValueHint::Other)
+                .help("The luminosity between 0 and 255, above which the braille dots are shown. Defaults to 128."),
+        )
+        .arg(
+            Arg::new("density-channel")
+                .long("density-channel")
+                .takes_value(true)
+                .possible_values(["luminosity", "red", "green", "blue", "alpha"])
+                .help("Select the characters using only a single channel of the image, instead of the luminosity. \
+                The colors of the characters are not changed."),
+        )
+        .arg(
+            Arg::new("grayscale")
+                .long("grayscale")
+                .conflicts_with("no-color")
+                .help("Color the ascii using the 24 step grayscale ramp of the 256 color palette. \
+                This gives finer gray tones than the basic ansi colors and does not require truecolor support. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("ansi-tie-break")
+                .long("ansi-tie-break")
+                .takes_value(true)
+                .possible_values(["standard", "bright"])
+                .conflicts_with("no-color")
+                .help("The preferred ansi color, when a color is equally close to a standard and a bright ansi color. \
+                This is only used, when the terminal does not support truecolor. Defaults to standard."),
+        )
+        .arg(
+            Arg::new("color-distance")
+                .long("color-distance")
+                .takes_value(true)
+                .possible_values(["euclidean", "weighted", "lab"])
+                .conflicts_with("no-color")
+                .help("The distance, which is used to find the closest color, when reducing the colors to a palette, the color step or the web-safe colors. \
+                weighted weights the channels by how strong they are perceived, lab uses the CIELAB color space. Defaults to euclidean."),
+        )
+        .arg(
+            Arg::new("backdrop")
+                .long("backdrop")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Use a single background color behind the whole ascii image, given as a hex color, for example #1e1e1e. \
+                Unlike --background, the color is the same for every character. This only has an effect when printing to the terminal. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("color-fg")
+                .long("color-fg")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Use a single color, given as a hex color, for example #00ff00, for every character instead of the colors of the image. \
+                The characters are still selected using the image. This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("color-step")
+                .long("color-step")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Round the colors to multiples of the given step, between 1 and 255. Similar colors will be the same, \
+                which results in smaller colored output files. This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("max-color-switches")
+                .long("max-color-switches")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Limit each line of the terminal output to the given number of runs of the same color, by merging similar adjacent colors. \
+                This speeds up rendering on slow terminals, but makes the colors less accurate. This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("half-blocks")
+                .long("half-blocks")
+                .conflicts_with_all(&["no-color", "comment"])
+                .help("Use upper half blocks, which are colored with the top half of the character as foreground and the bottom half as background color. \
+                This doubles the vertical resolution, but only works in the terminal and in .ans files, since it requires colors. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("web-safe")
+                .long("web-safe")
+                .conflicts_with("no-color")
+                .help("Snap the colors to the 216 web-safe colors, which reduces the amount of different colors. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("min-brightness")
+                .long("min-brightness")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Brighten colors, which are darker than the given value between 0 and 255, so the characters stay readable on dark terminals. \
+                The characters are not changed. This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("palette")
+                .long("palette")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .conflicts_with("no-color")
+                .help("Reduce the colors to the given palette, a comma separated list of hex colors, for example #000000,#ff0000,#ffffff. \
+                Every color is replaced with the closest color of the palette. This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("color-dither")
+                .long("color-dither")
+                .requires("palette")
+                .help("Dither the colors when reducing them to the palette, so gradients are represented by a mix of the palette colors instead of hard bands."),
+        )
+        .arg(
+            Arg::new("frame")
+                .long("frame")
+                .takes_value(true)
+                .default_value("0")
+                .value_hint(ValueHint::Other)
+                .conflicts_with_all(&["animate", "frames"])
+                .help("The index of the frame, which is converted from an animated gif, starting at 0. \
+                This argument is conflicting with --animate and --frames."),
+        )
+        .arg(
+            Arg::new("animate")
+                .long("animate")
+                .conflicts_with("both-themes")
+                .help("Convert every frame of the input gif and output a shell script, which plays them with the original delays. \
+                Running the script prints the animation in the terminal."),
+        )
+        .arg(
+            Arg::new("frames")
+                .long("frames")
+                .conflicts_with_all(&["animate", "both-themes", "inline-image", "dry-run", "label", "dump-codepoints", "dump-luminance", "color-map"])
+                .help("Convert every frame of the input gif and print them one after another, separated by a sequence, which clears the terminal. \
+                Unlike --animate, the frames are printed directly, so they can be processed or played by other programs."),
+        )
+        .arg(
+            Arg::new("frame-delimiter")
+                .long("frame-delimiter")
+                .takes_value(true)
+                .requires("frames")
+                .allow_hyphen_values(true)
+                .value_hint(ValueHint::Other)
+                .help("Separate the frames printed by --frames with the given text instead of the sequence, which clears the terminal."),
+        )
+        .arg(
+            Arg::new("edge-color")
+                .long("edge-color")
+                .conflicts_with("no-color")
+                .help("Only color the characters at edges of the image, the other ones are gray. This results in a glowing outline effect. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
+        .arg(
+            Arg::new("line-height")
+                .long("line-height")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("Set the line height of html output files, a smaller value removes the gaps between the rows. \
+                The value is relative to the font size and has to be larger than 0.0. It has no effect on other outputs."),
+        )
         .arg(
             Arg::new("border")
                 .long("border")
                 .help("Adds a decorative border surrounding the ascii image. This will make the image overall a bit smaller, \
                 since it respects the user given size."),
         )
+        .arg(
+            Arg::new("inline-image")
+                .long("inline-image")
+                .takes_value(true)
+                .possible_values(["auto", "kitty", "iterm"])
+                .conflicts_with_all(&["output-file", "both-themes", "animate"])
+                .help("Send the original image to terminals, which can display images inline, instead of converting it to ascii. \
+                With auto the protocol is detected and the ascii image is used as a fallback, kitty and iterm force the protocol."),
+        )
+        .arg(
+            Arg::new("comment")
+                .long("comment")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("Add the given prefix, for example '// ' or '# ', in front of every line, so the ascii art can be embedded as a comment in source code. \
+                It is only used for the terminal and plain text files, colors are disabled when using it."),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .conflicts_with_all(&["animate", "inline-image", "dump-codepoints"])
+                .help("Print the resolved settings, like the target, the used colors and the number of columns and rows, \
+                without converting the image. No output file is written."),
+        )
+        .arg(
+            Arg::new("legend")
+                .long("legend")
+                .help("Add a legend below the image, which lists the used characters from the densest to the lightest. \
+                It is only added to the terminal and plain text files, since it would break the other file types."),
+        )
+        .arg(
+            Arg::new("label")
+                .long("label")
+                .conflicts_with("animate")
+                .help("Add the path of each input on a line above its converted image, so multiple inputs can be told apart. \
+                The label is not added to html files, since it would break their structure."),
+        )
+        .arg(
+            Arg::new("dump-codepoints")
+                .long("dump-codepoints")
+                .conflicts_with_all(&["both-themes", "animate", "inline-image"])
+                .help("Instead of the ascii image, output the distinct characters it uses with their unicode codepoints. \
+                This helps with debugging encoding issues of the characters."),
+        )
+        .arg(
+            Arg::new("dump-luminance")
+                .long("dump-luminance")
+                .takes_value(true)
+                .value_hint(ValueHint::FilePath)
+                .conflicts_with_all(&["animate", "inline-image", "dry-run"])
+                .help("Write the luminosity of every cell to a grayscale pgm image, with one pixel per character. \
+                This shows the brightness, which is used to select the characters. The images of multiple inputs are written one after another into the same file."),
+        )
+        .arg(
+            Arg::new("color-map")
+                .long("color-map")
+                .takes_value(true)
+                .value_hint(ValueHint::FilePath)
+                .conflicts_with_all(&["both-themes", "animate", "inline-image", "dump-codepoints", "dry-run"])
+                .help("Write the colors of the ascii image to a separate file, so the ascii stays readable as plain text. \
+                Every non-blank character has a line with its row, its column and its hex color, for example '0 12 #FF8800'. \
+                The border and the ruler are not counted. The colors of multiple inputs are separated by an empty line."),
+        )
+        .arg(
+            Arg::new("ruler")
+                .long("ruler")
+                .help("Add a ruler, which marks every 10th column and row with its index. This is useful for debugging or teaching. \
+                It is only added when printing to the terminal or to plain text files."),
+        )
+        .arg(
+            Arg::new("tile")
+                .long("tile")
+                .help("Repeat images, which are smaller than the size, to fill the size instead of upscaling them. \
+                This keeps small textures crisp."),
+        )
+        .arg(
+            Arg::new("auto-rotate")
+                .long("auto-rotate")
+                .help("Rotate the image by 90 degrees, if it fits the terminal better, for example a portrait image in a wide terminal. \
+                When using --max-height or --fit-terminal, their height is used instead of the terminal height."),
+        )
+        .arg(
+            Arg::new("linear-downscale")
+                .long("linear-downscale")
+                .help("Downscale the image in linear light, which keeps bright details from being darkened. \
+                This takes some additional time."),
+        )
+        .arg(
+            Arg::new("pixel-art")
+                .long("pixel-art")
+                .conflicts_with("linear-downscale")
+                .help("Convert pixel art, by resizing the image using the nearest neighbor and coloring each character with the exact color of the pixel in the center of it. \
+                The characters are selected using an ordered dithering, so flat areas keep their tonal texture."),
+        )
+        .arg(
+            Arg::new("dither")
+                .long("dither")
+                .conflicts_with("outline")
+                .help("Dither the luminosity of the characters, by spreading the difference to the selected character onto the neighboring ones (Floyd–Steinberg dithering). \
+                This keeps tonal gradients, especially with only a few characters. This argument is conflicting with --outline."),
+        )
+        .arg(
+            Arg::new("edge-mode")
+                .long("edge-mode")
+                .takes_value(true)
+                .possible_values(["crop", "pad"])
+                .help("How the pixels at the right and bottom edge are handled, if they do not fill a whole character. \
+                crop resizes the image to whole characters, pad keeps the full image and fills the last characters with the pixels at the edge. \
+                pad can not be combined with --pixel-art or --linear-downscale. Defaults to crop."),
+        )
+        .arg(
+            Arg::new("border-outside")
+                .long("border-outside")
+                .requires("border")
+                .help("Add the border around the full sized image, instead of reducing the image size to make room for it. \
+                The output will be 2 columns and rows larger than the size. This will require the --border argument to be present as well."),
+        )
+        .arg(
+            Arg::new("color-border")
+                .long("color-border")
+                .requires("border")
+                .conflicts_with("no-color")
+                .help("Color the border using the colors of the adjacent characters. This will require the --border argument to be present as well. \
+                This argument is mutually exclusive with the no-color argument."),
+        )
         .arg(
             Arg::new("no-color")
                 .long("no-color")
                 .help("Do not use color when printing the image to the terminal."),
         )
+        .arg(
+            Arg::new("clear")
+                .long("clear")
+                .help("Clear the terminal before printing the image, so no leftovers of a previous image remain. \
+                This has no effect when using an output file."),
+        )
         .arg(
             Arg::new("outline")
                 .long("outline")
                 .help("Only create an outline of the image. This uses filters, so it will take more resources/time to complete, especially on larger images. \
                 It might not produce the desired output, it is advised to use this only on images with a clear distinction between foreground and background."),
         )
+        .arg(
+            Arg::new("tone-map")
+                .long("tone-map")
+                .help("Compress the highlights of the image using Reinhard tone mapping, before choosing the characters. \
+                This helps with bright images, which would otherwise only use a few characters."),
+        )
+        .arg(
+            Arg::new("min-contrast")
+                .long("min-contrast")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("Stretch the brightness of the image to the full range, if it spreads over less than the given contrast, between 0.0 and 255.0. \
+                This makes subtle variations of nearly uniform images visible, instead of converting them to a featureless block."),
+        )
+        .arg(
+            Arg::new("equalize")
+                .long("equalize")
+                .help("Equalize the histogram of the brightness, before choosing the characters. \
+                This spreads flat, low contrast images over all characters, so each character is used about equally often."),
+        )
+        .arg(
+            Arg::new("smooth")
+                .long("smooth")
+                .help("Soften harsh jumps between horizontally neighboring characters, which reduces banding along gradients."),
+        )
+        .arg(
+            Arg::new("sharpen")
+                .long("sharpen")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("Sharpen the image before converting it, this makes edges and fine textures more visible. \
+                The value is the strength of the sharpening and has to be between 0.0 (disabled) and 10.0."),
+        )
+        .arg(
+            Arg::new("kernel-size")
+                .long("kernel-size")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .help("The width and height of the blur, which is used for the outline and for sharpening. \
+                It has to be an odd number, for example 3, 5 or 7, larger sizes blur the image stronger. Defaults to 3."),
+        )
         .arg(
             Arg::new("hysteresis")
                 .long("hysteresis")
@@ -147,6 +714,30 @@ pub fn build_cli() -> Command<'static> {
                 .help("When creating the outline use the hysteresis method, which will remove imperfection, but might not be as good looking in ascii form.\
                  This will require the --outline argument to be present as well."),
         )
+        .arg(
+            Arg::new("hysteresis-gamma")
+                .long("hysteresis-gamma")
+                .takes_value(true)
+                .value_hint(ValueHint::Other)
+                .requires("hysteresis")
+                .help("Apply the gamma to the edges before the hysteresis thresholds, values larger than 1.0 keep more faint edges. \
+                The value has to be larger than 0.0. This will require the --hysteresis argument to be present as well."),
+        )
+        .arg(
+            Arg::new("outline-normalize")
+                .long("outline-normalize")
+                .requires("outline")
+                .help("Stretch the edges of the outline from the weakest to the strongest one to the full brightness range, so faint edges use more of the characters. \
+                This will require the --outline argument to be present as well."),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .takes_value(true)
+                .value_hint(ValueHint::FilePath)
+                .help("Read the arguments from a .toml or .json config file. The keys are the long names of the arguments, \
+                for example size = 40 or border = true. Arguments given on the command line take precedence over the config file."),
+        )
         .arg(
             Arg::new("verbosity")
                 .long("verbose")
@@ -0,0 +1,401 @@
+//! Loading of config files for the cli.
+//!
+//! A config file contains the long names of the command-line arguments as keys, for example
+//! `size = 40` or `border = true`. Flags are enabled by setting them to `true`.
+//! Alternatively, it can contain the options of the library in their serde format, for example
+//! `target_size = 40`, which is the format of `OptionBuilder::from_toml`.
+//! Arguments given on the command line always take precedence over the values of the config file,
+//! values which conflict with an argument of the command line are ignored.
+
+use std::{ffi::OsString, path::Path};
+
+use artem::options::OptionBuilder;
+use clap::{Arg, ArgMatches, Command, ValueSource};
+
+/// A single value of a config file.
+#[derive(Debug, PartialEq)]
+enum ConfigValue {
+    /// A flag, which is only added when it is true.
+    Flag(bool),
+    /// The value of an argument.
+    Value(String),
+}
+
+/// The options of a config file, which uses the serde format of the library.
+#[derive(Debug, PartialEq)]
+pub struct FileOptions(serde_json::Map<String, serde_json::Value>);
+
+impl FileOptions {
+    /// Merge the options of the config file with the options of the command line.
+    ///
+    /// An option of the config file is only used, if none of the arguments setting it were given on the command line.
+    pub fn merge(&self, command_line: &OptionBuilder, matches: &ArgMatches) -> OptionBuilder {
+        //the builder only contains serializable values, so it is safe to unwrap
+        let mut merged = serde_json::to_value(command_line).unwrap();
+        if let Some(merged) = merged.as_object_mut() {
+            for (key, value) in &self.0 {
+                if !option_arguments(key)
+                    .iter()
+                    .any(|id| matches.value_source(id) == Some(ValueSource::CommandLine))
+                {
+                    merged.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        //the values of the file have been checked when loading it, so it is safe to unwrap
+        serde_json::from_value(merged).unwrap()
+    }
+}
+
+/// Return the ids of the command line arguments, which set the option with the given name.
+fn option_arguments(name: &str) -> &'static [&'static str] {
+    match name {
+        "characters" => &["characters", "characters-file"],
+        "scale" => &["scale", "square-pixels"],
+        "target_size" | "dimension" => &[
+            "size",
+            "height",
+            "width",
+            "fit-terminal",
+            "max-width",
+            "max-height",
+            "columns",
+            "rows",
+        ],
+        "invert" => &["invert-density", "detect-background"],
+        "transform_x" => &["flipX"],
+        "transform_y" => &["flipY"],
+        "anchor_x" => &["centerX", "align-x"],
+        "anchor_y" => &["centerY", "align-y"],
+        "target" => &["output-file", "clipboard", "no-color", "background-color"],
+        "color_mode" => &["grayscale"],
+        "clear_screen" => &["clear"],
+        "color_quantize_step" => &["color-step"],
+        "min_color_luminance" => &["min-brightness", "detect-background"],
+        "smooth_horizontal" => &["smooth"],
+        "edge_color_only" => &["edge-color"],
+        "auto_char_scale" => &["auto-ratio"],
+        "border_inside" => &["border-outside"],
+        "web_safe_colors" => &["web-safe"],
+        "comment_prefix" => &["comment"],
+        "html_color_format" => &["html-color"],
+        "tile_source" => &["tile"],
+        "fixed_color" => &["color-fg"],
+        "sauce" => &["sauce-title", "sauce-author", "sauce-group"],
+        "max_color_switches_per_line" => &["max-color-switches"],
+        "braille" => &["braille", "braille-threshold"],
+        "border" => &["border"],
+        "outline" => &["outline"],
+        "hysteresis" => &["hysteresis"],
+        "sharpen" => &["sharpen"],
+        "backdrop" => &["backdrop"],
+        "palette" => &["palette"],
+        "color_dither" => &["color-dither"],
+        "hysteresis_gamma" => &["hysteresis-gamma"],
+        "ruler" => &["ruler"],
+        "line_height" => &["line-height"],
+        "tone_map" => &["tone-map"],
+        "color_border" => &["color-border"],
+        "ramp_cycle" => &["ramp-cycle"],
+        "density_channel" => &["density-channel"],
+        "auto_invert" => &["auto-invert"],
+        "legend" => &["legend"],
+        "rounding" => &["rounding"],
+        "linear_downscale" => &["linear-downscale"],
+        "max_display_width" => &["max-display-width"],
+        "ansi_tie_break" => &["ansi-tie-break"],
+        "auto_rotate" => &["auto-rotate"],
+        "pad_characters" => &["pad-characters"],
+        "pattern_match" => &["pattern-match"],
+        "html_fragment" => &["html-fragment"],
+        "outline_normalize" => &["outline-normalize"],
+        "pixel_art" => &["pixel-art"],
+        "equalize" => &["equalize"],
+        "color_distance" => &["color-distance"],
+        "min_contrast" => &["min-contrast"],
+        "check_width" => &["check-width"],
+        "edge_mode" => &["edge-mode"],
+        "transpose" => &["transpose"],
+        "kernel_size" => &["kernel-size"],
+        "half_blocks" => &["half-blocks"],
+        "dither" => &["dither"],
+        //the luminance lookup table, the luminance weights and the background fill can only be set in the file
+        _ => &[],
+    }
+}
+
+/// Merge the arguments of the config file with the command line arguments.
+///
+/// The config file can either be a `.json` file or a `.toml` file, every other extension is read as toml.
+/// Values of the config file are only used, if the argument was not given on the command line
+/// and does not conflict with an argument of the command line.
+/// The `args` are the original command line arguments, including the name of the program.
+///
+/// If the config file uses the serde format of the library, the matches are returned unchanged
+/// together with the options of the file, which still have to be merged with [`FileOptions::merge`].
+///
+/// On failure, an error message and the exit code are returned.
+pub fn merge_config_file(
+    path: &str,
+    cmd: Command<'static>,
+    matches: &ArgMatches,
+    args: Vec<OsString>,
+) -> Result<(ArgMatches, Option<FileOptions>), (String, i32)> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| (format!("Could not read config file {path}: {err}"), 66))?;
+
+    let json = Path::new(path)
+        .extension()
+        .and_then(std::ffi::OsStr::to_str)
+        == Some("json");
+
+    if let Some(options) = file_options(&content, json, &cmd)
+        .map_err(|err| (format!("Could not parse config file {path}: {err}"), 65))?
+    {
+        return Ok((matches.clone(), Some(options)));
+    }
+
+    let values = if json {
+        parse_json(&content)
+    } else {
+        parse_toml(&content)
+    }
+    .map_err(|err| (format!("Could not parse config file {path}: {err}"), 65))?;
+
+    //the config arguments are added directly after the program name, before the command line arguments
+    let mut merged_args = Vec::with_capacity(args.len() + values.len() * 2);
+    let mut args = args.into_iter();
+    merged_args.extend(args.next());
+
+    let given_on_command_line =
+        |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+    for (key, value) in values {
+        let arg = cmd
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key.as_str()) && key != "config")
+            .ok_or_else(|| (format!("Unknown config key {key}"), 65))?;
+
+        //the command line takes precedence, including arguments which conflict with the key
+        if given_on_command_line(arg.get_id())
+            || cmd
+                .get_arguments()
+                .filter(|other| given_on_command_line(other.get_id()))
+                .any(|other| conflicting(&cmd, arg, other))
+        {
+            continue;
+        }
+
+        match value {
+            ConfigValue::Flag(true) => merged_args.push(OsString::from(format!("--{key}"))),
+            ConfigValue::Flag(false) => {}
+            ConfigValue::Value(value) => {
+                merged_args.push(OsString::from(format!("--{key}")));
+                merged_args.push(OsString::from(value));
+            }
+        }
+    }
+    merged_args.extend(args);
+
+    Ok((cmd.get_matches_from(merged_args), None))
+}
+
+/// Read the options of a config file, which uses the serde format of the library.
+///
+/// Returns `None`, if the file is not a valid document or not all keys are names of options,
+/// as well as if all keys are also names of command line arguments.
+fn file_options(content: &str, json: bool, cmd: &Command) -> Result<Option<FileOptions>, String> {
+    let document = if json {
+        serde_json::from_str(content).ok()
+    } else {
+        content
+            .parse::<toml::Value>()
+            .ok()
+            .and_then(|value| serde_json::to_value(value).ok())
+    };
+    let document = match document {
+        Some(serde_json::Value::Object(document)) => document,
+        _ => return Ok(None),
+    };
+
+    //the builder only contains serializable values, so it is safe to unwrap
+    let defaults = serde_json::to_value(OptionBuilder::new()).unwrap();
+    let is_option = |key: &String| defaults.get(key).is_some();
+    let is_argument = |key: &String| {
+        key != "config"
+            && cmd
+                .get_arguments()
+                .any(|arg| arg.get_long() == Some(key.as_str()))
+    };
+    if document.keys().all(is_argument) || !document.keys().all(is_option) {
+        return Ok(None);
+    }
+
+    //check the values, before they are merged with the command line
+    serde_json::from_value::<OptionBuilder>(serde_json::Value::Object(document.clone()))
+        .map_err(|err| err.to_string())?;
+    Ok(Some(FileOptions(document)))
+}
+
+#[cfg(test)]
+mod test_file_options {
+    use super::*;
+
+    #[test]
+    fn library_format() {
+        let options = file_options(
+            "target_size = 40\nborder = true",
+            false,
+            &crate::cli::build_cli(),
+        )
+        .unwrap()
+        .unwrap();
+        let merged = options.merge(
+            &OptionBuilder::new(),
+            &crate::cli::build_cli().get_matches_from(["artem", "img.png"]),
+        );
+        assert_eq!(40, merged.build().target_size);
+        assert!(merged.build().border);
+    }
+
+    #[test]
+    fn command_line_takes_precedence() {
+        let options = file_options(
+            r#"{"target_size": 40, "invert": true}"#,
+            true,
+            &crate::cli::build_cli(),
+        )
+        .unwrap()
+        .unwrap();
+        let mut command_line = OptionBuilder::new();
+        command_line.target_size(std::num::NonZeroU32::new(60).unwrap());
+        let merged = options.merge(
+            &command_line,
+            &crate::cli::build_cli().get_matches_from(["artem", "img.png", "--size", "60"]),
+        );
+        assert_eq!(60, merged.build().target_size);
+        assert!(merged.build().invert);
+    }
+
+    #[test]
+    fn argument_format() {
+        assert_eq!(
+            Ok(None),
+            file_options("size = 40\nborder = true", false, &crate::cli::build_cli())
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        assert_eq!(
+            Ok(None),
+            file_options(
+                "target_size = 40\nunknown = true",
+                false,
+                &crate::cli::build_cli()
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_value() {
+        assert!(file_options("target_size = \"large\"", false, &crate::cli::build_cli()).is_err());
+    }
+}
+
+/// Return if the two arguments can not be used together.
+///
+/// A conflict is only declared on one of the arguments, so both directions are checked.
+fn conflicting(cmd: &Command, arg: &Arg, other: &Arg) -> bool {
+    cmd.get_arg_conflicts_with(arg)
+        .iter()
+        .any(|conflict| conflict.get_id() == other.get_id())
+        || cmd
+            .get_arg_conflicts_with(other)
+            .iter()
+            .any(|conflict| conflict.get_id() == arg.get_id())
+}
+
+/// Read the key-value pairs of a toml config file.
+fn parse_toml(content: &str) -> Result<Vec<(String, ConfigValue)>, String> {
+    let table = content
+        .parse::<toml::Value>()
+        .map_err(|err| err.to_string())?;
+    let table = table.as_table().ok_or("Expected a table")?;
+
+    table
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                toml::Value::Boolean(value) => ConfigValue::Flag(*value),
+                toml::Value::String(value) => ConfigValue::Value(value.clone()),
+                toml::Value::Integer(value) => ConfigValue::Value(value.to_string()),
+                toml::Value::Float(value) => ConfigValue::Value(value.to_string()),
+                _ => return Err(format!("Unsupported value for {key}")),
+            };
+            Ok((key.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_parse_toml {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(
+            Ok(vec![
+                ("border".to_string(), ConfigValue::Flag(true)),
+                ("ratio".to_string(), ConfigValue::Value("0.5".to_string())),
+                ("size".to_string(), ConfigValue::Value("40".to_string())),
+            ]),
+            parse_toml("size = 40\nborder = true\nratio = 0.5")
+        );
+    }
+
+    #[test]
+    fn unsupported_value() {
+        assert!(parse_toml("size = [40]").is_err());
+    }
+}
+
+/// Read the key-value pairs of a json config file.
+fn parse_json(content: &str) -> Result<Vec<(String, ConfigValue)>, String> {
+    let object: serde_json::Value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+    let object = object.as_object().ok_or("Expected an object")?;
+
+    object
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::Bool(value) => ConfigValue::Flag(*value),
+                serde_json::Value::String(value) => ConfigValue::Value(value.clone()),
+                serde_json::Value::Number(value) => ConfigValue::Value(value.to_string()),
+                _ => return Err(format!("Unsupported value for {key}")),
+            };
+            Ok((key.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_parse_json {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(
+            Ok(vec![
+                ("border".to_string(), ConfigValue::Flag(true)),
+                ("size".to_string(), ConfigValue::Value("40".to_string())),
+            ]),
+            parse_json(r#"{"size": 40, "border": true}"#)
+        );
+    }
+
+    #[test]
+    fn unsupported_value() {
+        assert!(parse_json(r#"{"size": null}"#).is_err());
+    }
+}
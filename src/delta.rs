@@ -0,0 +1,136 @@
+//! Differences between the converted frames of an animation, so only the changed cells have to be drawn.
+
+/// Characters and colors of a converted image, row by row.
+///
+/// # Examples
+/// ```
+/// use artem::delta::Grid;
+///
+/// let grid = Grid::from_layers(&["ab".to_string()], &[vec![[0, 0, 0], [255, 0, 0]]]);
+/// assert_eq!(Some(('b', [255, 0, 0])), grid.get(0, 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Grid {
+    pub rows: Vec<Vec<(char, [u8; 3])>>,
+}
+
+impl Grid {
+    /// Create the grid from the aligned layers of [`crate::convert_layers`].
+    pub fn from_layers(chars: &[String], colors: &[Vec<[u8; 3]>]) -> Grid {
+        Grid {
+            rows: chars
+                .iter()
+                .zip(colors)
+                .map(|(row_chars, row_colors)| {
+                    row_chars.chars().zip(row_colors.iter().copied()).collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the char and the color of the cell, or `None` if it is outside of the grid.
+    pub fn get(&self, row: usize, column: usize) -> Option<(char, [u8; 3])> {
+        self.rows.get(row)?.get(column).copied()
+    }
+
+    /// Return the number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.rows.iter().map(Vec::len).sum()
+    }
+
+    /// Return true, if the grid does not contain any cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A cell, which is different from the previous frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub row: usize,
+    pub column: usize,
+    pub char: char,
+    pub color: [u8; 3],
+}
+
+/// Changed cells of a frame, together with the full grid, which is compared with the next frame.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeltaFrame {
+    pub changes: Vec<Change>,
+    pub grid: Grid,
+}
+
+/// Return the cells of the `next` grid, which are different from the `previous` one.
+///
+/// Cells outside of the `previous` grid are always changed, so an empty grid returns every cell.
+///
+/// # Examples
+/// ```
+/// use artem::delta::{diff, Grid};
+///
+/// let previous = Grid::from_layers(&["ab".to_string()], &[vec![[0; 3], [0; 3]]]);
+/// let next = Grid::from_layers(&["ac".to_string()], &[vec![[0; 3], [0; 3]]]);
+/// assert_eq!(1, diff(&previous, next).changes.len());
+/// ```
+pub fn diff(previous: &Grid, next: Grid) -> DeltaFrame {
+    let mut changes = Vec::new();
+    for (row, cells) in next.rows.iter().enumerate() {
+        for (column, &(char, color)) in cells.iter().enumerate() {
+            if previous.get(row, column) != Some((char, color)) {
+                changes.push(Change {
+                    row,
+                    column,
+                    char,
+                    color,
+                });
+            }
+        }
+    }
+    DeltaFrame {
+        changes,
+        grid: next,
+    }
+}
+
+#[cfg(test)]
+mod test_diff {
+    use super::*;
+
+    fn grid(chars: &str, color: [u8; 3]) -> Grid {
+        Grid::from_layers(&[chars.to_string()], &[vec![color; chars.chars().count()]])
+    }
+
+    #[test]
+    fn same_grid() {
+        let previous = grid("abc", [0; 3]);
+        assert!(diff(&previous, previous.clone()).changes.is_empty());
+    }
+
+    #[test]
+    fn changed_color() {
+        let delta = diff(&grid("abc", [0; 3]), grid("abc", [1, 0, 0]));
+        assert_eq!(3, delta.changes.len());
+    }
+
+    #[test]
+    fn changed_char() {
+        let delta = diff(&grid("abc", [0; 3]), grid("abd", [0; 3]));
+        assert_eq!(
+            vec![Change {
+                row: 0,
+                column: 2,
+                char: 'd',
+                color: [0; 3]
+            }],
+            delta.changes
+        );
+    }
+
+    #[test]
+    fn empty_previous() {
+        let next = grid("abc", [0; 3]);
+        let delta = diff(&Grid::default(), next.clone());
+        assert_eq!(next.len(), delta.changes.len());
+        assert_eq!(next, delta.grid);
+    }
+}
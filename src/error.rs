@@ -0,0 +1,93 @@
+//! Errors, which are returned instead of converting the image.
+
+use std::fmt;
+
+/// Error of a conversion, which can not be completed.
+///
+/// # Examples
+/// ```
+/// use artem::error::ConvertError;
+///
+/// let error = ConvertError::BufferLength { expected: 16, actual: 12 };
+/// assert_eq!("The buffer has 12 bytes, but 16 are needed", error.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    /// The image has a width or height of 0.
+    EmptyImage,
+    /// The length of the pixel buffer does not match the dimensions of the image.
+    BufferLength { expected: usize, actual: usize },
+    /// The characters or one of the cycled ramps are empty.
+    EmptyCharacters,
+    /// The target size is 0.
+    ZeroTargetSize,
+    /// The scale is not a positive number, or so small, that a tile would be larger than the image.
+    InvalidScale(f32),
+    /// The kernel size of the blur is not odd.
+    EvenKernelSize(u32),
+    /// Dithering and the outline are both enabled.
+    DitherOutline,
+    /// The edges are padded, while the image should be resized as pixel art or in linear light.
+    PadResize,
+}
+
+impl ConvertError {
+    /// Return the exit code, which the program uses for this error.
+    ///
+    /// The codes are the ones of sysexits, like the rest of the program.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::error::ConvertError;
+    ///
+    /// assert_eq!(66, ConvertError::EmptyImage.exit_code());
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ConvertError::EmptyImage => 66,
+            ConvertError::EmptyCharacters
+            | ConvertError::DitherOutline
+            | ConvertError::PadResize => 64,
+            ConvertError::BufferLength { .. }
+            | ConvertError::ZeroTargetSize
+            | ConvertError::InvalidScale(_)
+            | ConvertError::EvenKernelSize(_) => 65,
+        }
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::EmptyImage => write!(f, "Image dimensions can not be 0"),
+            ConvertError::BufferLength { expected, actual } => {
+                write!(
+                    f,
+                    "The buffer has {actual} bytes, but {expected} are needed"
+                )
+            }
+            ConvertError::EmptyCharacters => write!(f, "Characters cannot be empty"),
+            ConvertError::ZeroTargetSize => write!(f, "The size can not be 0"),
+            ConvertError::InvalidScale(scale) => {
+                write!(
+                    f,
+                    "The scale {scale} is not a positive number or too small for the image"
+                )
+            }
+            ConvertError::EvenKernelSize(size) => {
+                write!(f, "The kernel size {size} is not odd")
+            }
+            ConvertError::DitherOutline => {
+                write!(f, "Dithering can not be combined with the outline")
+            }
+            ConvertError::PadResize => {
+                write!(
+                    f,
+                    "Padding the edges can not be combined with pixel art or linear downscaling, since the image is not resized"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
@@ -1,8 +1,10 @@
 use std::time::Instant;
 
-use image::{DynamicImage, GenericImageView, GrayImage, ImageBuffer};
+use image::{imageops::FilterType, DynamicImage, GenericImageView, GrayImage, ImageBuffer, Rgb};
 use log::{debug, info, trace};
 
+use crate::{options::LuminanceWeights, pixel};
+
 /// Filter an image using a technic similar to canny edge detection.
 ///
 /// The image will first be blurred and then as grayscale converted using the sobel operators.
@@ -11,41 +13,60 @@ use log::{debug, info, trace};
 ///
 /// When `hysteresis` is set to true, it will additionally use the hysteresis method to improve the outlines of the image.
 /// This will result in thinner lines, with less imperfections, but at the cost of less good looking ascii chars,
-/// since it will mostly consist of dots.
+/// since it will mostly consist of dots. The `hysteresis_gamma` is applied to the edge magnitudes before comparing them
+/// with the thresholds.
+///
+/// When `normalize` is set to true, the edge magnitudes are stretched to the full range, before applying the hysteresis.
+///
+/// The `kernel_size` is the width and height of the gaussian kernel, which is used for the blur.
+/// The `weights` are used to convert the colors to grayscale.
 ///
 /// # Example
 /// ```compile_fail, compile will fail, this is an internal example
-///  let outlined_image = edge_detection_filter(img, 4);
+///  let outlined_image = edge_detection_filter(img, true, 1f32, false, 3, LuminanceWeights::DEFAULT);
 /// ```
-pub fn edge_detection_filter(img: DynamicImage, hysteresis: bool) -> DynamicImage {
+pub fn edge_detection_filter(
+    img: DynamicImage,
+    hysteresis: bool,
+    hysteresis_gamma: f32,
+    normalize: bool,
+    kernel_size: u32,
+    weights: LuminanceWeights,
+) -> DynamicImage {
     //blur
-    let blurred_img = blur(img, 6.4f32);
+    let blurred_img = blur(img, 6.4f32, kernel_size);
     //apply sobel
-    let sobel_img = apply_sobel_kernel(blurred_img);
+    let sobel_img = apply_sobel_kernel(blurred_img, weights);
+    //stretch the faint edges
+    let sobel_img = if normalize {
+        stretch_contrast(sobel_img)
+    } else {
+        sobel_img
+    };
     //double threshold and hysteresis
     if hysteresis {
-        edge_tracking(sobel_img)
+        edge_tracking(sobel_img, hysteresis_gamma, weights)
     } else {
         sobel_img
     }
 }
 
-///Blur the given image using an gaussian blur, based on the given sigma.
+///Blur the given image using an gaussian blur, based on the given sigma and kernel size.
 ///
 /// This returns a new (blurred) image.
 ///
 /// # Examples
 /// ```compile_fail, compile will fail, this is an internal example
-/// let blurred = blur(image, 1.4f32, 4)
+/// let blurred = blur(image, 1.4f32, 3)
 /// ```
-fn blur(img: DynamicImage, sigma: f32) -> DynamicImage {
+fn blur(img: DynamicImage, sigma: f32, kernel_size: u32) -> DynamicImage {
     info!("Blurring image");
     //measure timing for this step
     trace!("Started time tracking for blurring");
     let now = Instant::now();
 
     debug!("Creating gauss kernel");
-    let kernel = create_gauss_kernel(sigma);
+    let kernel = create_gauss_kernel(sigma, kernel_size);
 
     let offset = (kernel.len() / 2) as u32;
 
@@ -110,20 +131,20 @@ mod test_blur {
     fn panic_sigma_0() {
         //create black image
         let img = DynamicImage::ImageRgb8(ImageBuffer::new(3, 3));
-        blur(img, 0f32);
+        blur(img, 0f32, 3);
     }
 
     #[test]
     #[should_panic]
     fn panic_sigma_negative() {
         let img = DynamicImage::ImageRgb8(ImageBuffer::new(3, 3));
-        blur(img, -1f32);
+        blur(img, -1f32, 3);
     }
 
     #[test]
     fn black_img_remains_black() {
         let img = DynamicImage::ImageRgb8(ImageBuffer::new(3, 3));
-        let blur = blur(img.clone(), 1.4f32);
+        let blur = blur(img.clone(), 1.4f32, 3);
         assert_eq!(img, blur);
     }
     #[test]
@@ -138,7 +159,7 @@ mod test_blur {
                 image::Rgb([0, 0, 0])
             }
         }));
-        let blur = blur(img.clone(), 1.4f32);
+        let blur = blur(img.clone(), 1.4f32, 3);
         assert_ne!(img, blur);
         let result = DynamicImage::ImageRgb8(ImageBuffer::from_fn(3, 3, |x, y| {
             if y == 1 && x == 1 {
@@ -151,35 +172,322 @@ mod test_blur {
         }));
         assert_eq!(result, blur);
     }
+
+    #[test]
+    fn larger_kernel_blurs_more() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::from_fn(9, 9, |x, y| {
+            if y == 4 && x == 4 {
+                image::Rgb([255, 255, 255])
+            } else {
+                image::Rgb([0, 0, 0])
+            }
+        }));
+        let small = blur(img.clone(), 1.4f32, 3);
+        let large = blur(img, 1.4f32, 5);
+        //the light is spread further, so the center is darker
+        assert!(large.get_pixel(4, 4).0[0] < small.get_pixel(4, 4).0[0]);
+        assert_eq!(0, small.get_pixel(2, 4).0[0]);
+        assert!(large.get_pixel(2, 4).0[0] > 0);
+    }
 }
 
-///Creates a gaussian kernel based on the given sigma.
+/// Repeat the image `factor` times along both axes.
+///
+/// The result is `factor` times as wide and as high as the given image. A factor of 1 returns the image unchanged.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let tiled = tile(image, 4);
+/// ```
+pub fn tile(img: DynamicImage, factor: u32) -> DynamicImage {
+    if factor <= 1 {
+        return img;
+    }
+
+    trace!("Repeating image {factor} times");
+    let (width, height) = img.dimensions();
+    DynamicImage::ImageRgba8(ImageBuffer::from_fn(
+        width * factor,
+        height * factor,
+        |x, y| img.get_pixel(x % width, y % height),
+    ))
+}
+
+#[cfg(test)]
+mod test_tile {
+    use super::*;
+
+    #[test]
+    fn factor_one_unchanged() {
+        let img = DynamicImage::new_rgb8(2, 3);
+        assert_eq!(img, tile(img.clone(), 1));
+    }
+
+    #[test]
+    fn repeats_pattern() {
+        let img = DynamicImage::ImageRgba8(ImageBuffer::from_fn(2, 2, |x, y| {
+            image::Rgba([(x * 100) as u8, (y * 100) as u8, 0, 255])
+        }));
+        let tiled = tile(img.clone(), 3);
+        assert_eq!((6, 6), tiled.dimensions());
+        for (x, y, pixel) in tiled.pixels() {
+            assert_eq!(img.get_pixel(x % 2, y % 2), pixel);
+        }
+    }
+}
+
+/// Resize the image to the exact dimensions, averaging the colors in linear light.
+///
+/// The color channels are converted from srgb to linear light, resized and converted back,
+/// so bright details are not darkened. The alpha channel is already linear and resized directly.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let resized = linear_thumbnail(&image, 80, 40);
+/// ```
+pub fn linear_thumbnail(img: &DynamicImage, width: u32, height: u32) -> DynamicImage {
+    trace!("Resizing image in linear light");
+    let mut linear = img.to_rgba32f();
+    for pixel in linear.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            *channel = pixel::srgb_to_linear(*channel);
+        }
+    }
+
+    //the thumbnail method only works with integer channels, so use a linear filter instead
+    let mut resized = image::imageops::resize(&linear, width, height, FilterType::Triangle);
+    for pixel in resized.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            *channel = pixel::linear_to_srgb(*channel);
+        }
+    }
+    DynamicImage::ImageRgba32F(resized).into_rgba8().into()
+}
+
+#[cfg(test)]
+mod test_linear_thumbnail {
+    use super::*;
+
+    /// Create a black and white checkerboard.
+    fn checkerboard() -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_fn(8, 8, |x, y| {
+            image::Luma([if (x + y) % 2 == 0 { 0 } else { 255 }])
+        }))
+    }
+
+    #[test]
+    fn checkerboard_is_mid_gray() {
+        let resized = linear_thumbnail(&checkerboard(), 1, 1);
+        let pixel = resized.get_pixel(0, 0);
+        //half of the light is srgb 188, not 128
+        assert!((186..=189).contains(&pixel.0[0]), "{pixel:?}");
+        assert_eq!(pixel.0[0], pixel.0[2]);
+        assert_eq!(255, pixel.0[3]);
+    }
+
+    #[test]
+    fn gamma_resize_is_darker() {
+        let resized = checkerboard().thumbnail_exact(1, 1);
+        assert!(resized.get_pixel(0, 0).0[0] < 140);
+    }
+
+    #[test]
+    fn keeps_dimensions() {
+        assert_eq!((3, 2), linear_thumbnail(&checkerboard(), 3, 2).dimensions());
+    }
+}
+
+/// Pad the image on the right and bottom to the given dimensions, by repeating the pixels at its edges.
+///
+/// The image is not resized, the dimensions have to be at least as large as the ones of the image.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let padded = pad_edges(&image, 80, 40);
+/// ```
+pub fn pad_edges(img: &DynamicImage, width: u32, height: u32) -> DynamicImage {
+    trace!("Padding image to {width}x{height}");
+    let max_x = img.width() - 1;
+    let max_y = img.height() - 1;
+    DynamicImage::ImageRgba8(ImageBuffer::from_fn(width, height, |x, y| {
+        img.get_pixel(x.min(max_x), y.min(max_y))
+    }))
+}
+
+#[cfg(test)]
+mod test_pad_edges {
+    use super::*;
+
+    #[test]
+    fn repeats_edges() {
+        let img = DynamicImage::ImageRgba8(ImageBuffer::from_fn(2, 2, |x, y| {
+            image::Rgba([(x * 100) as u8, (y * 100) as u8, 0, 255])
+        }));
+        let padded = pad_edges(&img, 4, 3);
+        assert_eq!((4, 3), padded.dimensions());
+        assert_eq!(img.get_pixel(1, 0), padded.get_pixel(3, 0));
+        assert_eq!(img.get_pixel(1, 1), padded.get_pixel(2, 2));
+        assert_eq!(img.get_pixel(0, 1), padded.get_pixel(0, 2));
+    }
+
+    #[test]
+    fn same_dimensions() {
+        let img = DynamicImage::new_rgba8(3, 2);
+        assert_eq!(img, pad_edges(&img, 3, 2));
+    }
+}
+
+/// Blend the transparent pixels of the image over the background color.
+///
+/// Images without an alpha channel are returned unchanged. The alpha channel is kept.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let filled = fill_transparency(image, Rgb([255, 255, 255]));
+/// ```
+pub fn fill_transparency(img: DynamicImage, background: Rgb<u8>) -> DynamicImage {
+    if !img.color().has_alpha() {
+        return img;
+    }
+    trace!("Filling transparent pixels with {background:?}");
+    let mut img = img.into_rgba8();
+    for pixel in img.pixels_mut() {
+        *pixel = pixel::composite(*pixel, background);
+    }
+    DynamicImage::ImageRgba8(img)
+}
+
+#[cfg(test)]
+mod test_fill_transparency {
+    use super::*;
+
+    #[test]
+    fn transparent_is_filled() {
+        let img =
+            DynamicImage::ImageRgba8(ImageBuffer::from_pixel(2, 2, image::Rgba([0, 0, 0, 0])));
+        let filled = fill_transparency(img, Rgb([255, 255, 255]));
+        assert_eq!(image::Rgba([255, 255, 255, 0]), filled.get_pixel(1, 1));
+    }
+
+    #[test]
+    fn opaque_is_unchanged() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(2, 2, Rgb([10, 20, 30])));
+        assert_eq!(img, fill_transparency(img.clone(), Rgb([255, 255, 255])));
+    }
+}
+
+/// Sharpen the given image using unsharp masking.
+///
+/// The image is blurred and the difference between the original and the blurred image
+/// is added back onto the original, scaled by `amount`:
+/// `sharp = original + amount * (original - blurred)`.
+/// This makes edges and fine textures more pronounced. The alpha channel is not changed.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let sharpened = sharpen(image, 1.5f32, 3);
+/// ```
+pub fn sharpen(img: DynamicImage, amount: f32, kernel_size: u32) -> DynamicImage {
+    info!("Sharpening image");
+    trace!("Started time tracking for sharpening");
+    let now = Instant::now();
+
+    let blurred_img = blur(img.clone(), 1.4f32, kernel_size);
+    let mut destination_img = img.into_rgba8();
+
+    for (x, y, pixel) in destination_img.enumerate_pixels_mut() {
+        let blurred_pixel = blurred_img.get_pixel(x, y);
+        //only sharpen the rgb channels, alpha is kept as it is
+        for channel in 0..3 {
+            let original = pixel.0[channel] as f32;
+            let difference = original - blurred_pixel.0[channel] as f32;
+            pixel.0[channel] = (original + amount * difference).round().clamp(0f32, 255f32) as u8;
+        }
+    }
+
+    info!(
+        "Successfully sharpened image in {:3} ms",
+        now.elapsed().as_millis()
+    );
+    DynamicImage::ImageRgba8(destination_img)
+}
+
+#[cfg(test)]
+mod test_sharpen {
+    use super::*;
+
+    /// Image with a soft, blurry edge from dark to light.
+    fn soft_edge_img() -> DynamicImage {
+        DynamicImage::ImageRgb8(ImageBuffer::from_fn(6, 3, |x, _| {
+            let value = [50, 50, 100, 150, 200, 200][x as usize];
+            image::Rgb([value, value, value])
+        }))
+    }
+
+    #[test]
+    fn amount_zero_does_not_change_img() {
+        let img = soft_edge_img();
+        assert_eq!(img.to_rgba8(), sharpen(img.clone(), 0f32, 3).to_rgba8());
+    }
+
+    #[test]
+    fn black_img_remains_black() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::new(3, 3));
+        assert_eq!(img.to_rgba8(), sharpen(img.clone(), 2f32, 3).to_rgba8());
+    }
+
+    #[test]
+    fn edges_have_more_variation() {
+        let sharpened = sharpen(soft_edge_img(), 1f32, 3);
+        //the dark side of the edge gets darker, the light side lighter
+        let row: Vec<u8> = (0..6).map(|x| sharpened.get_pixel(x, 1).0[0]).collect();
+        assert!(row[1] < 50, "dark side was not darkened: {:?}", row);
+        assert!(row[4] > 200, "light side was not lightened: {:?}", row);
+        assert!(row[4] - row[1] > 150);
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        let img = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(
+            3,
+            3,
+            image::Rgba([100, 100, 100, 42]),
+        ));
+        assert_eq!(42, sharpen(img, 1f32, 3).get_pixel(1, 1).0[3]);
+    }
+}
+
+///Creates a gaussian kernel with the given odd size, based on the given sigma.
 ///
 /// This is based on the c++ implementation on <https://www.geeksforgeeks.org/gaussian-filter-generation-c/>
 ///
 /// # Panics
-/// This will panic if the given `sigma` is smaller or equal to zero.
+/// This will panic if the given `sigma` is smaller or equal to zero or if the `size` is even.
 ///
 /// # Examples
 /// ```compile_fail, compile will fail, this is an internal example
-/// let kernel = create_gauss_kernel(1.4f32);
+/// let kernel = create_gauss_kernel(1.4f32, 3);
 /// ```
-fn create_gauss_kernel(sigma: f32) -> [[f32; 3]; 3] {
+fn create_gauss_kernel(sigma: f32, size: u32) -> Vec<Vec<f32>> {
     if sigma <= 0f32 {
         panic!("The given sigma {} was smaller or equal to zero", sigma)
     }
-    let mut kernel = [[0f32; 3]; 3];
+    if size % 2 == 0 {
+        panic!("The given kernel size {} is not odd", size)
+    }
+    let mut kernel = vec![vec![0f32; size as usize]; size as usize];
+    let radius = (size / 2) as isize;
 
     let mut r = 2f32 * sigma * sigma;
     let s = r;
 
     let mut sum = 0f32;
 
-    for x in -1..=1isize {
-        for y in -1..=1isize {
+    for x in -radius..=radius {
+        for y in -radius..=radius {
             r = ((x * x + y * y) as f32).sqrt();
             let value = (f32::exp(-(r * r) / s)) / (std::f32::consts::PI * s);
-            kernel[(x + 1) as usize][(y + 1) as usize] = value;
+            kernel[(x + radius) as usize][(y + radius) as usize] = value;
             sum += value;
         }
     }
@@ -200,37 +508,55 @@ mod test_create_gauss_kernel {
     #[test]
     #[should_panic]
     fn sigma_zero_panics() {
-        create_gauss_kernel(0f32);
+        create_gauss_kernel(0f32, 3);
     }
 
     #[test]
     #[should_panic]
     fn sigma_minus_one_panics() {
-        create_gauss_kernel(-1f32);
+        create_gauss_kernel(-1f32, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_size_panics() {
+        create_gauss_kernel(1.4f32, 4);
     }
 
     #[test]
     fn sigma_1_4() {
         assert_eq!(
-            [
-                [0.09235313, 0.119190335, 0.09235313],
-                [0.119190335, 0.15382625, 0.119190335],
-                [0.09235313, 0.119190335, 0.09235313]
+            vec![
+                vec![0.09235313, 0.119190335, 0.09235313],
+                vec![0.119190335, 0.15382625, 0.119190335],
+                vec![0.09235313, 0.119190335, 0.09235313]
             ],
-            create_gauss_kernel(1.4f32)
+            create_gauss_kernel(1.4f32, 3)
         )
     }
+
+    #[test]
+    fn size_5() {
+        let kernel = create_gauss_kernel(1.4f32, 5);
+        assert_eq!(5, kernel.len());
+        assert!(kernel.iter().all(|row| row.len() == 5));
+        let sum: f32 = kernel.iter().flatten().sum();
+        assert!((sum - 1f32).abs() < 1e-5);
+        //the weight is spread over more pixels, so the center has less
+        assert!(kernel[2][2] < create_gauss_kernel(1.4f32, 3)[1][1]);
+    }
 }
 
 /// Detect edges in an image by using the sobel operators.
 ///
 /// This returns a new, grayscale image with only the edges in white visible.
+/// The colors are converted to grayscale using the given `weights`.
 ///
 /// # Examples
 /// ```compile_fail, compile will fail, this is an internal example
-/// let outline = edge_detection(image, 4)
+/// let outline = apply_sobel_kernel(image, LuminanceWeights::DEFAULT)
 /// ```
-fn apply_sobel_kernel(img: DynamicImage) -> DynamicImage {
+fn apply_sobel_kernel(img: DynamicImage, weights: LuminanceWeights) -> DynamicImage {
     info!("Creating outline image");
     //create stop watch
     trace!("Started time tracking for sobel");
@@ -273,7 +599,8 @@ fn apply_sobel_kernel(img: DynamicImage) -> DynamicImage {
 
                 //get the current pixel, it will always be inside, since of the previous clamping
                 let pixel = img.get_pixel(pixel_pos_x, pixel_pos_y);
-                let pixel_gray = crate::pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2]);
+                let pixel_gray =
+                    crate::pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2], weights);
 
                 //add rgb values
                 kernel_values_x += pixel_gray as f32 * kernel_x[k_x][k_y];
@@ -311,7 +638,7 @@ mod test_sobel {
     fn no_edge() {
         //create empty image with no edge
         let img = DynamicImage::ImageLuma8(ImageBuffer::new(3, 3));
-        let edge_img = apply_sobel_kernel(img.clone());
+        let edge_img = apply_sobel_kernel(img.clone(), LuminanceWeights::DEFAULT);
         assert_eq!(img, edge_img);
     }
 
@@ -328,7 +655,7 @@ mod test_sobel {
                 image::Luma([255u8])
             }
         }));
-        let edge_img = apply_sobel_kernel(img.clone());
+        let edge_img = apply_sobel_kernel(img.clone(), LuminanceWeights::DEFAULT);
         assert_eq!(img, edge_img);
     }
 
@@ -345,11 +672,79 @@ mod test_sobel {
                 image::Luma([255u8])
             }
         }));
-        let edge_img = apply_sobel_kernel(img.clone());
+        let edge_img = apply_sobel_kernel(img.clone(), LuminanceWeights::DEFAULT);
         assert_eq!(img, edge_img);
     }
 }
 
+/// Stretch the brightness of the grayscale image, so the darkest pixel is black and the brightest pixel is white.
+///
+/// Images with a single brightness are returned unchanged, since they can not be stretched.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// let stretched = stretch_contrast(sobel_img);
+/// ```
+fn stretch_contrast(img: DynamicImage) -> DynamicImage {
+    let mut gray = img.into_luma8();
+    let min = gray.pixels().map(|pixel| pixel.0[0]).min().unwrap_or(0);
+    let max = gray.pixels().map(|pixel| pixel.0[0]).max().unwrap_or(0);
+    debug!("Stretching edge magnitudes from {min} - {max}");
+    if max > min {
+        let range = (max - min) as f32;
+        for pixel in gray.pixels_mut() {
+            pixel.0[0] = ((pixel.0[0] - min) as f32 / range * 255f32).round() as u8;
+        }
+    }
+    DynamicImage::ImageLuma8(gray)
+}
+
+#[cfg(test)]
+mod test_stretch_contrast {
+    use super::*;
+
+    #[test]
+    fn stretches_to_full_range() {
+        let img = DynamicImage::ImageLuma8(ImageBuffer::from_fn(3, 1, |x, _| {
+            image::Luma([10 + x as u8 * 15])
+        }));
+        let stretched = stretch_contrast(img).into_luma8();
+        assert_eq!(vec![0, 128, 255], stretched.into_raw());
+    }
+
+    #[test]
+    fn single_brightness_unchanged() {
+        let img = DynamicImage::ImageLuma8(ImageBuffer::from_pixel(3, 3, image::Luma([40u8])));
+        assert_eq!(img, stretch_contrast(img.clone()));
+    }
+
+    #[test]
+    fn low_contrast_edge_uses_more_range() {
+        //a faint vertical edge between two similar grays
+        let img = DynamicImage::ImageRgb8(ImageBuffer::from_fn(30, 30, |x, _| {
+            if x < 15 {
+                image::Rgb([100u8; 3])
+            } else {
+                image::Rgb([110u8; 3])
+            }
+        }));
+        let max = |img: DynamicImage| img.into_luma8().pixels().map(|pixel| pixel.0[0]).max();
+
+        let outline = edge_detection_filter(
+            img.clone(),
+            false,
+            1f32,
+            false,
+            3,
+            LuminanceWeights::DEFAULT,
+        );
+        let normalized =
+            edge_detection_filter(img, false, 1f32, true, 3, LuminanceWeights::DEFAULT);
+        assert!(max(outline).unwrap() < 128);
+        assert_eq!(Some(255), max(normalized));
+    }
+}
+
 /// Apply double threshold and hysteresis to the image to remove small imperfections and complete
 /// the edges.
 ///
@@ -357,11 +752,15 @@ mod test_sobel {
 /// This method uses two thresholds to determine between strong, weak and irrelevant pixels.weak ones can be converted into strong ones,
 /// if at least one neighboring pixel is strong. Every non-strong pixel will be removed.
 ///
+/// The edge magnitudes are not gamma encoded, so the `gamma` is applied to them before comparing them with the thresholds.
+/// A gamma larger than 1.0 lifts weak edges, so more of them survive, a gamma of 1.0 uses the magnitudes unchanged.
+/// The magnitudes are read using the given `weights`.
+///
 /// # Examples
 /// ```compile_fail, compile will fail, this is an internal example
-/// let hysteresis_img = edge_tracking(img);
+/// let hysteresis_img = edge_tracking(img, 1f32, LuminanceWeights::DEFAULT);
 /// ```
-fn edge_tracking(img: DynamicImage) -> DynamicImage {
+fn edge_tracking(img: DynamicImage, gamma: f32, weights: LuminanceWeights) -> DynamicImage {
     //start tracking to for this step
     trace!("Started time tracking for hysteresis");
     let now = Instant::now();
@@ -374,8 +773,14 @@ fn edge_tracking(img: DynamicImage) -> DynamicImage {
     let lower_threshold = u8::MAX as f32 * 0.3;
     debug!("Lower threshold: {}", lower_threshold);
 
+    //gamma adjusted edge magnitude of a pixel
+    let magnitude = |pixel: image::Rgba<u8>| {
+        let luminosity = crate::pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2], weights);
+        u8::MAX as f32 * (luminosity / u8::MAX as f32).powf(1f32 / gamma)
+    };
+
     img.pixels().into_iter().for_each(|(x, y, pixel)| {
-        let grayscale_pixel = crate::pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2]);
+        let grayscale_pixel = magnitude(pixel);
 
         //check if pixel is at least weak or strong
         if grayscale_pixel >= upper_threshold {
@@ -395,7 +800,7 @@ fn edge_tracking(img: DynamicImage) -> DynamicImage {
 
                     //get the adjacent pixel to target pixel, it will always be inside, since of the previous clamping
                     let pixel = img.get_pixel(pixel_pos_x, pixel_pos_y);
-                    let pixel_gray = crate::pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2]);
+                    let pixel_gray = magnitude(pixel);
 
                     if pixel_gray >= upper_threshold {
                         //adjacent pixel is strong, so target pixel should be strong as well
@@ -433,7 +838,7 @@ mod test_edge_detection {
     #[test]
     fn no_strong_results_in_black_img() {
         let img = DynamicImage::ImageLuma8(ImageBuffer::new(3, 3));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         assert_eq!(img, result);
     }
 
@@ -447,7 +852,7 @@ mod test_edge_detection {
                 image::Luma([0u8])
             }
         }));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         assert_eq!(img, result);
     }
 
@@ -461,7 +866,7 @@ mod test_edge_detection {
                 image::Luma([0u8])
             }
         }));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         //result is equal to a black image
         assert_eq!(DynamicImage::ImageLuma8(ImageBuffer::new(3, 3)), result);
     }
@@ -476,7 +881,7 @@ mod test_edge_detection {
                 image::Luma([0u8])
             }
         }));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         //result is equal to a black image
         assert_eq!(DynamicImage::ImageLuma8(ImageBuffer::new(3, 3)), result);
     }
@@ -503,7 +908,7 @@ mod test_edge_detection {
                 image::Luma([0u8])
             }
         }));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         //result is equal to a black image
         assert_eq!(desired_result, result);
     }
@@ -527,8 +932,50 @@ mod test_edge_detection {
                 image::Luma([0u8])
             }
         }));
-        let result = edge_tracking(img.clone());
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
         //result is equal to a black image
         assert_eq!(desired_result, result);
     }
+
+    #[test]
+    fn gamma_keeps_faint_pixel_with_strong_neighbor() {
+        let img = DynamicImage::ImageLuma8(ImageBuffer::from_fn(3, 3, |x, y| {
+            if x == 1 && y == 1 {
+                image::Luma([60u8])
+            } else if x == 2 && y == 1 {
+                image::Luma([255u8])
+            } else {
+                image::Luma([0u8])
+            }
+        }));
+
+        //without gamma, the faint pixel is irrelevant and removed
+        let result = edge_tracking(img.clone(), 1f32, LuminanceWeights::DEFAULT);
+        assert_eq!(
+            image::Luma([0u8]),
+            result.to_luma8().get_pixel(1, 1).to_owned()
+        );
+
+        //the gamma lifts it to a weak pixel, which survives due to the strong neighbor
+        let result = edge_tracking(img, 1.5f32, LuminanceWeights::DEFAULT);
+        assert_eq!(
+            image::Luma([255u8]),
+            result.to_luma8().get_pixel(1, 1).to_owned()
+        );
+    }
+
+    #[test]
+    fn gamma_does_not_change_black_and_white() {
+        let img = DynamicImage::ImageLuma8(ImageBuffer::from_fn(3, 3, |x, y| {
+            if x == 1 && y == 1 {
+                image::Luma([255u8])
+            } else {
+                image::Luma([0u8])
+            }
+        }));
+        assert_eq!(
+            img,
+            edge_tracking(img.clone(), 2.2f32, LuminanceWeights::DEFAULT)
+        );
+    }
 }
@@ -0,0 +1,155 @@
+//! Sending the original image to terminals, which support displaying images inline.
+//!
+//! Two protocols are supported, the Kitty graphics protocol and the inline images protocol of iTerm2.
+
+use std::io::Cursor;
+
+use image::{DynamicImage, ImageOutputFormat};
+
+/// Protocol for displaying images inline in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Kitty,
+    ITerm,
+}
+
+/// Detect the inline image protocol supported by the current terminal.
+///
+/// Returns `None`, when the terminal does not support inline images or it can not be detected.
+pub fn detect() -> Option<Protocol> {
+    let env = |key: &str| std::env::var(key).unwrap_or_default();
+    if env("TERM") == "xterm-kitty" || !env("KITTY_WINDOW_ID").is_empty() {
+        Some(Protocol::Kitty)
+    } else if env("TERM_PROGRAM") == "iTerm.app" || env("LC_TERMINAL") == "iTerm2" {
+        Some(Protocol::ITerm)
+    } else {
+        None
+    }
+}
+
+/// Return the escape sequence, which displays the image using the given protocol.
+///
+/// The image is sent as png.
+/// On failure, an error message and the exit code are returned.
+pub fn escape_sequence(image: &DynamicImage, protocol: Protocol) -> Result<String, (String, i32)> {
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)
+        .map_err(|err| (format!("Could not encode image: {err}"), 70))?;
+
+    Ok(match protocol {
+        Protocol::Kitty => kitty_sequence(&bytes),
+        Protocol::ITerm => iterm_sequence(&bytes),
+    })
+}
+
+/// Return the Kitty graphics protocol sequence for the png data.
+///
+/// The data is split into chunks of 4096 bytes, every chunk except the last one is marked with `m=1`.
+fn kitty_sequence(png: &[u8]) -> String {
+    let encoded = base64(png);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(4096).collect();
+    let mut sequence = String::with_capacity(encoded.len() + chunks.len() * 24);
+    for (index, chunk) in chunks.iter().enumerate() {
+        //only the first chunk contains the format and action
+        if index == 0 {
+            sequence.push_str("\x1b_Gf=100,a=T,");
+        } else {
+            sequence.push_str("\x1b_G");
+        }
+        sequence.push_str(if index + 1 < chunks.len() {
+            "m=1;"
+        } else {
+            "m=0;"
+        });
+        //base64 only contains ascii characters
+        sequence.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+        sequence.push_str("\x1b\\");
+    }
+    sequence
+}
+
+#[cfg(test)]
+mod test_kitty_sequence {
+    use super::*;
+
+    #[test]
+    fn single_chunk() {
+        assert_eq!("\x1b_Gf=100,a=T,m=0;YWJj\x1b\\", kitty_sequence(b"abc"));
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        let sequence = kitty_sequence(&[0u8; 4000]);
+        assert!(sequence.starts_with("\x1b_Gf=100,a=T,m=1;"));
+        assert!(sequence.contains("\x1b\\\x1b_Gm=0;"));
+        assert_eq!(2, sequence.matches("\x1b_G").count());
+    }
+}
+
+/// Return the iTerm2 inline image sequence for the png data.
+fn iterm_sequence(png: &[u8]) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={}:{}\x07",
+        png.len(),
+        base64(png)
+    )
+}
+
+#[cfg(test)]
+mod test_iterm_sequence {
+    use super::*;
+
+    #[test]
+    fn contains_size_and_data() {
+        assert_eq!(
+            "\x1b]1337;File=inline=1;size=3:YWJj\x07",
+            iterm_sequence(b"abc")
+        );
+    }
+}
+
+/// Encode the bytes using the standard base64 alphabet with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for index in 0..4 {
+            //a chunk of n bytes is encoded using n + 1 characters
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(value >> (18 - index * 6)) as usize & 0x3f] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test_base64 {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!("", base64(b""));
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!("Zg==", base64(b"f"));
+        assert_eq!("Zm8=", base64(b"fo"));
+        assert_eq!("Zm9v", base64(b"foo"));
+        assert_eq!("Zm9vYmFy", base64(b"foobar"));
+    }
+
+    #[test]
+    fn all_characters() {
+        assert_eq!("+/8=", base64(&[0xfb, 0xff]));
+    }
+}
@@ -18,18 +18,37 @@ pub mod util;
 //condense all arguments into a single struct
 pub mod options;
 
-//functions for working with pixels
-mod pixel;
+//functions for working with pixels, public to allow benchmarking them
+pub mod pixel;
+
+//errors of the conversion
+pub mod error;
+
+//changed cells between frames
+pub mod delta;
 
 //outlining filter
 mod filter;
+//reducing colors to a palette
+mod palette;
 //functions for dealing with output targets/files
 mod target;
 
-use image::{DynamicImage, GenericImageView};
-use log::{debug, info, trace};
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use image::{DynamicImage, Frame, GenericImageView, Rgba};
+use log::{debug, info, trace, warn};
+
+use crate::{
+    error::ConvertError,
+    options::{ColorMode, EdgeMode, Option, TargetType},
+};
 
-use crate::options::{Option, TargetType};
+/// Minimum edge magnitude of a cell, so it keeps its color when only coloring the edges.
+const EDGE_THRESHOLD: f32 = u8::MAX as f32 * 0.1;
 
 /// Takes an image and returns it as an ascii art string.
 ///
@@ -43,183 +62,781 @@ use crate::options::{Option, TargetType};
 /// ```
 ///It uses the [`Option`] to set specific options on how to convert the image.
 pub fn convert(image: DynamicImage, options: Option) -> String {
+    let mut output = Vec::new();
+    //writing to a vec can not fail and the output is always valid utf-8
+    let _ = convert_to_writer(image, &options, &mut output);
+    String::from_utf8(output).unwrap_or_default()
+}
+
+/// Takes an image and writes it as ascii art to the writer.
+///
+/// This is the same as [`convert`], but the output is written row by row, instead of being collected into a single string,
+/// so large images or animations can be written directly to a file or a socket.
+///
+/// # Errors
+/// Returns an error, if writing to the writer fails.
+///
+/// # Examples
+/// ```
+/// use artem::options::{OptionBuilder, TargetType};
+///
+/// let img = image::DynamicImage::new_rgb8(400, 200);
+/// let mut builder = OptionBuilder::new();
+/// builder.target(TargetType::File);
+/// let options = builder.build();
+///
+/// let mut output = Vec::new();
+/// artem::convert_to_writer(img.clone(), &options, &mut output).unwrap();
+/// assert_eq!(artem::convert(img, options).into_bytes(), output);
+/// ```
+pub fn convert_to_writer<W: Write>(
+    image: DynamicImage,
+    options: &Option,
+    out: &mut W,
+) -> io::Result<()> {
+    let mut options = options.clone();
     debug!("Using inverted color: {}", options.invert);
-    //get img dimensions
-    let input_width = image.width();
-    let input_height = image.height();
-    debug!("Input Image Width: {input_width}");
-    debug!("Input Image Height: {input_height}");
+    let comment_prefix = comment_prefix(&mut options);
 
-    //calculate the needed dimensions
-    let (columns, rows, tile_width, tile_height) = util::calculate_dimensions(
-        options.target_size,
-        input_height,
-        input_width,
-        options.scale,
-        options.border,
-        options.dimension,
+    if options.auto_invert {
+        options.invert = dark_background(&image, options.luminance_weights);
+        debug!("Automatically inverted: {}", options.invert);
+    }
+
+    //half blocks need the colors for both halves of the tile
+    if options.half_blocks
+        && matches!(
+            options.target,
+            TargetType::Shell(true, _) | TargetType::AnsiFile(_)
+        )
+    {
+        return out.write_all(half_blocks(image, &options).as_bytes());
+    }
+
+    let (cells, columns, rows, glyphs) = calculate_cells(image, &options);
+    render_to(
+        &cells,
+        &glyphs,
+        columns,
+        rows,
+        &options,
+        &comment_prefix,
+        out,
+    )
+}
+
+#[cfg(test)]
+mod test_convert_to_writer {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    fn img() -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+        }))
+    }
+
+    /// Writer, which counts the number of writes.
+    struct CountingWriter {
+        data: Vec<u8>,
+        writes: usize,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.writes += 1;
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn same_as_convert() {
+        for target in [
+            TargetType::File,
+            TargetType::HtmlFile(true, false),
+            TargetType::Rle,
+            TargetType::JsonLines,
+        ] {
+            let mut builder = OptionBuilder::new();
+            builder
+                .target(target)
+                .border(true)
+                .target_size(NonZeroU32::new(30).unwrap());
+            let options = builder.build();
+
+            let mut output = Vec::new();
+            convert_to_writer(img(), &options, &mut output).unwrap();
+            assert_eq!(convert(img(), options).into_bytes(), output);
+        }
+    }
+
+    #[test]
+    fn writes_rows() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let options = builder.build();
+
+        let mut writer = CountingWriter {
+            data: Vec::new(),
+            writes: 0,
+        };
+        convert_to_writer(img(), &options, &mut writer).unwrap();
+        let rows = String::from_utf8(writer.data).unwrap().lines().count();
+        assert!(writer.writes >= rows);
+    }
+
+    #[test]
+    fn write_error() {
+        struct FailingWriter;
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "closed"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        assert!(
+            convert_to_writer(img(), &OptionBuilder::new().build(), &mut FailingWriter).is_err()
+        );
+    }
+}
+
+/// Takes an image and returns it as an ascii art string, or an error if it can not be converted.
+///
+/// This is the same as [`convert`], but the image and the options are checked first,
+/// so invalid values return an error, instead of panicking while converting.
+/// The library never exits the program, the binary uses [`ConvertError::exit_code`] to decide on the exit code.
+///
+/// # Errors
+/// Returns an error, if the image is empty, the characters are empty,
+/// the target size, the scale or the kernel size are invalid, or the options can not be combined.
+///
+/// # Examples
+/// ```
+/// use artem::{error::ConvertError, options::OptionBuilder};
+///
+/// let img = image::DynamicImage::new_rgb8(0, 0);
+/// assert_eq!(Err(ConvertError::EmptyImage), artem::try_convert(img, OptionBuilder::new().build()));
+/// ```
+pub fn try_convert(image: DynamicImage, options: Option) -> Result<String, ConvertError> {
+    validate(&image, &options)?;
+    Ok(convert(image, options))
+}
+
+/// Check the image and the options, which would otherwise panic when converting.
+fn validate(image: &DynamicImage, options: &Option) -> Result<(), ConvertError> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err(ConvertError::EmptyImage);
+    }
+    if options.characters.is_empty() || options.ramp_cycle.iter().any(String::is_empty) {
+        return Err(ConvertError::EmptyCharacters);
+    }
+    if options.target_size == 0 {
+        return Err(ConvertError::ZeroTargetSize);
+    }
+    if !(options.scale.is_finite() && options.scale > 0f32) {
+        return Err(ConvertError::InvalidScale(options.scale));
+    }
+    //a tiny scale results in a tile far larger than the image, which can not be allocated when resizing
+    let (_, _, tile_width, tile_height) = output_dimensions(image.width(), image.height(), options);
+    let max_tile = image.width().max(image.height()).saturating_mul(2);
+    if tile_width > max_tile || tile_height > max_tile {
+        return Err(ConvertError::InvalidScale(options.scale));
+    }
+    if options.kernel_size % 2 == 0 {
+        return Err(ConvertError::EvenKernelSize(options.kernel_size));
+    }
+    if options.dither && options.outline {
+        return Err(ConvertError::DitherOutline);
+    }
+    if options.edge_mode == EdgeMode::Pad && (options.pixel_art || options.linear_downscale) {
+        return Err(ConvertError::PadResize);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_try_convert {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn img() -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_pixel(30, 20, image::Rgb([128; 3])))
+    }
+
+    #[test]
+    fn valid_input() {
+        let options = OptionBuilder::new().build();
+        assert_eq!(
+            Ok(convert(img(), options.clone())),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn empty_image() {
+        assert_eq!(
+            Err(ConvertError::EmptyImage),
+            try_convert(DynamicImage::new_rgb8(0, 10), OptionBuilder::new().build())
+        );
+    }
+
+    #[test]
+    fn empty_characters() {
+        let mut options = OptionBuilder::new().build();
+        options.characters = String::new();
+        assert_eq!(
+            Err(ConvertError::EmptyCharacters),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn empty_ramp() {
+        let options = OptionBuilder::new()
+            .ramp_cycle(vec!["ab".to_string(), String::new()])
+            .build();
+        assert_eq!(
+            Err(ConvertError::EmptyCharacters),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn zero_target_size() {
+        let mut options = OptionBuilder::new().build();
+        options.target_size = 0;
+        assert_eq!(
+            Err(ConvertError::ZeroTargetSize),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn invalid_scale() {
+        let options = OptionBuilder::new().scale(-1f32).build();
+        assert_eq!(
+            Err(ConvertError::InvalidScale(-1f32)),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn large_scale() {
+        //the tiles would be less than a pixel high
+        let img = DynamicImage::new_rgb8(100, 100);
+        let options = OptionBuilder::new().scale(5f32).build();
+        assert!(try_convert(img, options).is_ok());
+    }
+
+    #[test]
+    fn tiny_scale() {
+        //the tiles would be far higher than the image
+        let img = DynamicImage::new_rgb8(100, 100);
+        let options = OptionBuilder::new().scale(1e-9).build();
+        assert_eq!(
+            Err(ConvertError::InvalidScale(1e-9)),
+            try_convert(img, options)
+        );
+    }
+
+    #[test]
+    fn even_kernel_size() {
+        let mut options = OptionBuilder::new().build();
+        options.kernel_size = 4;
+        assert_eq!(
+            Err(ConvertError::EvenKernelSize(4)),
+            try_convert(img(), options)
+        );
+    }
+
+    #[test]
+    fn dither_outline() {
+        let mut builder = OptionBuilder::new();
+        builder.dither(true).outline(true);
+        assert_eq!(
+            Err(ConvertError::DitherOutline),
+            try_convert(img(), builder.build())
+        );
+    }
+
+    #[test]
+    fn pad_resize() {
+        let mut builder = OptionBuilder::new();
+        builder.edge_mode(options::EdgeMode::Pad).pixel_art(true);
+        assert_eq!(
+            Err(ConvertError::PadResize),
+            try_convert(img(), builder.build())
+        );
+        builder.pixel_art(false).linear_downscale(true);
+        assert_eq!(
+            Err(ConvertError::PadResize),
+            try_convert(img(), builder.build())
+        );
+    }
+}
+
+/// Takes a grid of pixels, with one pixel for every cell, and returns it as an ascii art string.
+///
+/// The `pixels` are given row by row, so the grid has to contain exactly `columns * rows` pixels.
+/// Unlike [`convert`], the grid is not resized, which allows using an own pipeline for the image handling,
+/// so only the characters and colors are calculated by artem.
+/// Options, which work on the full image, such as the outline, are ignored.
+///
+/// # Panics
+/// Panics if the number of pixels does not match the number of cells.
+///
+/// # Examples
+/// ```
+/// use artem::options::{OptionBuilder, TargetType};
+/// use image::Rgba;
+///
+/// let pixels = [
+///     Rgba([255, 255, 255, 255]),
+///     Rgba([0, 0, 0, 255]),
+///     Rgba([0, 0, 0, 255]),
+///     Rgba([255, 255, 255, 255]),
+/// ];
+/// let mut builder = OptionBuilder::new();
+/// builder.target(TargetType::File).characters("# ".to_string());
+///
+/// assert_eq!("# \n #", artem::convert_grid(&pixels, 2, 2, &builder.build()));
+/// ```
+pub fn convert_grid(pixels: &[Rgba<u8>], columns: u32, rows: u32, options: &Option) -> String {
+    assert_eq!(
+        (columns * rows) as usize,
+        pixels.len(),
+        "The grid has to contain one pixel for every cell"
     );
-    debug!("Columns: {columns}");
-    debug!("Rows: {rows}");
-    debug!("Tile Width: {tile_width}");
-    debug!("Tile Height: {tile_height}");
+    let mut options = options.clone();
+    let comment_prefix = comment_prefix(&mut options);
 
-    let mut input_img = image;
+    if options.auto_invert {
+        let image =
+            image::RgbaImage::from_fn(columns, rows, |x, y| pixels[(y * columns + x) as usize]);
+        options.invert =
+            dark_background(&DynamicImage::ImageRgba8(image), options.luminance_weights);
+        debug!("Automatically inverted: {}", options.invert);
+    }
 
-    if options.outline {
-        //create an outline using an algorithm loosely based on the canny edge algorithm
-        input_img = filter::edge_detection_filter(input_img, options.hysteresis);
+    let mut cells: Vec<pixel::Cell> = pixels
+        .iter()
+        .map(|pixel| {
+            let pixel = pixel::composite(*pixel, options.background_fill);
+            pixel::Cell::from_block(&[pixel], options.luminance_weights)
+        })
+        .collect();
+    adjust_cells(&mut cells, columns, &options);
+
+    render(&cells, &[], columns, rows, &options, &comment_prefix)
+}
+
+#[cfg(test)]
+mod test_convert_grid {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn same_as_convert_without_resizing() {
+        let img = image::RgbaImage::from_fn(4, 3, |x, y| {
+            image::Rgba([(x * 60) as u8, (y * 100) as u8, 50, 255])
+        });
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .dimension(util::ResizingDimension::Width)
+            .target_size(std::num::NonZeroU32::new(4).unwrap())
+            .scale(1f32);
+        let options = builder.build();
+
+        let pixels: Vec<Rgba<u8>> = img.pixels().copied().collect();
+        assert_eq!(
+            convert(DynamicImage::ImageRgba8(img), options.clone()),
+            convert_grid(&pixels, 4, 3, &options)
+        );
     }
 
-    if options.transform_x {
-        info!("Flipping image horizontally");
-        input_img = input_img.fliph();
+    #[test]
+    fn border() {
+        let pixels = [Rgba([255, 255, 255, 255]); 2];
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File).border(true);
+        let converted = convert_grid(&pixels, 2, 1, &builder.build());
+        assert_eq!(3, converted.lines().count());
     }
 
-    if options.transform_y {
-        info!("Flipping image vertically");
-        input_img = input_img.flipv();
+    #[test]
+    #[should_panic]
+    fn wrong_pixel_count() {
+        convert_grid(&[Rgba([0, 0, 0, 255])], 2, 2, &OptionBuilder::new().build());
     }
+}
 
-    info!("Resizing image to fit new dimensions");
-    //use the thumbnail method, since its way faster, it may result in artifacts, but the ascii art will be pixelate anyway
-    let source_img = input_img.thumbnail_exact(columns * tile_width, rows * tile_height);
+/// Takes a raw buffer of rgba pixels and returns it as an ascii art string.
+///
+/// The buffer contains the pixels row by row, with 4 bytes (red, green, blue and alpha) for every pixel.
+/// This is the same as [`convert`], but does not require creating an image first, for example when calling it from other languages.
+///
+/// # Errors
+/// Returns an error, if the image is empty or the length of the buffer does not match `width * height * 4`.
+/// The options are checked like by [`try_convert`].
+///
+/// # Examples
+/// ```
+/// use artem::options::{OptionBuilder, TargetType};
+///
+/// //a white and a black pixel
+/// let buffer = [255, 255, 255, 255, 0, 0, 0, 255];
+/// let mut builder = OptionBuilder::new();
+/// builder.target(TargetType::File).characters("# ".to_string());
+///
+/// assert!(artem::convert_rgba(2, 1, &buffer, &builder.build()).is_ok());
+/// ```
+pub fn convert_rgba(
+    width: u32,
+    height: u32,
+    buffer: &[u8],
+    options: &Option,
+) -> Result<String, ConvertError> {
+    if width == 0 || height == 0 {
+        return Err(ConvertError::EmptyImage);
+    }
 
-    debug!("Resized Image Width: {}", source_img.width());
-    debug!("Resized Image Height: {}", source_img.height());
+    let expected = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(4))
+        .unwrap_or(usize::MAX);
+    if buffer.len() != expected {
+        return Err(ConvertError::BufferLength {
+            expected,
+            actual: buffer.len(),
+        });
+    }
+
+    //the length is already checked, so creating the image can not fail
+    let image = image::RgbaImage::from_raw(width, height, buffer.to_vec()).ok_or(
+        ConvertError::BufferLength {
+            expected,
+            actual: buffer.len(),
+        },
+    )?;
+    try_convert(DynamicImage::ImageRgba8(image), options.clone())
+}
+
+#[cfg(test)]
+mod test_convert_rgba {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn same_as_convert() {
+        let img = image::RgbaImage::from_fn(20, 10, |x, y| {
+            image::Rgba([(x * 12) as u8, (y * 25) as u8, 50, 255])
+        });
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let options = builder.build();
 
-    //output string
-    let mut output = String::with_capacity((tile_width * tile_height) as usize);
+        assert_eq!(
+            Ok(convert(
+                DynamicImage::ImageRgba8(img.clone()),
+                options.clone()
+            )),
+            convert_rgba(20, 10, img.as_raw(), &options)
+        );
+    }
+
+    #[test]
+    fn length_mismatch() {
+        assert_eq!(
+            Err(ConvertError::BufferLength {
+                expected: 8,
+                actual: 7
+            }),
+            convert_rgba(2, 1, &[0; 7], &OptionBuilder::new().build())
+        );
+    }
+
+    #[test]
+    fn empty_image() {
+        assert_eq!(
+            Err(ConvertError::EmptyImage),
+            convert_rgba(0, 1, &[], &OptionBuilder::new().build())
+        );
+    }
+}
+
+/// Convert the cells, given row by row, to the output string of the target.
+///
+/// This adds everything around the characters of the cells, such as the spacing, the border and the legend.
+/// The cells with a glyph use it instead of the characters.
+fn render(
+    cells: &[pixel::Cell],
+    glyphs: &[std::option::Option<char>],
+    columns: u32,
+    rows: u32,
+    options: &Option,
+    comment_prefix: &str,
+) -> String {
+    let mut output = Vec::with_capacity(((columns + 1) * rows) as usize);
+    //writing to a vec can not fail and the output is always valid utf-8
+    let _ = render_to(
+        cells,
+        glyphs,
+        columns,
+        rows,
+        options,
+        comment_prefix,
+        &mut output,
+    );
+    String::from_utf8(output).unwrap_or_default()
+}
+
+/// Render the cells and write them to the writer.
+///
+/// The output is written row by row, so it is never stored completely.
+/// Only run-length encoded rows are written at once, since they are encoded after the rows have been created.
+fn render_to<W: Write>(
+    cells: &[pixel::Cell],
+    glyphs: &[std::option::Option<char>],
+    columns: u32,
+    rows: u32,
+    options: &Option,
+    comment_prefix: &str,
+    out: &mut W,
+) -> io::Result<()> {
+    if options.target == TargetType::JsonLines {
+        //the rows are written without any decorations, so every line is a valid JSON object
+        return out.write_all(json_lines(cells, glyphs, columns, options).as_bytes());
+    }
+
+    //the rle rows are encoded at the end, so they have to be kept until then
+    let stream = options.target != TargetType::Rle;
+
+    //output string of the current row, with space for every char and the line break
+    let mut output = String::with_capacity((columns + 1) as usize);
     trace!("Created output string");
 
+    if options.clear_screen
+        && std::mem::discriminant(&options.target)
+            == std::mem::discriminant(&TargetType::Shell(true, true))
+    {
+        trace!("Adding clear screen sequence");
+        //clear the whole screen and move the cursor to the top left
+        output.push_str("\x1b[2J\x1b[H");
+    }
+
     if std::mem::discriminant(&options.target)
         == std::mem::discriminant(&TargetType::HtmlFile(true, true))
     {
         trace!("Adding html top part");
-        output.push_str(&target::html::html_top());
+        output.push_str(&target::html::html_top(
+            options.line_height,
+            options.html_fragment,
+        ));
     }
 
+    //the ruler is only added to plain text, since it would break the markup of the other targets
+    let ruler =
+        options.ruler && matches!(options.target, TargetType::Shell(_, _) | TargetType::File);
+    let ruler_width = if ruler { util::ruler_width(rows) } else { 0 };
+    let legend =
+        options.legend && matches!(options.target, TargetType::Shell(_, _) | TargetType::File);
+
+    //wide characters take up two columns each
+    let char_width = if wide_characters(options) { 2 } else { 1 };
+    let (width, height) = displayed_size(
+        columns * char_width,
+        rows,
+        ruler_width as u32,
+        legend,
+        options.border,
+    );
+
     trace!("Calculating horizontal spacing");
-    let horizontal_spacing = if options.center_x {
-        util::spacing_horizontal(if options.border {
-            //two columns are missing because the border takes up two lines
-            columns + 2
-        } else {
-            columns
-        })
+    let horizontal_spacing = util::spacing_horizontal(width, options.anchor_x);
+
+    //only the shell can be moved vertically
+    let (top_spacing, bottom_spacing) = if std::mem::discriminant(&options.target)
+        == std::mem::discriminant(&TargetType::Shell(true, true))
+    {
+        util::spacing_vertical(height, options.anchor_y)
     } else {
-        String::with_capacity(0)
+        (String::with_capacity(0), String::with_capacity(0))
     };
 
-    if options.center_y
-        && std::mem::discriminant(&options.target)
-            == std::mem::discriminant(&TargetType::Shell(true, true))
-    {
-        trace!("Adding vertical top spacing");
-        output.push_str(&util::spacing_vertical(if options.border {
-            //two rows are missing because the border takes up two lines
-            rows + 2
-        } else {
-            rows
-        }));
+    trace!("Adding vertical top spacing");
+    output.push_str(&top_spacing);
+
+    info!("Starting conversion to ascii");
+
+    //the backdrop is only used for the colored shell output
+    let backdrop = match (options.backdrop, options.target) {
+        (Some(color), TargetType::Shell(true, _)) => target::ansi::backdrop_sequence(color),
+        _ => String::with_capacity(0),
+    };
+
+    if ruler {
+        trace!("Adding column ruler");
+        output.push_str(comment_prefix);
+        output.push_str(&horizontal_spacing);
+        //the left border is in front of the first column
+        output.push_str(&util::ruler_columns(
+            columns,
+            ruler_width + if options.border { 1 } else { 0 },
+        ));
+        output.push('\n');
     }
 
     if options.border {
-        //add spacing for centering
-        if options.center_x {
-            output.push_str(&horizontal_spacing);
-        }
+        //add spacing for the anchor
+        output.push_str(comment_prefix);
+        output.push_str(&horizontal_spacing);
+        output.push_str(&" ".repeat(ruler_width));
 
         //add top part of border before conversion
         trace!("Adding top part of border");
-        output.push('╔');
-        output.push_str("═".repeat(columns as usize).as_str());
-        output.push_str("╗\n");
+        let first_row = &cells[..columns as usize];
+        push_border('╔', &first_row[0], options, &mut output);
+        for cell in first_row {
+            for _ in 0..char_width {
+                push_border('═', cell, options, &mut output);
+            }
+        }
+        push_border('╗', &first_row[first_row.len() - 1], options, &mut output);
+        output.push('\n');
     }
 
-    info!("Starting conversion to ascii");
+    //the colors of the shell output are merged into runs, which only use a single color sequence
+    let color_runs = match (
+        options.max_color_switches_per_line,
+        options.target,
+        options.color_mode,
+    ) {
+        (Some(max_runs), TargetType::Shell(true, background_color), ColorMode::Auto) => {
+            //the chars of the runs are colored together, so they are created without colors
+            let plain = options
+                .to_builder()
+                .target(TargetType::Shell(false, background_color))
+                .build();
+            Some((max_runs, background_color, plain))
+        }
+        _ => None,
+    };
 
-    //convert source img to a target string
-    let target = source_img
-        .pixels()
-        .into_iter()
-        .step_by(tile_width as usize)
-        .filter_map(|(x, y, _)| {
-            if y % tile_height == 0 && x % tile_width == 0 {
-                //preallocate vector with the with space for all pixels in the tile
-                let mut pixels = Vec::with_capacity((tile_height * tile_width) as usize);
+    if stream {
+        out.write_all(output.as_bytes())?;
+        output.clear();
+    }
 
-                //get all pixel of the tile
-                for p_x in 0..tile_width {
-                    for p_y in 0..tile_height {
-                        pixels.push(source_img.get_pixel(x + p_x, y + p_y))
-                    }
-                }
+    //convert cells to a target string
+    for (index, row) in cells.chunks(columns as usize).enumerate() {
+        //the characters can change with every row
+        let density = options.row_characters(index);
 
-                //convert pixels to a char/string
-                let mut char = pixel::correlating_char(
-                    &pixels,
-                    options.characters.as_str(),
-                    options.invert,
-                    options.target,
-                );
+        //add spacing for the anchor
+        output.push_str(comment_prefix);
+        output.push_str(&horizontal_spacing);
 
-                //add border at the start
-                //this cannot be done in single if-else, since the image might only be a single pixel wide
-                if x == 0 {
-                    //add outer border (left)
-                    if options.border {
-                        char = format!("{}{}", "║", char);
-                    }
+        if ruler {
+            output.push_str(&util::ruler_row(index as u32, ruler_width));
+        }
 
-                    //add spacing for centering the image
-                    if options.center_x {
-                        char = format!("{}{}", horizontal_spacing, char);
-                    }
-                }
+        //set the backdrop for the row
+        output.push_str(&backdrop);
 
-                //add a break at line end
-                if x == source_img.width() - tile_width {
-                    //add outer border (right)
-                    if options.border {
-                        char.push('║');
-                    }
+        //add outer border (left)
+        if options.border {
+            push_border('║', &row[0], options, &mut output);
+        }
 
-                    char.push('\n');
+        if let Some((max_runs, background_color, plain)) = &color_runs {
+            let start = index * columns as usize;
+            let row_glyphs = glyphs.get(start..start + row.len()).unwrap_or(&[]);
+            for ([red, green, blue], text) in
+                color_runs_of_row(row, row_glyphs, density, *max_runs, plain)
+            {
+                output.push_str(&target::ansi::colored_str(
+                    red,
+                    green,
+                    blue,
+                    &text,
+                    *background_color,
+                    options.ansi_tie_break,
+                ));
+                if !backdrop.is_empty() {
+                    output.push_str(&backdrop);
                 }
+            }
+        } else {
+            for (column, cell) in row.iter().enumerate() {
+                //convert the cell to a char and append it to the output
+                let glyph = glyphs
+                    .get(index * columns as usize + column)
+                    .copied()
+                    .flatten();
+                push_glyph_or_cell(cell, glyph, density, options, &mut output);
 
-                Some(char)
-            } else {
-                //only read tiles
-                None
+                if !backdrop.is_empty() {
+                    //colored chars reset the background, so it has to be set again
+                    output.push_str(&backdrop);
+                }
             }
-        })
-        .reduce(|acc, value| acc + value.as_str());
+        }
 
-    match target {
-        Some(value) => output.push_str(&value),
-        //this none case should never appear
-        None => {
-            util::fatal_error("Failed to convert image.", Some(70));
+        //add outer border (right)
+        if options.border {
+            push_border('║', &row[row.len() - 1], options, &mut output);
         }
-    };
 
-    if options.border {
-        //add spacing for centering
-        if options.center_x {
-            output.push_str(&horizontal_spacing);
+        if !backdrop.is_empty() {
+            output.push_str(target::ansi::RESET);
+        }
+
+        //add a break at line end, the last row only needs one, if the border follows
+        if index + 1 < rows as usize || options.border {
+            output.push('\n');
+        }
+
+        if stream {
+            out.write_all(output.as_bytes())?;
+            output.clear();
         }
+    }
+
+    if options.border {
+        //add spacing for the anchor
+        output.push_str(comment_prefix);
+        output.push_str(&horizontal_spacing);
+        output.push_str(&" ".repeat(ruler_width));
 
         //add bottom part of border after conversion
         trace!("Adding bottom border");
-        output.push('╚');
-        output.push_str("═".repeat((columns) as usize).as_str());
-        output.push('╝');
-    } else {
-        //last char is a new line char, remove it
-        //don't use trim, since it can remove "whitespace" which include spaces
-        //these might be used to represent part of the image
-        output.remove(output.len() - 1);
+        let last_row = &cells[cells.len() - columns as usize..];
+        push_border('╚', &last_row[0], options, &mut output);
+        for cell in last_row {
+            for _ in 0..char_width {
+                push_border('═', cell, options, &mut output);
+            }
+        }
+        push_border('╝', &last_row[last_row.len() - 1], options, &mut output);
+    }
+
+    if legend {
+        trace!("Adding legend");
+        output.push('\n');
+        output.push_str(comment_prefix);
+        output.push_str(&horizontal_spacing);
+        output.push_str(&legend_line(&options.characters));
     }
 
     //compare it, ignoring the enum value such as true, true
@@ -227,22 +844,3193 @@ pub fn convert(image: DynamicImage, options: Option) -> String {
         == std::mem::discriminant(&TargetType::HtmlFile(true, true))
     {
         trace!("Adding html bottom part");
-        output.push_str(&target::html::html_bottom());
+        output.push_str(&target::html::html_bottom(options.html_fragment));
     }
 
-    if options.center_y
-        && std::mem::discriminant(&options.target)
-            == std::mem::discriminant(&TargetType::Shell(true, true))
-    {
-        trace!("Adding vertical bottom spacing");
-        output.push_str(&util::spacing_vertical(if options.border {
-            //two rows are missing because the border takes up two lines
-            rows + 2
-        } else {
-            rows
+    trace!("Adding vertical bottom spacing");
+    output.push_str(&bottom_spacing);
+
+    if options.target == TargetType::Rle {
+        trace!("Encoding rows");
+        output = output
+            .split('\n')
+            .map(target::rle::encode_line)
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+
+    out.write_all(output.as_bytes())
+}
+
+#[cfg(test)]
+mod test_html_fragment {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn convert_html(fragment: bool) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(40, 20, |x, _| {
+            image::Rgb([(x * 6) as u8, 100, 50])
         }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .html_fragment(fragment);
+        convert(img, builder.build())
     }
 
-    //return output
-    output
+    #[test]
+    fn fragment_omits_document() {
+        let converted = convert_html(true);
+        assert!(!converted.contains("<!DOCTYPE html>"));
+        assert!(!converted.contains("<head>"));
+        assert!(converted.starts_with("<pre>"));
+        assert!(converted.ends_with("</pre>"));
+    }
+
+    #[test]
+    fn fragment_same_content() {
+        let document = convert_html(false);
+        let fragment = convert_html(true);
+        assert!(document.contains(&fragment[..fragment.len() - "</pre>".len()]));
+    }
+}
+
+/// Return if the background of the image is dark.
+///
+/// The background is estimated using the average luminosity of the pixels at the edges of the image.
+fn dark_background(image: &DynamicImage, weights: options::LuminanceWeights) -> bool {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return false;
+    }
+
+    let edge_pixels = (0..width)
+        .flat_map(|x| [(x, 0), (x, height - 1)])
+        .chain((0..height).flat_map(|y| [(0, y), (width - 1, y)]));
+
+    let (sum, count) = edge_pixels.fold((0f32, 0u32), |(sum, count), (x, y)| {
+        let pixel = image.get_pixel(x, y);
+        (
+            sum + pixel::luminosity(pixel.0[0], pixel.0[1], pixel.0[2], weights),
+            count + 1,
+        )
+    });
+    sum / (count as f32) < u8::MAX as f32 / 2f32
+}
+
+#[cfg(test)]
+mod test_dark_background {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    /// Create an image with a centered square of the foreground on the background.
+    fn subject_image(background: u8, foreground: u8) -> DynamicImage {
+        DynamicImage::ImageLuma8(image::GrayImage::from_fn(200, 100, |x, y| {
+            if (50..150).contains(&x) && (25..75).contains(&y) {
+                image::Luma([foreground])
+            } else {
+                image::Luma([background])
+            }
+        }))
+    }
+
+    #[test]
+    fn dark() {
+        assert!(dark_background(
+            &subject_image(20, 240),
+            options::LuminanceWeights::DEFAULT
+        ));
+    }
+
+    #[test]
+    fn light() {
+        assert!(!dark_background(
+            &subject_image(240, 20),
+            options::LuminanceWeights::DEFAULT
+        ));
+    }
+
+    /// Convert the image to plain text.
+    fn convert_plain(img: DynamicImage, invert: bool, auto_invert: bool) -> String {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .invert(invert)
+            .auto_invert(auto_invert);
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn auto_invert_dark_background() {
+        let img = subject_image(20, 240);
+        assert_eq!(
+            convert_plain(img.clone(), true, false),
+            convert_plain(img, false, true)
+        );
+    }
+
+    #[test]
+    fn auto_invert_light_background() {
+        let img = subject_image(240, 20);
+        //the invert option is replaced
+        assert_eq!(
+            convert_plain(img.clone(), false, false),
+            convert_plain(img, true, true)
+        );
+    }
+}
+
+/// Apply the filters of the `options` to the image, without converting it to characters.
+///
+/// This is the part of [`convert`], which changes the image itself, such as filling the transparent pixels, tiling, rotating, the outline and flipping it.
+/// The image is not resized, so it can be used by other programs as well.
+///
+/// # Examples
+/// ```
+/// use artem::options::OptionBuilder;
+///
+/// let img = image::open("assets/images/standard_test_img.png").unwrap();
+/// let mut builder = OptionBuilder::new();
+/// builder.outline(true);
+///
+/// let outline = artem::apply_filters(img, &builder.build());
+/// assert!(!outline.color().has_color());
+/// ```
+pub fn apply_filters(image: DynamicImage, options: &Option) -> DynamicImage {
+    //transparent pixels would otherwise be treated as the color they hide, which is often black
+    let image = filter::fill_transparency(image, options.background_fill);
+
+    let image = if options.tile_source {
+        //repeat the image until it fills the target size along the resized side
+        let side = match options.dimension {
+            util::ResizingDimension::Height => image.height(),
+            _ => image.width(),
+        }
+        .max(1);
+        let factor = (options.target_size + side - 1) / side;
+        filter::tile(image, factor)
+    } else {
+        image
+    };
+
+    let mut image =
+        if options.auto_rotate && rotation_fits_better(image.width(), image.height(), options) {
+            info!("Rotating image to fit the available area");
+            image.rotate90()
+        } else {
+            image
+        };
+
+    if options.outline {
+        //create an outline using an algorithm loosely based on the canny edge algorithm
+        image = filter::edge_detection_filter(
+            image,
+            options.hysteresis,
+            options.hysteresis_gamma,
+            options.outline_normalize,
+            options.kernel_size,
+            options.luminance_weights,
+        );
+    }
+
+    if options.transform_x {
+        info!("Flipping image horizontally");
+        image = image.fliph();
+    }
+
+    if options.transform_y {
+        info!("Flipping image vertically");
+        image = image.flipv();
+    }
+    image
+}
+
+#[cfg(test)]
+mod test_apply_filters {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn no_filters_unchanged() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(4, 3, |x, y| {
+            image::Rgb([x as u8 * 50, y as u8 * 80, 0])
+        }));
+        assert_eq!(
+            img,
+            apply_filters(img.clone(), &OptionBuilder::new().build())
+        );
+    }
+
+    #[test]
+    fn flipped() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(4, 3, |x, y| {
+            image::Rgb([x as u8 * 50, y as u8 * 80, 0])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder.transform_x(true).transform_y(true);
+        assert_eq!(img.rotate180(), apply_filters(img, &builder.build()));
+    }
+
+    #[test]
+    fn outline_keeps_size() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::new(30, 20));
+        let mut builder = OptionBuilder::new();
+        builder.outline(true);
+        let outline = apply_filters(img, &builder.build());
+        assert_eq!((30, 20), outline.dimensions());
+    }
+}
+
+#[cfg(test)]
+mod test_background_fill {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use image::Rgb;
+
+    fn convert_pixel(pixel: Rgba<u8>, fill: std::option::Option<Rgb<u8>>) -> String {
+        let img = DynamicImage::ImageRgba8(image::RgbaImage::from_pixel(4, 4, pixel));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(std::num::NonZeroU32::new(2).unwrap());
+        if let Some(fill) = fill {
+            builder.background_fill(fill);
+        }
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn transparent_is_not_black() {
+        let transparent = convert_pixel(Rgba([0, 0, 0, 0]), None);
+        assert_eq!(convert_pixel(Rgba([255, 255, 255, 255]), None), transparent);
+        assert_ne!(convert_pixel(Rgba([0, 0, 0, 255]), None), transparent);
+    }
+
+    #[test]
+    fn custom_fill() {
+        assert_eq!(
+            convert_pixel(Rgba([0, 0, 0, 255]), None),
+            convert_pixel(Rgba([200, 100, 0, 0]), Some(Rgb([0, 0, 0])))
+        );
+    }
+
+    #[test]
+    fn opaque_is_unchanged() {
+        assert_eq!(
+            convert_pixel(Rgba([30, 60, 90, 255]), None),
+            convert_pixel(Rgba([30, 60, 90, 255]), Some(Rgb([0, 0, 0])))
+        );
+    }
+}
+
+/// Resize the image to the given size, which covers all tiles.
+///
+/// With [`EdgeMode::Pad`] the image is not resized, only the last partial tiles are filled with the pixels at the edge.
+fn resize_to_tiles(img: &DynamicImage, width: u32, height: u32, options: &Option) -> DynamicImage {
+    if options.edge_mode == EdgeMode::Pad {
+        //the tiles already cover the full image, only the last ones have to be filled
+        filter::pad_edges(img, width, height)
+    } else if options.pixel_art {
+        //keep the exact colors of the pixels, instead of blending them
+        img.resize_exact(width, height, image::imageops::FilterType::Nearest)
+    } else if options.linear_downscale {
+        filter::linear_thumbnail(img, width, height)
+    } else {
+        //use the thumbnail method, since its way faster, it may result in artifacts, but the ascii art will be pixelate anyway
+        img.thumbnail_exact(width, height)
+    }
+}
+
+/// Calculate the cells of the image, which are converted to the characters.
+///
+/// Returns the cells row by row, together with the number of columns and rows.
+/// When matching patterns, the glyph of every cell is returned as well, otherwise it is empty.
+/// All filters, which change the colors or the luminosity of the cells, are already applied.
+fn calculate_cells(
+    image: DynamicImage,
+    options: &Option,
+) -> (Vec<pixel::Cell>, u32, u32, Vec<std::option::Option<char>>) {
+    //the filters do not change the dimensions, besides rotating and tiling
+    let input_img = apply_filters(image, options);
+
+    //get img dimensions
+    let input_width = input_img.width();
+    let input_height = input_img.height();
+    debug!("Input Image Width: {input_width}");
+    debug!("Input Image Height: {input_height}");
+
+    if wide_characters(options) {
+        warn!("The characters contain wide characters, which take up two columns, the number of columns is halved");
+    }
+
+    //calculate the needed dimensions
+    let (columns, rows, tile_width, tile_height) =
+        output_dimensions(input_width, input_height, options);
+    debug!("Columns: {columns}");
+    debug!("Rows: {rows}");
+    debug!("Tile Width: {tile_width}");
+    debug!("Tile Height: {tile_height}");
+
+    info!("Resizing image to fit new dimensions");
+    let mut source_img = resize_to_tiles(
+        &input_img,
+        columns * tile_width,
+        rows * tile_height,
+        options,
+    );
+
+    if options.sharpen > 0f32 {
+        //sharpen after resizing, so the details are not lost when downscaling
+        source_img = filter::sharpen(source_img, options.sharpen, options.kernel_size);
+    }
+
+    debug!("Resized Image Width: {}", source_img.width());
+    debug!("Resized Image Height: {}", source_img.height());
+
+    //preallocate vector with the with space for all pixels in the tile
+    //it is reused for every tile, to avoid allocating a new one each time
+    let mut pixels = Vec::with_capacity((tile_height * tile_width) as usize);
+
+    //calculate all cells first, so they can be compared to their neighbors
+    #[cfg(not(feature = "parallel"))]
+    let mut cells = {
+        let mut cells = Vec::with_capacity((columns * rows) as usize);
+        for y in (0..source_img.height()).step_by(tile_height as usize) {
+            for x in (0..source_img.width()).step_by(tile_width as usize) {
+                cells.push(tile_cell(
+                    &source_img,
+                    x,
+                    y,
+                    tile_width,
+                    tile_height,
+                    options,
+                    &mut pixels,
+                ));
+            }
+        }
+        cells
+    };
+
+    //the tiles are independent, the indexed iterator keeps them in row order
+    #[cfg(feature = "parallel")]
+    let mut cells: Vec<pixel::Cell> = {
+        use rayon::prelude::*;
+        trace!("Calculating the cells in parallel");
+        //runs on the current pool, which is the one of convert_in_pool or the global one
+        (0..columns * rows)
+            .into_par_iter()
+            .map_init(
+                || Vec::with_capacity((tile_height * tile_width) as usize),
+                |pixels, index| {
+                    tile_cell(
+                        &source_img,
+                        (index % columns) * tile_width,
+                        (index / columns) * tile_height,
+                        tile_width,
+                        tile_height,
+                        options,
+                        pixels,
+                    )
+                },
+            )
+            .collect()
+    };
+
+    let glyphs = if let Some(threshold) = options.braille {
+        trace!("Converting the cells to braille dots");
+        let mut glyphs = Vec::with_capacity(cells.len());
+        for y in (0..source_img.height()).step_by(tile_height as usize) {
+            for x in (0..source_img.width()).step_by(tile_width as usize) {
+                let luminosities = sub_luminosities(
+                    &source_img,
+                    (x, y),
+                    (tile_width, tile_height),
+                    2,
+                    options.luminance_weights,
+                );
+                glyphs.push(Some(pixel::braille_char(
+                    &luminosities,
+                    threshold,
+                    options.invert,
+                )));
+            }
+        }
+        glyphs
+    } else if options.pattern_match {
+        trace!("Matching the patterns of the cells");
+        let mut glyphs = Vec::with_capacity(cells.len());
+        for y in (0..source_img.height()).step_by(tile_height as usize) {
+            for x in (0..source_img.width()).step_by(tile_width as usize) {
+                let luminosities = sub_luminosities(
+                    &source_img,
+                    (x, y),
+                    (tile_width, tile_height),
+                    3,
+                    options.luminance_weights,
+                );
+                glyphs.push(pixel::pattern_glyph(&luminosities, options.invert));
+            }
+        }
+        glyphs
+    } else {
+        Vec::new()
+    };
+
+    if options.edge_color_only {
+        trace!("Removing the color of cells without edges");
+        //the edges are detected on the full image, since the blur would remove them on the small one
+        let edge_img = filter::edge_detection_filter(
+            input_img,
+            false,
+            1f32,
+            false,
+            options.kernel_size,
+            options.luminance_weights,
+        );
+        //resize it like the image, so the tiles line up with the cells
+        let edge_img =
+            resize_to_tiles(&edge_img, columns * tile_width, rows * tile_height, options);
+
+        let mut cells_iter = cells.iter_mut();
+        for y in (0..edge_img.height()).step_by(tile_height as usize) {
+            for x in (0..edge_img.width()).step_by(tile_width as usize) {
+                pixels.clear();
+                for p_x in 0..tile_width {
+                    for p_y in 0..tile_height {
+                        pixels.push(edge_img.get_pixel(x + p_x, y + p_y))
+                    }
+                }
+
+                //the cells are in the same order as the tiles
+                match cells_iter.next() {
+                    Some(cell)
+                        if pixel::Cell::from_block(&pixels, options.luminance_weights)
+                            .luminosity
+                            < EDGE_THRESHOLD =>
+                    {
+                        cell.desaturate(options.luminance_weights)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    adjust_cells(&mut cells, columns, options);
+
+    if options.transpose {
+        trace!("Transposing the cells");
+        //the glyphs are empty, if the patterns are not matched
+        let glyphs = if glyphs.is_empty() {
+            glyphs
+        } else {
+            transpose(&glyphs, columns, rows)
+        };
+        return (transpose(&cells, columns, rows), rows, columns, glyphs);
+    }
+
+    (cells, columns, rows, glyphs)
+}
+
+/// Return the grid with its columns as rows.
+///
+/// The grid is given row by row and has to contain exactly `columns * rows` values.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!(vec![1, 3, 2, 4], transpose(&[1, 2, 3, 4], 2, 2));
+/// ```
+fn transpose<T: Copy>(grid: &[T], columns: u32, rows: u32) -> Vec<T> {
+    let (columns, rows) = (columns as usize, rows as usize);
+    (0..columns)
+        .flat_map(|column| (0..rows).map(move |row| grid[row * columns + column]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_transpose {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn swaps_rows_and_columns() {
+        assert_eq!(vec![1, 4, 2, 5, 3, 6], transpose(&[1, 2, 3, 4, 5, 6], 3, 2));
+    }
+
+    #[test]
+    fn wide_image_becomes_tall() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(400, 100, |x, _| {
+            image::Rgb([(x % 256) as u8; 3])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(40).unwrap());
+        let normal = convert(img.clone(), builder.build());
+        let transposed = convert(img.clone(), builder.transpose(true).build());
+
+        let normal_rows: Vec<Vec<char>> =
+            normal.lines().map(|line| line.chars().collect()).collect();
+        let transposed_rows: Vec<Vec<char>> = transposed
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        assert_eq!(normal_rows[0].len(), transposed_rows.len());
+        assert_eq!(normal_rows.len(), transposed_rows[0].len());
+        assert!(transposed_rows.len() > transposed_rows[0].len());
+        for (row, chars) in normal_rows.iter().enumerate() {
+            for (column, char) in chars.iter().enumerate() {
+                assert_eq!(*char, transposed_rows[column][row]);
+            }
+        }
+        assert_eq!(
+            (normal_rows.len() as u32, normal_rows[0].len() as u32),
+            output_size(&img, &builder.build())
+        );
+    }
+}
+
+/// Convert the image into rows of upper half blocks, which are colored with the top and the bottom half of each tile.
+///
+/// Every tile is split into a top and a bottom half, if the height of the tile is odd, the bottom half is a pixel smaller.
+/// Tiles, which are only a single pixel high, use the same pixel for both halves.
+fn half_blocks(image: DynamicImage, options: &Option) -> String {
+    let input_img = apply_filters(image, options);
+    let (columns, rows, tile_width, tile_height) =
+        output_dimensions(input_img.width(), input_img.height(), options);
+    debug!("Columns: {columns}");
+    debug!("Rows: {rows}");
+
+    info!("Resizing image to fit new dimensions");
+    let source_img = input_img.thumbnail_exact(columns * tile_width, rows * tile_height);
+
+    //round down the bottom half, so the top half is never empty
+    let bottom_height = tile_height / 2;
+    let top_height = tile_height - bottom_height;
+
+    let mut top_cells = Vec::with_capacity((columns * rows) as usize);
+    let mut bottom_cells = Vec::with_capacity((columns * rows) as usize);
+    let mut pixels = Vec::with_capacity((tile_width * top_height) as usize);
+    for y in (0..source_img.height()).step_by(tile_height as usize) {
+        for x in (0..source_img.width()).step_by(tile_width as usize) {
+            pixels.clear();
+            for p_x in 0..tile_width {
+                for p_y in 0..top_height {
+                    pixels.push(source_img.get_pixel(x + p_x, y + p_y))
+                }
+            }
+            let top = pixel::Cell::from_block(&pixels, options.luminance_weights);
+
+            if bottom_height > 0 {
+                pixels.clear();
+                for p_x in 0..tile_width {
+                    for p_y in top_height..tile_height {
+                        pixels.push(source_img.get_pixel(x + p_x, y + p_y))
+                    }
+                }
+                bottom_cells.push(pixel::Cell::from_block(&pixels, options.luminance_weights));
+            } else {
+                bottom_cells.push(top);
+            }
+            top_cells.push(top);
+        }
+    }
+    adjust_cells(&mut top_cells, columns, options);
+    adjust_cells(&mut bottom_cells, columns, options);
+
+    let color = |cell: &pixel::Cell| {
+        let color = pixel::output_color(cell, options);
+        if options.invert {
+            color.map(|channel| 255 - channel)
+        } else {
+            color
+        }
+    };
+
+    let mut output = String::with_capacity(((columns + 1) * rows) as usize);
+    for (row, (top_row, bottom_row)) in top_cells
+        .chunks(columns as usize)
+        .zip(bottom_cells.chunks(columns as usize))
+        .enumerate()
+    {
+        if row != 0 {
+            output.push('\n');
+        }
+        for (top, bottom) in top_row.iter().zip(bottom_row) {
+            output.push_str(&target::ansi::half_block(
+                color(top),
+                color(bottom),
+                options.ansi_tie_break,
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test_half_blocks {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::{env, num::NonZeroU32};
+
+    /// Create an image with a red top and a blue bottom half in every row of tiles.
+    fn stripes(tile_height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(40, 40, |_, y| {
+            if y % tile_height < tile_height - tile_height / 2 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 0, 255])
+            }
+        }))
+    }
+
+    fn options(invert: bool) -> Option {
+        //every tile is 4x4 pixels
+        OptionBuilder::new()
+            .target_size(NonZeroU32::new(10).unwrap())
+            .scale(1f32)
+            .half_blocks(true)
+            .invert(invert)
+            .build()
+    }
+
+    #[test]
+    fn top_and_bottom_colors() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        let converted = convert(stripes(4), options(false));
+        assert_eq!(10, converted.lines().count());
+        let first_line = converted.lines().next().unwrap();
+        assert_eq!(10, first_line.matches(target::ansi::HALF_BLOCK).count());
+        assert!(first_line.contains("38;2;255;0;0"));
+        assert!(first_line.contains("48;2;0;0;255"));
+    }
+
+    #[test]
+    fn inverted_colors() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        let converted = convert(stripes(4), options(true));
+        assert!(converted.contains("38;2;0;255;255"));
+        assert!(converted.contains("48;2;255;255;0"));
+    }
+
+    #[test]
+    fn odd_tile_height() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        //the top half has 2 pixels, the bottom half only 1
+        let img = stripes(3).crop_imm(0, 0, 30, 30);
+        let converted = convert(img, options(false));
+        assert_eq!(10, converted.lines().count());
+        assert!(converted.contains("38;2;255;0;0"));
+        assert!(converted.contains("48;2;0;0;255"));
+    }
+
+    #[test]
+    fn plain_target_is_not_changed() {
+        let mut builder = options(false).to_builder();
+        builder.target(TargetType::File);
+        let converted = convert(stripes(4), builder.build());
+        assert!(!converted.contains(target::ansi::HALF_BLOCK));
+    }
+}
+
+/// Calculate the cell of the tile, which starts at the given position.
+///
+/// The `pixels` are only used as a buffer, so it does not have to be allocated for every tile.
+fn tile_cell(
+    image: &DynamicImage,
+    x: u32,
+    y: u32,
+    tile_width: u32,
+    tile_height: u32,
+    options: &Option,
+    pixels: &mut Vec<Rgba<u8>>,
+) -> pixel::Cell {
+    pixels.clear();
+
+    //get all pixel of the tile
+    for p_x in 0..tile_width {
+        for p_y in 0..tile_height {
+            pixels.push(image.get_pixel(x + p_x, y + p_y))
+        }
+    }
+
+    let mut cell = pixel::Cell::from_block(pixels, options.luminance_weights);
+    if options.pixel_art {
+        //use the exact color of the center pixel, the luminosity is still averaged over the tile
+        let center = image.get_pixel(x + tile_width / 2, y + tile_height / 2);
+        cell.red = center.0[0];
+        cell.green = center.0[1];
+        cell.blue = center.0[2];
+        cell.alpha = center.0[3];
+    }
+    cell
+}
+
+/// Return the luminosities of the sub-pixels of the tile at the given position, row by row.
+///
+/// The tile is split into `N` sub-pixels, with `sub_columns` in every row. The tile has to be at least as large as the sub-pixel grid,
+/// so every sub-pixel contains a pixel.
+fn sub_luminosities<const N: usize>(
+    image: &DynamicImage,
+    (x, y): (u32, u32),
+    (tile_width, tile_height): (u32, u32),
+    sub_columns: u32,
+    weights: options::LuminanceWeights,
+) -> [f32; N] {
+    let sub_rows = N as u32 / sub_columns;
+    let mut luminosities = [0f32; N];
+    let mut pixels = Vec::new();
+    for (index, luminosity) in luminosities.iter_mut().enumerate() {
+        let (row, column) = (index as u32 / sub_columns, index as u32 % sub_columns);
+        pixels.clear();
+        for p_x in column * tile_width / sub_columns..(column + 1) * tile_width / sub_columns {
+            for p_y in row * tile_height / sub_rows..(row + 1) * tile_height / sub_rows {
+                pixels.push(image.get_pixel(x + p_x, y + p_y));
+            }
+        }
+        *luminosity = pixel::Cell::from_block(&pixels, weights).luminosity;
+    }
+    luminosities
+}
+
+/// Convert the cells to JSON lines, with a JSON object for every row.
+///
+/// See [`target::json::row_line`] for the objects of the rows.
+fn json_lines(
+    cells: &[pixel::Cell],
+    glyphs: &[std::option::Option<char>],
+    columns: u32,
+    options: &Option,
+) -> String {
+    //the chars are created without colors, since the colors are stored separately
+    let plain = options.to_builder().target(TargetType::File).build();
+
+    let mut lines = Vec::with_capacity(cells.len() / columns.max(1) as usize);
+    for (index, row) in cells.chunks(columns as usize).enumerate() {
+        let density = options.row_characters(index);
+        let mut chars = String::with_capacity(row.len());
+        for (column, cell) in row.iter().enumerate() {
+            let glyph = glyphs
+                .get(index * columns as usize + column)
+                .copied()
+                .flatten();
+            push_glyph_or_cell(cell, glyph, density, &plain, &mut chars);
+        }
+        let colors: Vec<[u8; 3]> = row
+            .iter()
+            .map(|cell| pixel::output_color(cell, options))
+            .collect();
+        lines.push(target::json::row_line(index, &chars, &colors));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_json_lines {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn line_for_every_row() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(40, 40, |x, y| {
+            image::Rgb([(x * 6) as u8, (y * 6) as u8, 50])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::JsonLines)
+            .target_size(NonZeroU32::new(10).unwrap())
+            .scale(1f32);
+        let options = builder.build();
+        let (_, columns, rows, _) = calculate_cells(img.clone(), &options);
+        let converted = convert(img, options);
+
+        assert_eq!(rows as usize, converted.lines().count());
+        for (index, line) in converted.lines().enumerate() {
+            //every line is valid on its own
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(index, value["row"]);
+            assert_eq!(columns as usize, value["cells"].as_array().unwrap().len());
+        }
+    }
+}
+
+/// Return the runs of the row, which use the same color, together with their chars.
+///
+/// Similar colors are merged first, so the row has at most `max_runs` runs. The chars are created
+/// using the `options`, which should not use colors, so each run can be colored as a whole.
+fn color_runs_of_row(
+    row: &[pixel::Cell],
+    glyphs: &[std::option::Option<char>],
+    density: &str,
+    max_runs: u32,
+    options: &Option,
+) -> Vec<([u8; 3], String)> {
+    let mut cells = row.to_vec();
+    pixel::limit_color_runs(&mut cells, max_runs as usize);
+
+    let mut runs: Vec<([u8; 3], String)> = Vec::new();
+    for (column, cell) in cells.iter().enumerate() {
+        let color = pixel::output_color(cell, options);
+        if runs.last().map(|(run_color, _)| *run_color) != Some(color) {
+            runs.push((color, String::new()));
+        }
+        //safe to unwrap, since a run was added before
+        let (_, text) = runs.last_mut().unwrap();
+        let glyph = glyphs.get(column).copied().flatten();
+        push_glyph_or_cell(cell, glyph, density, options, text);
+    }
+    runs
+}
+
+#[cfg(test)]
+mod test_color_runs {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a noisy image, where every pixel has a random color.
+    fn convert_noise(max_runs: std::option::Option<u32>) -> String {
+        std::env::set_var("COLORTERM", "truecolor");
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(40, 10, |x, y| {
+            let value = (x * 97 + y * 31) % 256;
+            image::Rgb([value as u8, (value * 7 % 256) as u8, 128])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::Shell(true, false))
+            .target_size(NonZeroU32::new(40).unwrap())
+            .scale(1f32)
+            .max_color_switches_per_line(max_runs);
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn noisy_row_limited() {
+        for line in convert_noise(Some(4)).lines() {
+            assert!(line.matches("\x1b[38;2;").count() <= 4);
+        }
+    }
+
+    #[test]
+    fn chars_unchanged() {
+        let strip = |output: String| {
+            output
+                .split('\x1b')
+                .map(|part| part.split_once('m').map_or(part, |(_, text)| text))
+                .collect::<String>()
+        };
+        assert_eq!(strip(convert_noise(None)), strip(convert_noise(Some(4))));
+    }
+}
+
+/// Append the char of the cell to the `output`.
+///
+/// If the cell has a glyph, which matches its pattern, the glyph is used, otherwise the char is selected from the `density`.
+fn push_glyph_or_cell(
+    cell: &pixel::Cell,
+    glyph: std::option::Option<char>,
+    density: &str,
+    options: &Option,
+    output: &mut String,
+) {
+    match glyph {
+        Some(glyph) => pixel::push_colored_char(glyph, cell, options, output),
+        None => pixel::push_cell_with_characters(cell, density, options, output),
+    }
+}
+
+#[cfg(test)]
+mod test_pattern_match {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a single cell of the image with pattern matching.
+    fn convert_cell(img: image::RgbImage) -> String {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(1).unwrap())
+            .scale(1f32)
+            .pattern_match(true);
+        convert(DynamicImage::ImageRgb8(img), builder.build())
+    }
+
+    #[test]
+    fn cross_is_plus() {
+        let img = image::RgbImage::from_fn(3, 3, |x, y| {
+            if x == 1 || y == 1 {
+                image::Rgb([255, 255, 255])
+            } else {
+                image::Rgb([0, 0, 0])
+            }
+        });
+        assert_eq!("+", convert_cell(img));
+    }
+
+    #[test]
+    fn large_cross_is_plus() {
+        let img = image::RgbImage::from_fn(30, 30, |x, y| {
+            if (10..20).contains(&x) || (10..20).contains(&y) {
+                image::Rgb([255, 255, 255])
+            } else {
+                image::Rgb([0, 0, 0])
+            }
+        });
+        assert_eq!("+", convert_cell(img));
+    }
+
+    #[test]
+    fn flat_uses_characters() {
+        let img = image::RgbImage::from_pixel(3, 3, image::Rgb([255, 255, 255]));
+        assert_eq!("M", convert_cell(img));
+    }
+
+    #[test]
+    fn small_tiles_are_enlarged() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target_size(NonZeroU32::new(10).unwrap())
+            .scale(1f32);
+        let (columns, rows, _, _) = output_dimensions(10, 10, &builder.build());
+        let (_, _, tile_width, tile_height) =
+            output_dimensions(10, 10, &builder.pattern_match(true).build());
+        assert_eq!((10, 10), (columns, rows));
+        assert_eq!((3, 3), (tile_width, tile_height));
+    }
+}
+
+#[cfg(test)]
+mod test_braille {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    fn braille_options() -> OptionBuilder {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(1).unwrap())
+            .scale(1f32)
+            .braille(128);
+        builder
+    }
+
+    #[test]
+    fn left_half_is_left_column() {
+        let img = image::RgbImage::from_fn(20, 20, |x, _| {
+            if x < 10 {
+                image::Rgb([255, 255, 255])
+            } else {
+                image::Rgb([0, 0, 0])
+            }
+        });
+        assert_eq!(
+            "⡇",
+            convert(DynamicImage::ImageRgb8(img), braille_options().build())
+        );
+    }
+
+    #[test]
+    fn small_tiles_are_enlarged() {
+        let mut builder = braille_options();
+        builder.target_size(NonZeroU32::new(10).unwrap());
+        let (_, _, tile_width, tile_height) = output_dimensions(10, 10, &builder.build());
+        assert_eq!((2, 4), (tile_width, tile_height));
+    }
+
+    #[test]
+    fn only_braille_chars() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = braille_options();
+        builder.target_size(NonZeroU32::new(40).unwrap());
+        let options = builder.build();
+        let converted = convert(img.clone(), options.clone());
+        assert!(converted
+            .chars()
+            .all(|char| char == '\n' || ('\u{2800}'..='\u{28FF}').contains(&char)));
+        assert_eq!(predict_output_len(&img, &options), converted.len());
+    }
+}
+
+/// Apply the filters, which change the luminosity or the colors of the finished cells.
+fn adjust_cells(cells: &mut [pixel::Cell], columns: u32, options: &Option) {
+    if options.equalize {
+        trace!("Equalizing the luminosity");
+        pixel::equalize(cells);
+    } else if options.min_contrast > 0f32 {
+        //equalized cells already use the full range
+        trace!("Expanding the contrast of the luminosity");
+        pixel::expand_contrast(cells, options.min_contrast);
+    }
+
+    if options.tone_map {
+        trace!("Tone mapping the luminosity");
+        for cell in cells.iter_mut() {
+            cell.luminosity = pixel::tone_map(cell.luminosity);
+        }
+    }
+
+    if options.smooth_horizontal {
+        for row in cells.chunks_mut(columns as usize) {
+            pixel::smooth_horizontal(row, options.characters.chars().count());
+        }
+    }
+
+    if options.pixel_art {
+        trace!("Dithering the luminosity");
+        pixel::ordered_dither(cells, columns as usize, options.characters.chars().count());
+    }
+
+    if options.dither {
+        trace!("Diffusing the luminosity error");
+        pixel::error_diffusion(cells, columns as usize, options.characters.chars().count());
+    }
+
+    if let Some(colors) = &options.palette {
+        if options.color_dither {
+            trace!("Dithering colors to the palette");
+            palette::dither(cells, columns as usize, colors, options.color_distance);
+        } else {
+            trace!("Reducing colors to the palette");
+            palette::reduce(cells, colors, options.color_distance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_equalize {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Return a low contrast gradient.
+    ///
+    /// Most of the image is in a narrow range of dark grays, with a few brighter pixels.
+    fn low_contrast_image() -> DynamicImage {
+        DynamicImage::ImageLuma8(image::GrayImage::from_fn(100, 100, |x, _| {
+            image::Luma([if x < 90 { 60 + x as u8 / 6 } else { 120 }])
+        }))
+    }
+
+    /// Return how often each of the characters is used in the converted low contrast gradient.
+    fn char_counts(equalize: bool) -> Vec<usize> {
+        let img = low_contrast_image();
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(50).unwrap())
+            .equalize(equalize);
+        builder.characters("#*+-. ".to_string());
+        let options = builder.build();
+        let converted = convert(img, options.clone());
+        options
+            .characters
+            .chars()
+            .map(|char| converted.matches(char).count())
+            .collect()
+    }
+
+    #[test]
+    fn uses_more_characters() {
+        let used = |counts: Vec<usize>| counts.iter().filter(|count| **count > 0).count();
+        assert!(used(char_counts(true)) > used(char_counts(false)));
+    }
+
+    #[test]
+    fn flatter_than_linear_stretch() {
+        //a linear stretch maps the range evenly, so the few bright pixels take up a whole part of the characters
+        let img = low_contrast_image();
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(50).unwrap());
+        let (mut cells, ..) = calculate_cells(img, &builder.build());
+        let (min, max) = cells.iter().fold((255f32, 0f32), |(min, max), cell| {
+            (min.min(cell.luminosity), max.max(cell.luminosity))
+        });
+        let levels = 6;
+        let histogram = |cells: &[pixel::Cell]| {
+            let mut histogram = vec![0usize; levels];
+            for cell in cells {
+                histogram[((cell.luminosity / 256f32) * levels as f32) as usize] += 1;
+            }
+            histogram
+        };
+        let mut stretched = cells.clone();
+        for cell in stretched.iter_mut() {
+            cell.luminosity = (cell.luminosity - min) / (max - min) * 255f32;
+        }
+        pixel::equalize(&mut cells);
+
+        //the most used level is used less often, when equalizing
+        let largest = |histogram: Vec<usize>| histogram.into_iter().max().unwrap();
+        assert!(largest(histogram(&cells)) < largest(histogram(&stretched)));
+    }
+}
+
+#[cfg(test)]
+mod test_dither {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a horizontal gradient with only two characters.
+    fn convert_gradient(dither: bool) -> String {
+        let img = DynamicImage::ImageLuma8(image::GrayImage::from_fn(200, 100, |x, _| {
+            image::Luma([(x * 255 / 199) as u8])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(40).unwrap())
+            .dither(dither);
+        builder.characters("# ".to_string());
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn gradient_is_mixed() {
+        let transitions = |ascii: &str| {
+            ascii
+                .lines()
+                .map(|line| {
+                    line.as_bytes()
+                        .windows(2)
+                        .filter(|pair| pair[0] != pair[1])
+                        .count()
+                })
+                .sum::<usize>()
+        };
+        //without dithering, every row only has a single edge
+        let plain = convert_gradient(false);
+        assert_eq!(plain.lines().count(), transitions(&plain));
+        assert!(transitions(&convert_gradient(true)) > 2 * transitions(&plain));
+    }
+
+    #[test]
+    fn off_by_default() {
+        assert!(!OptionBuilder::new().build().dither);
+    }
+}
+
+#[cfg(test)]
+mod test_luminance_weights {
+    use super::*;
+    use crate::options::{LuminanceWeights, OptionBuilder};
+    use std::num::NonZeroU32;
+
+    fn convert_with(weights: LuminanceWeights) -> String {
+        //a pure red image, which only differs in the red weight
+        let img =
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(20, 20, image::Rgb([255, 0, 0])));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(4).unwrap())
+            .luminance_weights(weights);
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn default_is_unchanged() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let default = convert(img.clone(), builder.build());
+        builder.luminance_weights(LuminanceWeights::DEFAULT);
+        assert_eq!(default, convert(img, builder.build()));
+    }
+
+    #[test]
+    fn weights_select_characters() {
+        assert_ne!(
+            convert_with(LuminanceWeights::DEFAULT),
+            convert_with(LuminanceWeights::REC_601)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_min_contrast {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Return the number of distinct characters of the converted near-uniform image.
+    fn distinct_chars(min_contrast: f32) -> usize {
+        let img = DynamicImage::ImageLuma8(image::GrayImage::from_fn(100, 100, |x, _| {
+            image::Luma([if x < 50 { 120 } else { 123 }])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(20).unwrap())
+            .min_contrast(min_contrast);
+        builder.characters("#*+-. ".to_string());
+        let converted = convert(img, builder.build());
+        let mut chars: Vec<char> = converted.chars().filter(|char| *char != '\n').collect();
+        chars.sort_unstable();
+        chars.dedup();
+        chars.len()
+    }
+
+    #[test]
+    fn uniform_block_without_floor() {
+        assert_eq!(1, distinct_chars(0f32));
+    }
+
+    #[test]
+    fn near_uniform_uses_characters() {
+        assert!(distinct_chars(16f32) >= 2);
+    }
+}
+
+#[cfg(test)]
+mod test_pixel_art {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    fn pixel_art_options() -> Option {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(10).unwrap())
+            .pixel_art(true);
+        builder.characters("#*+-. ".to_string());
+        builder.build()
+    }
+
+    #[test]
+    fn hard_color_edges() {
+        //the edge between the colors is not aligned with the tiles
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(103, 103, |x, _| {
+            if x < 47 {
+                image::Rgb([200, 0, 0])
+            } else {
+                image::Rgb([0, 0, 200])
+            }
+        }));
+        let (cells, ..) = calculate_cells(img, &pixel_art_options());
+        assert!(cells
+            .iter()
+            .all(|cell| matches!((cell.red, cell.green, cell.blue), (200, 0, 0) | (0, 0, 200))));
+    }
+
+    #[test]
+    fn density_varies() {
+        let img =
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(100, 100, image::Rgb([128; 3])));
+        let converted = convert(img, pixel_art_options());
+        let mut chars: Vec<char> = converted.chars().filter(|char| *char != '\n').collect();
+        chars.sort_unstable();
+        chars.dedup();
+        assert!(chars.len() > 1);
+    }
+}
+
+#[cfg(test)]
+mod test_edge_mode {
+    use super::*;
+    use crate::options::{EdgeMode, OptionBuilder};
+    use std::num::NonZeroU32;
+
+    /// Create a black image with a thin white stripe at the right edge.
+    fn edge_stripe() -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(105, 100, |x, _| {
+            if x >= 102 {
+                image::Rgb([255; 3])
+            } else {
+                image::Rgb([0; 3])
+            }
+        }))
+    }
+
+    fn options(edge_mode: EdgeMode) -> Option {
+        OptionBuilder::new()
+            .target_size(NonZeroU32::new(10).unwrap())
+            .edge_mode(edge_mode)
+            .build()
+    }
+
+    #[test]
+    fn pad_covers_image() {
+        let (columns, rows, tile_width, tile_height) =
+            output_dimensions(105, 100, &options(EdgeMode::Pad));
+        assert!(columns * tile_width >= 105);
+        assert!((columns - 1) * tile_width < 105);
+        assert!(rows * tile_height >= 100);
+        assert!((rows - 1) * tile_height < 100);
+    }
+
+    #[test]
+    fn pad_keeps_edge() {
+        let (columns, _, grid) = luminance_grid(edge_stripe(), &options(EdgeMode::Pad));
+        let last_column = grid[columns as usize - 1];
+        assert!(last_column > 128, "{last_column}");
+        //the stripe does not bleed into the other columns
+        assert!(grid[..columns as usize - 1]
+            .iter()
+            .all(|luminosity| *luminosity == 0));
+    }
+
+    #[test]
+    fn pad_is_brighter_than_crop() {
+        let (columns, _, crop) = luminance_grid(edge_stripe(), &options(EdgeMode::Crop));
+        let (_, _, pad) = luminance_grid(edge_stripe(), &options(EdgeMode::Pad));
+        assert!(pad[columns as usize - 1] > crop[columns as usize - 1]);
+    }
+}
+
+#[cfg(test)]
+mod test_tile_alignment {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a black 100x100 image with a white vertical line of a single pixel at `line` to 10 columns.
+    ///
+    /// Returns the columns, which contain the line.
+    fn line_columns(line: u32) -> Vec<usize> {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(100, 100, |x, _| {
+            if x == line {
+                image::Rgb([255; 3])
+            } else {
+                image::Rgb([0; 3])
+            }
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(10).unwrap());
+        builder.characters("#k. ".to_string());
+        let converted = convert(img, builder.build());
+
+        let first_line = converted.lines().next().unwrap();
+        first_line
+            .chars()
+            .enumerate()
+            .filter(|(_, char)| *char != ' ')
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn line_at_tile_start() {
+        assert_eq!(vec![3], line_columns(30));
+    }
+
+    #[test]
+    fn line_at_tile_center() {
+        assert_eq!(vec![3], line_columns(35));
+    }
+
+    #[test]
+    fn line_at_tile_end() {
+        assert_eq!(vec![3], line_columns(39));
+    }
+}
+
+#[cfg(test)]
+mod test_convert_tile_source {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a 2x2 image with a black and a white column.
+    fn convert_stripes(tile_source: bool) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(2, 2, |x, _| {
+            image::Rgb([(x * 255) as u8; 3])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(8).unwrap())
+            .tile_source(tile_source);
+        builder.characters("#k. ".to_string());
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn small_image_repeated() {
+        let converted = convert_stripes(true);
+        for line in converted.lines() {
+            assert_eq!(" # # # #", line);
+        }
+    }
+
+    #[test]
+    fn small_image_not_repeated() {
+        let converted = convert_stripes(false);
+        assert_eq!(2, converted.lines().next().unwrap().chars().count());
+    }
+}
+
+/// Return the legend of the characters, which lists them from the densest to the lightest.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!("Legend (densest to lightest): \"#k. \"", legend_line("#k. "));
+/// ```
+fn legend_line(characters: &str) -> String {
+    //the characters are quoted, since the lightest is often a space
+    format!("Legend (densest to lightest): \"{characters}\"")
+}
+
+#[cfg(test)]
+mod test_legend {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn convert_legend(target: TargetType) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(400, 200, |x, _| {
+            image::Rgb([(x % 256) as u8, 100, 50])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder.target(target).legend(true);
+        builder.characters("MWk. ".to_string());
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn legend_is_last_line() {
+        let converted = convert_legend(TargetType::File);
+        assert_eq!(
+            "Legend (densest to lightest): \"MWk. \"",
+            converted.lines().last().unwrap()
+        );
+    }
+
+    #[test]
+    fn no_legend_in_html() {
+        let converted = convert_legend(TargetType::HtmlFile(true, false));
+        assert!(!converted.contains("Legend"));
+        assert!(converted.ends_with(&target::html::html_bottom(false)));
+    }
+}
+
+/// Return the prefix, which is added in front of every line.
+///
+/// The prefix is only used for plain text, since it would break the markup of the other targets.
+/// If a prefix is used, the colors of the shell are disabled, since the escape sequences would break the comment.
+fn comment_prefix(options: &mut Option) -> String {
+    let prefix = match (&options.comment_prefix, options.target) {
+        (Some(prefix), TargetType::Shell(_, _) | TargetType::File) => prefix.clone(),
+        _ => return String::with_capacity(0),
+    };
+    if let TargetType::Shell(true, _) = options.target {
+        options.target = TargetType::Shell(false, false);
+    }
+    prefix
+}
+
+/// Return the (width, height) the converted image takes up in the terminal.
+///
+/// Both axes include the border, which adds a line on each side, the ruler and the legend,
+/// so the image can be placed in the terminal as a whole.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!((12, 7), displayed_size(10, 5, 0, false, true));
+/// ```
+fn displayed_size(
+    columns: u32,
+    rows: u32,
+    ruler_width: u32,
+    legend: bool,
+    border: bool,
+) -> (u32, u32) {
+    //the border takes up two columns and two rows
+    let border = if border { 2 } else { 0 };
+    //the ruler adds a column on the left and a line above the image
+    let ruler_rows = if ruler_width > 0 { 1 } else { 0 };
+    (
+        columns + border + ruler_width,
+        rows + border + ruler_rows + legend as u32,
+    )
+}
+
+#[cfg(test)]
+mod test_displayed_size {
+    use super::*;
+    use util::{HorizontalAnchor, VerticalAnchor};
+
+    /// Return the leading columns and the (top, bottom) rows of an image centered in a terminal of the given size.
+    fn centered(terminal: (u32, u32), size: (u32, u32)) -> (usize, (usize, usize)) {
+        let horizontal = util::spacing_horizontal_in(terminal.0, size.0, HorizontalAnchor::Center);
+        let (top, bottom) = util::spacing_vertical_in(terminal.1, size.1, VerticalAnchor::Center);
+        (horizontal.len(), (top.len(), bottom.len()))
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!((10, 5), displayed_size(10, 5, 0, false, false));
+    }
+
+    #[test]
+    fn border_counts_on_both_axes() {
+        assert_eq!((12, 7), displayed_size(10, 5, 0, false, true));
+    }
+
+    #[test]
+    fn ruler_and_legend() {
+        assert_eq!((15, 9), displayed_size(10, 5, 3, true, true));
+    }
+
+    #[test]
+    fn bordered_even_terminal() {
+        //12x7 image, 8 free columns and 13 free rows
+        assert_eq!(
+            (4, (6, 7)),
+            centered((20, 20), displayed_size(10, 5, 0, false, true))
+        );
+    }
+
+    #[test]
+    fn bordered_odd_terminal() {
+        //12x7 image, 9 free columns and 14 free rows
+        assert_eq!(
+            (4, (7, 7)),
+            centered((21, 21), displayed_size(10, 5, 0, false, true))
+        );
+    }
+
+    #[test]
+    fn bordered_fills_terminal_height() {
+        for height in 7..=30 {
+            let (_, (top, bottom)) = centered((80, height), displayed_size(10, 5, 0, false, true));
+            assert_eq!(height as usize, top + 7 + bottom);
+        }
+    }
+
+    #[test]
+    fn bordered_output_fits_terminal_height() {
+        for height in 8..=30 {
+            let (top, bottom) = util::spacing_vertical_in(
+                height,
+                displayed_size(10, 5, 0, false, true).1,
+                VerticalAnchor::Center,
+            );
+            //the last line of the image is not ended by a newline
+            let output = format!("{top}{}{bottom}", ["line"; 7].join("\n"));
+            //the final newline is only printed, if the output does not end with one
+            let printed = if output.ends_with('\n') {
+                output
+            } else {
+                output + "\n"
+            };
+            //the cursor ends up on the line after the last newline, which has to be in the terminal
+            assert!(printed.matches('\n').count() < height as usize);
+        }
+    }
+
+    #[test]
+    fn bordered_larger_than_terminal() {
+        assert_eq!(
+            (0, (0, 0)),
+            centered((11, 6), displayed_size(10, 5, 0, false, true))
+        );
+    }
+}
+
+/// Return if the image would cover more of the available area, when it is rotated by 90 degrees.
+///
+/// The available area is the target size and the maximum rows of the resizing dimension,
+/// otherwise the height of the terminal is used. If neither is known, the image is not rotated.
+fn rotation_fits_better(width: u32, height: u32, options: &Option) -> bool {
+    let rows = match options.dimension {
+        util::ResizingDimension::Fit(rows) | util::ResizingDimension::Contain(rows) => rows,
+        //the image is stretched to fill the grid, rotating it would only distort it
+        util::ResizingDimension::Exact { .. } => return false,
+        _ => match terminal_size::terminal_size() {
+            Some(value) => value.1 .0 as u32,
+            None => return false,
+        },
+    };
+    if width == 0 || height == 0 || rows == 0 {
+        return false;
+    }
+
+    //the number of cells, which are covered by an image with the aspect ratio (columns / rows)
+    let covered = |aspect: f32| {
+        let columns = options.target_size as f32;
+        let rows = rows as f32;
+        if aspect >= columns / rows {
+            columns * columns / aspect
+        } else {
+            rows * rows * aspect
+        }
+    };
+    //chars are higher than wide, so the image needs fewer rows than columns
+    let aspect = width as f32 / (height as f32 * options.scale);
+    let rotated_aspect = height as f32 / (width as f32 * options.scale);
+    covered(rotated_aspect) > covered(aspect)
+}
+
+#[cfg(test)]
+mod test_rotation_fits_better {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Create options for an area with the given columns and rows.
+    fn area(columns: u32, rows: u32) -> Option {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target_size(NonZeroU32::new(columns).unwrap())
+            .dimension(util::ResizingDimension::Fit(rows));
+        builder.build()
+    }
+
+    #[test]
+    fn portrait_in_wide_area() {
+        assert!(rotation_fits_better(300, 1000, &area(200, 30)));
+    }
+
+    #[test]
+    fn landscape_in_wide_area() {
+        assert!(!rotation_fits_better(1000, 300, &area(200, 30)));
+    }
+
+    #[test]
+    fn landscape_in_high_area() {
+        assert!(rotation_fits_better(1000, 300, &area(40, 100)));
+    }
+
+    #[test]
+    fn square_in_terminal_area() {
+        //the aspect ratio of a square image does not change when it is rotated
+        assert!(!rotation_fits_better(500, 500, &area(80, 40)));
+    }
+
+    #[test]
+    fn convert_rotates_portrait() {
+        let img = DynamicImage::ImageLuma8(image::GrayImage::from_fn(300, 1000, |x, _| {
+            image::Luma([if x < 150 { 0 } else { 255 }])
+        }));
+        let mut options = area(200, 30);
+        options.target = TargetType::File;
+        options.auto_rotate = true;
+        let converted = convert(img, options);
+
+        //the rotated image is wider than high, with the dark half at the top
+        let lines: Vec<&str> = converted.lines().collect();
+        assert!(lines[0].len() > lines.len());
+        assert!(lines.first().unwrap().trim().is_empty());
+        assert!(!lines.last().unwrap().trim().is_empty());
+    }
+}
+
+/// Calculate the columns, rows and tile sizes of the converted image.
+///
+/// When the aspect ratio of the characters is derived from the image, it is used instead of the configured scale.
+fn output_dimensions(
+    input_width: u32,
+    input_height: u32,
+    options: &Option,
+) -> (u32, u32, u32, u32) {
+    //a border outside of the image does not take up any of its space
+    let border = options.border && options.border_inside;
+
+    //the scale is used as the aspect ratio of the terminal cells, when deriving it from the image
+    let scale = if options.auto_char_scale {
+        util::auto_scale(
+            options.target_size,
+            input_height,
+            input_width,
+            options.scale,
+            border,
+        )
+    } else {
+        options.scale
+    };
+    debug!("Scale: {scale}");
+
+    let dimensions = util::calculate_dimensions(
+        options.target_size,
+        input_height,
+        input_width,
+        scale,
+        border,
+        options.dimension,
+    );
+
+    //the border is always displayed next to the image
+    let border_columns = if options.border { 2 } else { 0 };
+    let (columns, rows, tile_width, tile_height) = match options.max_display_width {
+        Some(max_width) if dimensions.0 + border_columns > max_width => {
+            debug!("Reducing the size to the maximum width of {max_width}");
+            //the inside border is already subtracted when calculating the dimensions
+            let target_size = if border {
+                max_width
+            } else {
+                max_width.saturating_sub(border_columns)
+            };
+            util::calculate_dimensions(
+                target_size.max(1),
+                input_height,
+                input_width,
+                scale,
+                border,
+                util::ResizingDimension::Width,
+            )
+        }
+        _ => dimensions,
+    };
+
+    //every wide char covers two columns, so the tiles are twice as wide
+    let (columns, tile_width) = if wide_characters(options) {
+        ((columns / 2).max(1), tile_width * 2)
+    } else {
+        (columns, tile_width)
+    };
+
+    let (tile_width, tile_height) = if options.braille.is_some() {
+        //every tile needs at least a pixel for each of the 2x4 braille dots
+        (tile_width.max(2), tile_height.max(4))
+    } else if options.pattern_match {
+        //every tile needs at least a pixel for each of the 3x3 sub-pixels
+        (tile_width.max(3), tile_height.max(3))
+    } else {
+        (tile_width, tile_height)
+    };
+
+    match options.edge_mode {
+        EdgeMode::Crop => (columns, rows, tile_width, tile_height),
+        EdgeMode::Pad => {
+            //enlarge the tiles, so they cover the full image, and scale the height with the width
+            let padded_width = tile_width.max((input_width + columns - 1) / columns);
+            let padded_height = ((padded_width as f32 / tile_width as f32) * tile_height as f32)
+                .round()
+                .max(1f32) as u32;
+            //the last tiles only contain the remaining pixels
+            let columns = (input_width + padded_width - 1) / padded_width;
+            let rows = (input_height + padded_height - 1) / padded_height;
+            (columns.max(1), rows.max(1), padded_width, padded_height)
+        }
+    }
+}
+
+/// Return true, if the width of the characters is checked and any of them is wide.
+///
+/// See [`util::has_wide_chars`] for the characters, which are wide.
+fn wide_characters(options: &Option) -> bool {
+    options.check_width
+        && (util::has_wide_chars(&options.characters)
+            || options
+                .ramp_cycle
+                .iter()
+                .any(|characters| util::has_wide_chars(characters)))
+}
+
+#[cfg(test)]
+mod test_check_width {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    /// Convert a gradient with full-width characters to 20 columns.
+    fn convert_wide(check_width: bool) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(100, 100, |x, _| {
+            image::Rgb([(x * 2) as u8; 3])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(NonZeroU32::new(20).unwrap())
+            .check_width(check_width);
+        builder.characters("\u{FF2D}\u{FF37}\u{FF0B}\u{3000}".to_string());
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn columns_are_halved() {
+        let first_line = |output: String| output.lines().next().unwrap().chars().count();
+        assert_eq!(20, first_line(convert_wide(false)));
+        assert_eq!(10, first_line(convert_wide(true)));
+    }
+
+    #[test]
+    fn same_number_of_rows() {
+        assert_eq!(
+            convert_wide(false).lines().count(),
+            convert_wide(true).lines().count()
+        );
+    }
+
+    #[test]
+    fn narrow_characters_unchanged() {
+        let mut builder = OptionBuilder::new();
+        builder.check_width(true);
+        assert!(!wide_characters(&builder.build()));
+    }
+}
+
+#[cfg(test)]
+mod test_output_dimensions {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn narrow_image_unchanged() {
+        let mut builder = OptionBuilder::new();
+        builder.max_display_width(Some(100));
+        assert_eq!(
+            output_dimensions(512, 512, &OptionBuilder::new().build()),
+            output_dimensions(512, 512, &builder.build())
+        );
+    }
+
+    #[test]
+    fn wide_image_is_shrunk() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target_size(NonZeroU32::new(120).unwrap())
+            .max_display_width(Some(60));
+        assert_eq!(60, output_dimensions(512, 512, &builder.build()).0);
+    }
+
+    #[test]
+    fn height_is_shrunk_to_width() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target_size(NonZeroU32::new(100).unwrap())
+            .dimension(util::ResizingDimension::Height)
+            .max_display_width(Some(60));
+        assert_eq!(60, output_dimensions(1024, 256, &builder.build()).0);
+    }
+
+    #[test]
+    fn outside_border_is_included() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target_size(NonZeroU32::new(120).unwrap())
+            .border(true)
+            .border_inside(false)
+            .max_display_width(Some(60));
+        assert_eq!(58, output_dimensions(512, 512, &builder.build()).0);
+    }
+}
+
+#[cfg(test)]
+mod test_convert_edge_color {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    /// Return the html colors of the first row.
+    fn first_row_colors(converted: &str) -> Vec<[u8; 3]> {
+        let row = converted
+            .lines()
+            .find(|line| line.contains("<span"))
+            .unwrap();
+        row.split("color: #")
+            .skip(1)
+            .map(|span| {
+                let hex = &span[..6];
+                [0, 2, 4].map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn only_edges_keep_color() {
+        //red on the left, green on the right, so there is a single edge in the middle
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(400, 200, |x, _| {
+            if x < 200 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 255, 0])
+            }
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .edge_color_only(true);
+
+        let colors = first_row_colors(&convert(img, builder.build()));
+        let is_gray = |color: &[u8; 3]| color[0] == color[1] && color[1] == color[2];
+
+        //the flat regions are desaturated
+        assert!(is_gray(&colors[0]));
+        assert!(is_gray(colors.last().unwrap()));
+        //the cells at the edge keep their color
+        assert!(colors[colors.len() / 2 - 2..colors.len() / 2 + 2]
+            .iter()
+            .any(|color| !is_gray(color)));
+    }
+
+    #[test]
+    fn padded_edges_line_up() {
+        //the padded tiles are 3 pixels wide and cover 24 pixels, the edge is at the start of the sixth column
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(22, 20, |x, _| {
+            if x < 16 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 255, 0])
+            }
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .target_size(std::num::NonZeroU32::new(10).unwrap())
+            .edge_mode(options::EdgeMode::Pad)
+            .edge_color_only(true);
+
+        let colors = first_row_colors(&convert(img, builder.build()));
+        let is_gray = |color: &[u8; 3]| color[0] == color[1] && color[1] == color[2];
+        assert!(!is_gray(&colors[4]));
+        assert!(!is_gray(&colors[5]));
+        //a resized edge image would be stretched to the right
+        assert!(is_gray(&colors[6]));
+    }
+
+    #[test]
+    fn disabled_keeps_color() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            400,
+            200,
+            image::Rgb([255, 0, 0]),
+        ));
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::HtmlFile(true, false));
+
+        let colors = first_row_colors(&convert(img, builder.build()));
+        assert!(colors.iter().all(|color| color == &[255, 0, 0]));
+    }
+}
+
+#[cfg(test)]
+mod test_convert_tone_map {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn high_key_uses_darker_characters() {
+        //a bright gradient, which only uses the densest characters
+        let img = DynamicImage::ImageLuma8(image::GrayImage::from_fn(400, 200, |x, _| {
+            image::Luma([200 + (x * 55 / 399) as u8])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let plain = convert(img.clone(), builder.build());
+        let mapped = convert(img, builder.tone_map(true).build());
+
+        //characters after the first quarter of the density
+        let darker = |converted: &str| {
+            let mut chars: Vec<char> = converted
+                .chars()
+                .filter(|char| !"MWNXK\n".contains(*char))
+                .collect();
+            chars.sort_unstable();
+            chars.dedup();
+            chars.len()
+        };
+        assert_eq!(0, darker(&plain));
+        assert!(darker(&mapped) >= 2);
+    }
+}
+
+#[cfg(test)]
+mod test_convert_color_border {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn convert_border(color_border: bool) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            400,
+            200,
+            image::Rgb([255, 0, 0]),
+        ));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .border(true)
+            .color_border(color_border);
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn border_is_colored() {
+        let converted = convert_border(true);
+        for char in ['╔', '═', '╗', '║', '╚', '╝'] {
+            assert!(converted.contains(&format!("<span style=\"color: #FF0000\">{char}</span>")));
+        }
+    }
+
+    #[test]
+    fn border_is_plain() {
+        let converted = convert_border(false);
+        assert!(converted.contains("<pre>╔═"));
+        assert!(!converted.contains("\">║</span>"));
+    }
+}
+
+#[cfg(test)]
+mod test_convert_border_inside {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    /// Return the width of the first line in chars.
+    fn width(border: bool, border_inside: bool) -> usize {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            400,
+            200,
+            image::Rgb([255, 0, 0]),
+        ));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .border(border)
+            .border_inside(border_inside);
+        let converted = convert(img, builder.build());
+        converted.lines().next().unwrap().chars().count()
+    }
+
+    #[test]
+    fn border_inside_keeps_width() {
+        assert_eq!(width(false, true), width(true, true));
+    }
+
+    #[test]
+    fn border_outside_adds_columns() {
+        assert_eq!(width(false, true) + 2, width(true, false));
+    }
+}
+
+#[cfg(test)]
+mod test_convert_comment_prefix {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn convert_comment(target: TargetType) -> String {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(400, 200, |x, _| {
+            image::Rgb([(x % 256) as u8, 100, 50])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(target)
+            .border(true)
+            .ruler(true)
+            .comment_prefix(Some("// ".to_string()));
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn every_line_prefixed() {
+        let converted = convert_comment(TargetType::File);
+        assert!(converted.lines().all(|line| line.starts_with("// ")));
+    }
+
+    #[test]
+    fn shell_without_color() {
+        let converted = convert_comment(TargetType::Shell(true, false));
+        assert!(converted.lines().all(|line| line.starts_with("// ")));
+        assert!(!converted.contains('\x1b'));
+    }
+
+    #[test]
+    fn html_not_prefixed() {
+        let converted = convert_comment(TargetType::HtmlFile(true, false));
+        assert!(!converted.contains("// "));
+    }
+}
+
+#[cfg(test)]
+mod test_convert_ramp_cycle {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn rows_alternate_ramps() {
+        let img = DynamicImage::ImageLuma8(image::GrayImage::from_fn(400, 200, |x, _| {
+            image::Luma([(x * 255 / 399) as u8])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .ramp_cycle(vec!["ABC ".to_string(), "xyz ".to_string()]);
+        let converted = convert(img, builder.build());
+
+        for (index, line) in converted.lines().enumerate() {
+            let ramp = if index % 2 == 0 { "ABC " } else { "xyz " };
+            assert!(line.chars().all(|char| ramp.contains(char)));
+            //the gradient uses all characters of the ramp
+            assert!(ramp.chars().all(|char| line.contains(char)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_convert_ruler {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn convert_with_ruler(target: TargetType, border: bool) -> String {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(target).border(border).ruler(true);
+        convert(img, builder.build())
+    }
+
+    #[test]
+    fn markers_at_expected_positions() {
+        let converted = convert_with_ruler(TargetType::File, false);
+        let lines: Vec<&str> = converted.lines().collect();
+        //the ruler column is three wide, since the last marked row has two digits
+        assert!(lines[0].starts_with("   0         10        20"));
+        assert!(lines[1].starts_with(" 0 "));
+        assert!(lines[2].starts_with("   "));
+        assert!(lines[11].starts_with("10 "));
+        assert!(lines[21].starts_with("20 "));
+    }
+
+    #[test]
+    fn image_is_not_changed() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let plain = convert(img, builder.build());
+
+        let converted = convert_with_ruler(TargetType::File, false);
+        for (plain_line, ruler_line) in plain.lines().zip(converted.lines().skip(1)) {
+            assert_eq!(plain_line, &ruler_line[3..]);
+        }
+    }
+
+    #[test]
+    fn markers_with_border() {
+        let converted = convert_with_ruler(TargetType::File, true);
+        let lines: Vec<&str> = converted.lines().collect();
+        //the first column is behind the left border
+        assert!(lines[0].starts_with("    0         10"));
+        assert!(lines[1].starts_with("   ╔"));
+        assert!(lines[2].starts_with(" 0 ║"));
+        assert!(lines.last().unwrap().starts_with("   ╚"));
+    }
+
+    #[test]
+    fn no_ruler_for_html() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::HtmlFile(true, false));
+        assert_eq!(
+            convert(img, builder.build()),
+            convert_with_ruler(TargetType::HtmlFile(true, false), false)
+        );
+    }
+}
+
+/// Takes the frames of an animation and returns each of them as an ascii art string, together with its delay.
+///
+/// The delay is the time the frame should be shown, before the next frame is displayed.
+/// Every frame is converted using the same `options`. Frames with a different size than the first frame are resized to it,
+/// so all converted frames have the same number of columns and rows.
+/// With `auto_invert`, the background is only detected from the first frame, so the density does not flip between frames.
+///
+/// # Examples
+/// ```no_run
+/// use artem::options::OptionBuilder;
+/// use image::AnimationDecoder;
+///
+/// let file = std::fs::File::open("animation.gif").unwrap();
+/// let frames = image::codecs::gif::GifDecoder::new(file)
+///     .unwrap()
+///     .into_frames()
+///     .collect_frames()
+///     .unwrap();
+/// let converted_frames = artem::convert_frames(frames, OptionBuilder::new().build());
+/// ```
+pub fn convert_frames(frames: Vec<Frame>, mut options: Option) -> Vec<(String, Duration)> {
+    debug!("Converting {} frames", frames.len());
+    let size = frames.first().map(|frame| frame.buffer().dimensions());
+
+    //detect the background once, instead of for every frame
+    if let (true, Some(first)) = (options.auto_invert, frames.first()) {
+        options.invert = dark_background(
+            &DynamicImage::ImageRgba8(first.buffer().clone()),
+            options.luminance_weights,
+        );
+        options.auto_invert = false;
+        debug!("Automatically inverted: {}", options.invert);
+    }
+    frames
+        .into_iter()
+        .map(|frame| {
+            let delay = Duration::from(frame.delay());
+            let mut image = DynamicImage::ImageRgba8(frame.into_buffer());
+            if let Some((width, height)) = size.filter(|&size| size != image.dimensions()) {
+                trace!("Resizing frame to {width}x{height}");
+                image = image.resize_exact(width, height, image::imageops::FilterType::Nearest);
+            }
+            (convert(image, options.clone()), delay)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_convert_frames {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use image::{Delay, RgbaImage};
+
+    #[test]
+    fn delays_are_kept() {
+        let frames = vec![
+            Frame::from_parts(
+                RgbaImage::from_pixel(4, 4, image::Rgba([0, 0, 0, 255])),
+                0,
+                0,
+                Delay::from_numer_denom_ms(100, 1),
+            ),
+            Frame::from_parts(
+                RgbaImage::from_pixel(4, 4, image::Rgba([255, 255, 255, 255])),
+                0,
+                0,
+                Delay::from_numer_denom_ms(250, 1),
+            ),
+        ];
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+
+        let converted = convert_frames(frames, builder.build());
+        assert_eq!(
+            vec![Duration::from_millis(100), Duration::from_millis(250)],
+            converted
+                .iter()
+                .map(|(_, delay)| *delay)
+                .collect::<Vec<_>>()
+        );
+        //the frames are converted separately
+        assert_ne!(converted[0].0, converted[1].0);
+    }
+
+    #[test]
+    fn differing_dimensions() {
+        let frames = vec![
+            Frame::new(RgbaImage::from_pixel(40, 20, image::Rgba([0, 0, 0, 255]))),
+            Frame::new(RgbaImage::from_pixel(
+                10,
+                30,
+                image::Rgba([255, 255, 255, 255]),
+            )),
+        ];
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .target_size(std::num::NonZeroU32::new(10).unwrap());
+
+        let converted = convert_frames(frames, builder.build());
+        let size = |ascii: &str| {
+            (
+                ascii.lines().count(),
+                ascii.lines().map(|line| line.chars().count()).max(),
+            )
+        };
+        assert_eq!(size(&converted[0].0), size(&converted[1].0));
+    }
+
+    #[test]
+    fn no_frames() {
+        assert!(convert_frames(Vec::new(), OptionBuilder::new().build()).is_empty());
+    }
+
+    #[test]
+    fn auto_invert_uses_first_frame() {
+        let black = RgbaImage::from_pixel(4, 4, image::Rgba([0, 0, 0, 255]));
+        let white = RgbaImage::from_pixel(4, 4, image::Rgba([255, 255, 255, 255]));
+        let frames = || vec![Frame::new(black.clone()), Frame::new(white.clone())];
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+
+        //the first frame is dark, so every frame uses the inverted density
+        let fixed = convert_frames(frames(), builder.invert(true).build());
+        let auto = convert_frames(frames(), builder.invert(false).auto_invert(true).build());
+        assert_eq!(fixed, auto);
+    }
+}
+
+/// Convert the image into separate layers for the characters and their colors.
+///
+/// The first layer contains the characters of each row, the second layer the rgb color of each character.
+/// Both layers are aligned: they have the same number of rows and the `n`th char of a row has the `n`th color of the same row.
+/// The colors are the same ones, which [`convert`] would use for a colored target.
+///
+/// Only the image itself is converted, the border, the ruler, the spacing and the comment prefix are not included.
+///
+/// # Examples
+/// ```no_run
+/// use artem::options::OptionBuilder;
+///
+/// let img = image::open("examples/abraham_lincoln.jpg").unwrap();
+/// let (chars, colors) = artem::convert_layers(img, &OptionBuilder::new().build());
+/// assert_eq!(chars.len(), colors.len());
+/// ```
+pub fn convert_layers(image: DynamicImage, options: &Option) -> (Vec<String>, Vec<Vec<[u8; 3]>>) {
+    let mut options = options.clone();
+    if options.auto_invert {
+        options.invert = dark_background(&image, options.luminance_weights);
+    }
+    let options = &options;
+
+    let (cells, columns, rows, glyphs) = calculate_cells(image, options);
+
+    //use plain text, so the chars do not contain any color sequences
+    let plain_options = options.to_builder().target(TargetType::File).build();
+
+    let mut chars = Vec::with_capacity(rows as usize);
+    let mut colors = Vec::with_capacity(rows as usize);
+    for (index, row) in cells.chunks(columns as usize).enumerate() {
+        let density = options.row_characters(index);
+
+        let mut row_chars = String::with_capacity(columns as usize);
+        for (column, cell) in row.iter().enumerate() {
+            let glyph = glyphs
+                .get(index * columns as usize + column)
+                .copied()
+                .flatten();
+            push_glyph_or_cell(cell, glyph, density, &plain_options, &mut row_chars);
+        }
+        chars.push(row_chars);
+        colors.push(
+            row.iter()
+                .map(|cell| pixel::output_color(cell, options))
+                .collect(),
+        );
+    }
+    (chars, colors)
+}
+
+#[cfg(test)]
+mod test_convert_layers {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn layers_are_aligned() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+        }));
+        let (chars, colors) = convert_layers(img, &OptionBuilder::new().build());
+
+        assert!(!chars.is_empty());
+        assert_eq!(chars.len(), colors.len());
+        for (row_chars, row_colors) in chars.iter().zip(&colors) {
+            assert_eq!(row_chars.chars().count(), row_colors.len());
+        }
+    }
+
+    #[test]
+    fn chars_match_convert() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+        }));
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let options = builder.build();
+
+        let (chars, _) = convert_layers(img.clone(), &options);
+        assert_eq!(convert(img, options), chars.join("\n"));
+    }
+
+    #[test]
+    fn colors_match_cells() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            300,
+            200,
+            image::Rgb([255, 0, 0]),
+        ));
+        let (_, colors) = convert_layers(img, &OptionBuilder::new().build());
+        assert!(colors.iter().flatten().all(|color| *color == [255, 0, 0]));
+    }
+}
+
+/// Return the luminosity of every cell, which selects its character, row by row.
+///
+/// The grid contains one value per cell, so it has the same number of columns and rows as the converted image.
+/// The luminosities are taken after the cells have been adjusted, for example by the contrast or the brightness.
+///
+/// Returns the number of columns and rows, followed by the luminosities.
+///
+/// # Examples
+/// ```
+/// use artem::options::OptionBuilder;
+/// use std::num::NonZeroU32;
+///
+/// let img = image::DynamicImage::new_rgb8(400, 200);
+/// let (columns, rows, grid) = artem::luminance_grid(img, &OptionBuilder::new().target_size(NonZeroU32::new(40).unwrap()).build());
+/// assert_eq!((columns * rows) as usize, grid.len());
+/// ```
+pub fn luminance_grid(image: DynamicImage, options: &Option) -> (u32, u32, Vec<u8>) {
+    let (cells, columns, rows, _) = calculate_cells(image, options);
+    let grid = cells
+        .iter()
+        .map(|cell| cell.luminosity.round().clamp(0f32, 255f32) as u8)
+        .collect();
+    (columns, rows, grid)
+}
+
+#[cfg(test)]
+mod test_luminance_grid {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn grid_matches_output_size() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+        }));
+        let options = OptionBuilder::new().build();
+        let (columns, rows, grid) = luminance_grid(img.clone(), &options);
+
+        assert_eq!(output_size(&img, &options), (columns, rows));
+        assert_eq!((columns * rows) as usize, grid.len());
+    }
+
+    #[test]
+    fn uniform_image() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            300,
+            200,
+            image::Rgb([255, 255, 255]),
+        ));
+        let (_, _, grid) = luminance_grid(img, &OptionBuilder::new().build());
+        assert!(grid.iter().all(|luminosity| *luminosity == 255));
+    }
+}
+
+/// Convert the image into a palette, the palette indices of the cells and the characters.
+///
+/// This is useful for palette based formats, like gifs. The characters are the same as the ones of [`convert_layers`],
+/// every cell of the indices references the color of the palette, which is closest to its color.
+/// If a palette is set in the options, it is used, otherwise the palette contains the distinct colors of the image,
+/// in the order of their first appearance. Since the indices are bytes, the palette contains at most 256 colors,
+/// if the image has more colors, they are quantized with an increasing step until they fit.
+///
+/// Returns the palette, the indices of every row and the characters of every row.
+///
+/// # Examples
+/// ```
+/// use artem::options::OptionBuilder;
+///
+/// let img = image::DynamicImage::new_rgb8(400, 200);
+/// let (palette, indices, chars) = artem::convert_indexed(img, &OptionBuilder::new().build());
+/// assert_eq!(vec![[0, 0, 0]], palette);
+/// assert_eq!(chars.len(), indices.len());
+/// ```
+pub fn convert_indexed(
+    image: DynamicImage,
+    options: &Option,
+) -> (Vec<[u8; 3]>, Vec<Vec<u8>>, Vec<String>) {
+    let (chars, colors) = convert_layers(image, options);
+
+    let (palette, colors) = match &options.palette {
+        Some(palette) => {
+            //only the first 256 colors can be referenced by an index
+            (palette.iter().take(256).copied().collect(), colors)
+        }
+        None => {
+            let mut colors = colors;
+            let mut step = options.color_quantize_step.unwrap_or(1).max(1);
+            loop {
+                let palette = distinct_colors(&colors);
+                if palette.len() <= 256 {
+                    break (palette, colors);
+                }
+                //reduce the colors, until each of them can be referenced by an index
+                step = step.saturating_mul(2);
+                debug!("Quantizing the colors of the palette with step {step}");
+                for color in colors.iter_mut().flatten() {
+                    *color = pixel::snap(*color, step, options.color_distance);
+                }
+            }
+        }
+    };
+
+    let indices = colors
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|color| {
+                    let closest =
+                        palette::nearest(color.map(|c| c as f32), &palette, options.color_distance);
+                    palette
+                        .iter()
+                        .position(|entry| *entry == closest)
+                        .unwrap_or_default() as u8
+                })
+                .collect()
+        })
+        .collect();
+    (palette, indices, chars)
+}
+
+/// Return the distinct colors in the order of their first appearance.
+fn distinct_colors(colors: &[Vec<[u8; 3]>]) -> Vec<[u8; 3]> {
+    let mut seen = std::collections::HashSet::new();
+    colors
+        .iter()
+        .flatten()
+        .filter(|color| seen.insert(**color))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod test_convert_indexed {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    /// Create an image with a color gradient, which has more than 256 colors.
+    fn gradient() -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8])
+        }))
+    }
+
+    fn assert_valid(palette: &[[u8; 3]], indices: &[Vec<u8>], chars: &[String]) {
+        assert!(palette.len() <= 256);
+        assert_eq!(chars.len(), indices.len());
+        for (row_indices, row_chars) in indices.iter().zip(chars) {
+            assert_eq!(row_chars.chars().count(), row_indices.len());
+            assert!(row_indices
+                .iter()
+                .all(|index| (*index as usize) < palette.len()));
+        }
+    }
+
+    #[test]
+    fn indices_reference_palette() {
+        let (_, colors) = convert_layers(gradient(), &OptionBuilder::new().build());
+        assert!(distinct_colors(&colors).len() > 256);
+
+        let (palette, indices, chars) = convert_indexed(gradient(), &OptionBuilder::new().build());
+        assert_valid(&palette, &indices, &chars);
+    }
+
+    #[test]
+    fn respects_quantize_step() {
+        let mut builder = OptionBuilder::new();
+        builder.color_quantize_step(Some(64));
+        let options = builder.build();
+        let (palette, indices, chars) = convert_indexed(gradient(), &options);
+        assert_valid(&palette, &indices, &chars);
+        //every channel is one of 0, 64, 128 or 192
+        assert!(palette.len() <= 4 * 4 * 4);
+
+        //the indices reference the colors of the cells
+        let (_, colors) = convert_layers(gradient(), &options);
+        for (row_indices, row_colors) in indices.iter().zip(&colors) {
+            for (index, color) in row_indices.iter().zip(row_colors) {
+                assert_eq!(*color, palette[*index as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn uses_given_palette() {
+        let mut builder = OptionBuilder::new();
+        builder.palette(Some(vec![[0, 0, 0], [255, 255, 255]]));
+        let (palette, indices, chars) = convert_indexed(gradient(), &builder.build());
+        assert_eq!(vec![[0, 0, 0], [255, 255, 255]], palette);
+        assert_valid(&palette, &indices, &chars);
+    }
+}
+
+/// Convert the image and return only the cells, which are different from the `previous` grid.
+///
+/// The returned [`delta::DeltaFrame`] also contains the full grid of the image, which is used as the previous grid of the next frame.
+/// Starting with an empty grid returns every cell, so the first frame is drawn completely.
+/// The cells are the same as the layers of [`convert_layers`].
+///
+/// # Examples
+/// ```
+/// use artem::{delta::Grid, options::OptionBuilder};
+///
+/// let options = OptionBuilder::new().build();
+/// let first = artem::convert_delta(&Grid::default(), image::DynamicImage::new_rgb8(400, 200), &options);
+/// let second = artem::convert_delta(&first.grid, image::DynamicImage::new_rgb8(400, 200), &options);
+/// assert!(second.changes.is_empty());
+/// ```
+pub fn convert_delta(
+    previous: &delta::Grid,
+    next_image: DynamicImage,
+    options: &Option,
+) -> delta::DeltaFrame {
+    let (chars, colors) = convert_layers(next_image, options);
+    delta::diff(previous, delta::Grid::from_layers(&chars, &colors))
+}
+
+#[cfg(test)]
+mod test_convert_delta {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    fn frame(offset: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            if x >= offset && x < offset + 20 && y < 20 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 0, 0])
+            }
+        }))
+    }
+
+    #[test]
+    fn nearly_identical_frames() {
+        let options = OptionBuilder::new().build();
+        let first = convert_delta(&delta::Grid::default(), frame(0), &options);
+        let second = convert_delta(&first.grid, frame(10), &options);
+
+        assert!(!second.changes.is_empty());
+        assert!(second.changes.len() < first.grid.len() / 10);
+    }
+
+    #[test]
+    fn different_frames() {
+        let options = OptionBuilder::new().build();
+        let first = convert_delta(&delta::Grid::default(), frame(0), &options);
+        let white =
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(300, 200, image::Rgb([255; 3])));
+        let second = convert_delta(&first.grid, white, &options);
+
+        assert_eq!(first.grid.len(), second.changes.len());
+    }
+}
+
+/// Return the number of columns and rows of the converted image, without converting it.
+///
+/// The border and the ruler are not included, they add their own columns and rows.
+///
+/// # Examples
+/// ```
+/// use artem::options::OptionBuilder;
+/// use std::num::NonZeroU32;
+///
+/// let img = image::DynamicImage::new_rgb8(400, 200);
+/// let (columns, _) = artem::output_size(&img, &OptionBuilder::new().target_size(NonZeroU32::new(40).unwrap()).build());
+/// assert_eq!(40, columns);
+/// ```
+pub fn output_size(image: &DynamicImage, options: &Option) -> (u32, u32) {
+    let (width, height) = image.dimensions();
+    let (width, height) = if options.auto_rotate && rotation_fits_better(width, height, options) {
+        (height, width)
+    } else {
+        (width, height)
+    };
+    let (columns, rows, _, _) = output_dimensions(width, height, options);
+    if options.transpose {
+        (rows, columns)
+    } else {
+        (columns, rows)
+    }
+}
+
+#[cfg(test)]
+mod test_output_size {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn matches_rotated_convert() {
+        let img = DynamicImage::new_rgb8(300, 1000);
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .dimension(util::ResizingDimension::Fit(30))
+            .auto_rotate(true);
+        let options = builder.build();
+
+        let (columns, rows) = output_size(&img, &options);
+        let converted = convert(img, options);
+        assert_eq!(rows as usize, converted.lines().count());
+        assert!(converted
+            .lines()
+            .all(|line| line.chars().count() == columns as usize));
+    }
+
+    #[test]
+    fn matches_convert() {
+        let img = DynamicImage::new_rgb8(300, 200);
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        let options = builder.build();
+
+        let (columns, rows) = output_size(&img, &options);
+        let converted = convert(img, options);
+        assert_eq!(rows as usize, converted.lines().count());
+        assert!(converted
+            .lines()
+            .all(|line| line.chars().count() == columns as usize));
+    }
+}
+
+/// Predict the length in bytes of the converted image, without converting it.
+///
+/// The prediction uses the same dimensions as [`convert`] and a fixed cost for the markup or escape codes
+/// of each cell, depending on the target. Html files without a colored background add no markup to whitespace,
+/// so a scaled down copy of the image is used to estimate the number of whitespace cells.
+/// Since the exact length of the colors is only known after converting,
+/// the result is only an estimate, which can be used to preallocate buffers or to check if the output fits into a message.
+///
+/// # Examples
+/// ```no_run
+/// use artem::options::OptionBuilder;
+///
+/// let img = image::open("examples/abraham_lincoln.jpg").unwrap();
+/// let predicted_len = artem::predict_output_len(&img, &OptionBuilder::new().build());
+/// ```
+pub fn predict_output_len(image: &DynamicImage, options: &Option) -> usize {
+    //the comment prefix changes the target, like when converting
+    let mut options = options.clone();
+    let comment_prefix = comment_prefix(&mut options).len();
+    let options = &options;
+
+    let (columns, rows, _, _) = output_dimensions(image.width(), image.height(), options);
+    let (columns, rows) = if options.transpose {
+        (rows as usize, columns as usize)
+    } else {
+        (columns as usize, rows as usize)
+    };
+
+    //both targets only contain the rows, without any decorations
+    if options.half_blocks
+        && matches!(
+            options.target,
+            TargetType::Shell(true, _) | TargetType::AnsiFile(_)
+        )
+    {
+        //every half block is 3 bytes long, plus its foreground and background color
+        return columns * rows * (3 + half_block_overhead()) + rows - 1;
+    }
+    if options.target == TargetType::JsonLines {
+        return json_lines_len(columns, rows, options);
+    }
+
+    let overhead = cell_overhead(options);
+
+    let ruler =
+        options.ruler && matches!(options.target, TargetType::Shell(_, _) | TargetType::File);
+    let ruler_width = if ruler {
+        util::ruler_width(rows as u32)
+    } else {
+        0
+    };
+
+    let backdrop = match (options.backdrop, options.target) {
+        (Some(color), TargetType::Shell(true, _)) => target::ansi::backdrop_sequence(color).len(),
+        _ => 0,
+    };
+
+    //the border chars are all 3 bytes long
+    let border_len = 3 + if options.color_border { overhead } else { 0 };
+    let border_width = if options.border { 2 } else { 0 };
+
+    //whitespace in html files only gets a span, when the background is colored
+    let colored_share = match options.target {
+        TargetType::HtmlFile(true, false) => 1f32 - whitespace_share(image, columns, rows, options),
+        _ => 1f32,
+    };
+
+    let mut len = 0;
+    for row in 0..rows {
+        let characters = options.row_characters(row);
+        //use the average length, since the chars can have different lengths
+        let char_len = if options.braille.is_some() {
+            //every braille char is 3 bytes long
+            3f32
+        } else {
+            characters.len() as f32 / characters.chars().count().max(1) as f32
+        };
+        let cells = columns as f32 * (char_len + overhead as f32 * colored_share);
+        len +=
+            comment_prefix + cells.round() as usize + ruler_width + border_width * border_len + 1;
+
+        if backdrop > 0 {
+            //the backdrop is set again after every cell
+            len += backdrop * (columns + 1) + target::ansi::RESET.len();
+        }
+    }
+
+    if options.border {
+        //top and bottom border
+        len += 2 * (comment_prefix + ruler_width + (columns + 2) * border_len) + 1;
+    } else {
+        //the last line break is removed
+        len -= 1;
+    }
+
+    if options.legend && matches!(options.target, TargetType::Shell(_, _) | TargetType::File) {
+        len += 1 + comment_prefix + legend_line(&options.characters).len();
+    }
+
+    if ruler {
+        len += comment_prefix
+            + util::ruler_columns(columns as u32, ruler_width + border_width / 2).len()
+            + 1;
+    }
+
+    match options.target {
+        TargetType::HtmlFile(_, _) => {
+            len += target::html::html_top(options.line_height, options.html_fragment).len()
+                + target::html::html_bottom(options.html_fragment).len()
+        }
+        TargetType::Shell(true, _) if options.clear_screen => len += "\x1b[2J\x1b[H".len(),
+        _ => {}
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod test_predict_output_len {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    /// Assert that the prediction is within 10% of the actual length.
+    fn assert_close(builder: &OptionBuilder) {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+        }));
+        assert_close_for(img, builder);
+    }
+
+    /// Assert that the prediction for the given image is within 10% of the actual length.
+    fn assert_close_for(img: DynamicImage, builder: &OptionBuilder) {
+        let options = builder.build();
+
+        let predicted = predict_output_len(&img, &options) as f32;
+        let actual = convert(img, options).len() as f32;
+        assert!(
+            (predicted - actual).abs() <= actual * 0.1,
+            "predicted {predicted}, actual {actual}"
+        );
+    }
+
+    #[test]
+    fn plain_file() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn plain_file_with_border_and_ruler() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File).border(true).ruler(true);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn html() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::HtmlFile(true, false));
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn html_background() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, true))
+            .border(true);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn html_hsl() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .html_color_format(options::HtmlColorFormat::Hsl);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn html_whitespace() {
+        //the black half is converted to whitespace, which does not get a span
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(300, 200, |x, y| {
+            if x < 150 {
+                image::Rgb([0, 0, 0])
+            } else {
+                image::Rgb([(x % 256) as u8, (y % 256) as u8, 100])
+            }
+        }));
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .characters("@%#*+=-:. ".to_string());
+        assert_close_for(img, &builder);
+    }
+
+    #[test]
+    fn bbcode() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::BBCode);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn json_lines() {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::JsonLines);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn half_blocks() {
+        //ensure that colors will be used
+        std::env::set_var("COLORTERM", "truecolor");
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::AnsiFile(true)).half_blocks(true);
+        assert_close(&builder);
+    }
+
+    #[test]
+    fn comment_prefix() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::Shell(true, false))
+            .comment_prefix(Some("# ".to_string()));
+        assert_close(&builder);
+    }
+}
+
+/// Return the estimated share of the cells, which are converted to a whitespace char.
+///
+/// The image is scaled down to a single pixel per cell, whose char is selected like when converting.
+/// The filters, which change the cells after calculating them, are not applied.
+fn whitespace_share(image: &DynamicImage, columns: usize, rows: usize, options: &Option) -> f32 {
+    if columns == 0 || rows == 0 {
+        return 0f32;
+    }
+    let plain = options.to_builder().target(TargetType::File).build();
+    //the image is not transposed yet
+    let small = if options.transpose {
+        image.thumbnail_exact(rows as u32, columns as u32)
+    } else {
+        image.thumbnail_exact(columns as u32, rows as u32)
+    };
+
+    let mut char = String::with_capacity(4);
+    let mut whitespace = 0;
+    for (x, y, pixel) in small.pixels() {
+        let row = if options.transpose { x } else { y } as usize;
+        char.clear();
+        pixel::push_cell_with_characters(
+            &pixel::Cell::from_block(&[pixel], options.luminance_weights),
+            options.row_characters(row),
+            &plain,
+            &mut char,
+        );
+        if char.chars().all(char::is_whitespace) {
+            whitespace += 1;
+        }
+    }
+    whitespace as f32 / (columns * rows) as f32
+}
+
+/// Return the estimated number of bytes of the json lines of the given size.
+///
+/// Every cell is an object with its char and color, every row an object with its index and cells.
+fn json_lines_len(columns: usize, rows: usize, options: &Option) -> usize {
+    let mut len = 0f32;
+    for row in 0..rows {
+        let characters = options.row_characters(row);
+        let char_len = if options.braille.is_some() {
+            3f32
+        } else {
+            characters.len() as f32 / characters.chars().count().max(1) as f32
+        };
+        //{"char":"","color":[,,]}, with about 2.5 digits per color and the comma between the cells
+        let cells = columns as f32 * (char_len + 32.5) - 1f32;
+        //{"cells":[],"row":} and the line break
+        len += cells + 20f32 + row.to_string().len() as f32;
+    }
+    //the last line break is removed
+    (len.round() as usize).saturating_sub(1)
+}
+
+/// Return the estimated number of bytes, which are added to every half block for its foreground and background color.
+fn half_block_overhead() -> usize {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        0
+    } else if util::supports_truecolor() {
+        //"\x1b[48;2;R;G;B;38;2;R;G;Bm", with about 2.5 digits per color, and the reset
+        37
+    } else {
+        //"\x1b[44;31m" and the reset
+        12
+    }
+}
+
+/// Return the estimated number of bytes, which are added to every char for its color.
+fn cell_overhead(options: &Option) -> usize {
+    match options.target {
+        TargetType::Shell(true, _) | TargetType::AnsiFile(_)
+            if !colored::control::SHOULD_COLORIZE.should_colorize() =>
+        {
+            0
+        }
+        TargetType::Shell(true, _) | TargetType::AnsiFile(_) => match options.color_mode {
+            //"\x1b[38;5;NNNm" and the reset
+            options::ColorMode::Grayscale256 => 15,
+            //"\x1b[38;2;R;G;Bm", with about 2.5 digits per color, and the reset
+            options::ColorMode::Auto if util::supports_truecolor() => 21,
+            //"\x1b[31m" and the reset
+            options::ColorMode::Auto => 9,
+        },
+        TargetType::HtmlFile(true, background_color) => {
+            //<span style="color: "></span> and the longer background-color
+            let span = if background_color { 39 } else { 28 };
+            span + match options.html_color_format {
+                //#RRGGBB
+                options::HtmlColorFormat::Hex => 7,
+                //hsl(H, S%, L%), with about 2.5 digits per value
+                options::HtmlColorFormat::Hsl => 17,
+            }
+        }
+        //[color=#RRGGBB][/color]
+        TargetType::BBCode => 23,
+        _ => 0,
+    }
+}
+
+/// Append the border char to the `output`.
+///
+/// If the border should be colored, it uses the color of the adjacent cell.
+fn push_border(char: char, cell: &pixel::Cell, options: &Option, output: &mut String) {
+    if options.color_border {
+        pixel::push_colored_char(char, cell, options, output);
+    } else {
+        output.push(char);
+    }
+}
+
+/// Takes an image and returns it as an ascii art string, running the conversion inside the given thread pool.
+///
+/// This is the same as [`convert`], but any parallel work is done by the threads of the `pool`,
+/// instead of the global rayon pool. This avoids oversubscription, when an application already manages its own pool
+/// and runs many conversions at the same time.
+/// It requires the `parallel` feature.
+///
+/// # Examples
+/// ```no_run
+/// use artem::options::OptionBuilder;
+///
+/// let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+/// let img = image::open("examples/abraham_lincoln.jpg").unwrap();
+/// let converted_image = artem::convert_in_pool(img, OptionBuilder::new().build(), &pool);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn convert_in_pool(image: DynamicImage, options: Option, pool: &rayon::ThreadPool) -> String {
+    pool.install(|| convert(image, options))
+}
+
+/// Build a thread pool with the given number of threads, which calculate the characters.
+///
+/// The pool should be built once and then be reused with [`convert_in_pool`] for every image or frame,
+/// instead of creating a new pool for each conversion. The output is the same, regardless of the number of threads.
+/// It requires the `parallel` feature.
+///
+/// # Errors
+/// Returns an error, if the threads of the pool could not be created.
+///
+/// # Examples
+/// ```no_run
+/// use artem::options::OptionBuilder;
+/// use std::num::NonZeroU32;
+///
+/// let pool = artem::thread_pool(NonZeroU32::new(2).unwrap()).unwrap();
+/// let img = image::open("examples/abraham_lincoln.jpg").unwrap();
+/// let converted_image = artem::convert_in_pool(img, OptionBuilder::new().build(), &pool);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn thread_pool(
+    threads: std::num::NonZeroU32,
+) -> Result<rayon::ThreadPool, rayon::ThreadPoolBuildError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.get() as usize)
+        .build()
+}
+
+#[cfg(test)]
+#[cfg(feature = "parallel")]
+mod test_convert_in_pool {
+    use super::*;
+    use crate::options::OptionBuilder;
+
+    #[test]
+    fn same_output_in_custom_pool() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File);
+
+        let expected =
+            std::fs::read_to_string("assets/standard_test_img/standard_test_img.txt").unwrap();
+        let converted = convert_in_pool(img.clone(), builder.build(), &pool);
+        assert_eq!(expected.trim_end_matches('\n'), converted);
+        assert_eq!(convert(img, builder.build()), converted);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "parallel")]
+mod test_threads {
+    use super::*;
+    use crate::options::OptionBuilder;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn pool_uses_threads() {
+        let pool = thread_pool(NonZeroU32::new(3).unwrap()).unwrap();
+        assert_eq!(3, pool.current_num_threads());
+    }
+
+    #[test]
+    fn same_output_as_sequential() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File).border(true);
+
+        let expected =
+            std::fs::read_to_string("assets/standard_test_img/standard_test_img_border.txt")
+                .unwrap();
+        for threads in [1, 3] {
+            let pool = thread_pool(NonZeroU32::new(threads).unwrap()).unwrap();
+            assert_eq!(
+                expected.trim_end_matches('\n'),
+                convert_in_pool(img.clone(), builder.build(), &pool)
+            );
+        }
+    }
+
+    #[test]
+    fn centered_in_row_order() {
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::File)
+            .border(true)
+            .anchor_x(util::HorizontalAnchor::Center);
+
+        let converted = convert(img.clone(), builder.build());
+        let pool = thread_pool(NonZeroU32::new(4).unwrap()).unwrap();
+        assert_eq!(converted, convert_in_pool(img, builder.build(), &pool));
+    }
 }
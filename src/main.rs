@@ -9,25 +9,46 @@
 
 use std::{
     fs::File,
-    io::Write,
+    io::{Read, Write},
     num::NonZeroU32,
     path::{Path, PathBuf},
+    process,
 };
 
-use log::{debug, info, trace, warn, LevelFilter};
+use log::{debug, error, info, trace, warn, LevelFilter};
 
 use artem::{
-    options::{OptionBuilder, TargetType},
-    util,
+    options::{
+        AnsiTieBreak, ColorDistance, ColorMode, DensityChannel, EdgeMode, HtmlColorFormat,
+        IndexRounding, OptionBuilder, SauceInfo, TargetType,
+    },
+    util::{self, HorizontalAnchor, VerticalAnchor},
 };
 
 //import cli
 mod cli;
+//loading of config files
+#[cfg(feature = "config")]
+mod config;
+//shell scripts for animations
+mod animate;
+//zip archives for the frames of animations
+#[cfg(feature = "archive")]
+mod archive;
+//inline images for supported terminals
+mod inline;
+//querying the background color of the terminal
+#[cfg(feature = "terminal")]
+mod terminal;
 
 fn main() {
     //get args from cli
     let matches = cli::build_cli().get_matches();
 
+    //merge the values of the config file, the command line arguments take precedence
+    //errors can only be logged after the logger has been started
+    let (matches, file_options, config_error) = load_config(matches);
+
     //get log level from args
     let log_level = match matches.value_of("verbosity") {
         Some("trace") => LevelFilter::Trace,
@@ -47,6 +68,10 @@ fn main() {
         .init();
     trace!("Started logger with trace");
 
+    if let Some((message, code)) = config_error {
+        fatal_error(&message, Some(code));
+    }
+
     //log enabled features
     trace!("Feature web_image: {}", cfg!(feature = "web_image"));
 
@@ -61,6 +86,12 @@ fn main() {
     for value in input {
         let path = Path::new(value);
 
+        if value == "-" {
+            debug!("Input is read from stdin");
+            img_paths.push(value);
+            continue;
+        }
+
         #[cfg(feature = "web_image")]
         {
             if value.starts_with("http") {
@@ -71,55 +102,138 @@ fn main() {
         }
         //check if file exist and is a file (not a directory)
         if !path.exists() {
-            util::fatal_error(format!("File {value} does not exist").as_str(), Some(66));
+            fatal_error(format!("File {value} does not exist").as_str(), Some(66));
         } else if !Path::new(path).is_file() {
-            util::fatal_error(format!("{value} is not a file").as_str(), Some(66));
+            fatal_error(format!("{value} is not a file").as_str(), Some(66));
         }
         debug!("Input {} is a file", value);
         img_paths.push(value);
     }
 
     //density char map
-    let density = if matches.is_present("characters") {
+    let density = if matches.is_present("characters-file") {
+        //safe to unwrap, since it is checked before
+        load_characters_file(matches.value_of("characters-file").unwrap())
+    } else if matches.is_present("characters") {
         match matches.value_of("characters").unwrap() {
-            "short" | "s" | "0" => r#"Ñ@#W$9876543210?!abc;:+=-,._ "#,
-            "flat" | "f" | "1" => r#"MWNXK0Okxdolc:;,'...   "#,
-            "long" | "l" | "2" => {
-                r#"$@B%8&WM#*oahkbdpqwmZO0QLCJUYXzcvunxrjft/\|()1{}[]?-_+~<>i!lI;:,"^`'. "#
-            }
+            "short" | "s" | "0" => artem::pixel::SHORT_CHARACTERS,
+            "flat" | "f" | "1" => artem::pixel::FLAT_CHARACTERS,
+            "long" | "l" | "2" => artem::pixel::LONG_CHARACTERS,
+            "braille" | "b" | "3" => artem::pixel::BRAILLE_RAMP,
             _ => {
                 info!("Using user provided characters");
                 let chars = matches.value_of("characters").unwrap();
                 if chars.is_empty() {
-                    util::fatal_error("Characters cannot be empty", Some(64))
+                    fatal_error("Characters cannot be empty", Some(64))
                 } else {
                     chars
                 }
             }
         }
+        .to_string()
     } else {
         //density map from jp2a
         info!("Using default characters");
-        r#"MWNXK0Okxdolc:;,'...   "#
+        artem::pixel::DEFAULT_CHARACTERS.to_string()
     };
+    if let Some(ramps) = matches.values_of("ramp-cycle") {
+        let ramps: Vec<String> = ramps.map(str::to_string).collect();
+        if ramps.iter().any(String::is_empty) {
+            fatal_error("Characters cannot be empty", Some(64))
+        }
+        debug!("Ramp cycle: {ramps:?}");
+        options_builder.ramp_cycle(ramps);
+    }
+
+    let pad_characters = matches.is_present("pad-characters");
+    debug!("Pad characters: {pad_characters}");
+    if density.chars().count() < 2 && !pad_characters {
+        warn!("A single character results in a solid block, since every cell uses it. Use --pad-characters to add a space as the lightest character.");
+    }
+    options_builder.pad_characters(pad_characters);
+
+    let check_width = matches.is_present("check-width");
+    options_builder.check_width(check_width);
+    debug!("Check width: {check_width}");
+
     debug!("Characters used: \"{density}\"");
-    options_builder.characters(density.to_string());
+    options_builder.characters(density);
 
     //set the default resizing dimension to width
     options_builder.dimension(util::ResizingDimension::Width);
 
+    //a max width is a hard cap, so it is applied after the clamping
+    let mut width_cap = None;
+
     //get target size from args
     //only one arg should be present
-    let target_size = if matches.is_present("height") {
+    let target_size = if matches.is_present("fit-terminal") {
+        //use the largest size, which fits into both terminal dimensions
+        trace!("Using terminal width and height as target size");
+
+        //read terminal size, error when STDOUT is not a tty
+        match terminal_dimensions() {
+            (Some(width), Some(height)) => {
+                options_builder.dimension(util::ResizingDimension::Fit(height));
+                width
+            }
+            _ => fatal_error(
+                "Failed to read terminal size, STDOUT is not a tty",
+                Some(72),
+            ),
+        }
+    } else if matches.is_present("columns") {
+        //use the exact grid, the target size is not used for resizing
+        trace!("Using columns and rows as exact dimensions");
+
+        //both are required together, so it should be safe to unwrap
+        let columns = match matches.value_of("columns").unwrap().parse::<u32>() {
+            Ok(v) if v > 0 => v,
+            _ => fatal_error("Could not work with columns input value", Some(65)),
+        };
+        let rows = match matches.value_of("rows").unwrap().parse::<u32>() {
+            Ok(v) if v > 0 => v,
+            _ => fatal_error("Could not work with rows input value", Some(65)),
+        };
+        debug!("Columns: {columns}");
+        debug!("Rows: {rows}");
+        options_builder.dimension(util::ResizingDimension::Exact { columns, rows });
+        columns
+    } else if matches.is_present("max-width") || matches.is_present("max-height") {
+        //use the largest size, which fits into both caps
+        trace!("Using max width and max height as target size");
+
+        if matches.is_present("max-height") {
+            let max_height = match matches.value_of("max-height").unwrap().parse::<u32>() {
+                Ok(v) => v.max(1), //at least a single row is needed
+                Err(_) => fatal_error("Could not work with max height input value", Some(65)),
+            };
+            debug!("Max height: {max_height}");
+            options_builder.dimension(util::ResizingDimension::Contain(max_height));
+        }
+
+        match matches.value_of("max-width") {
+            Some(max_width) => match max_width.parse::<u32>() {
+                Ok(v) => {
+                    let v = v.max(1); //at least a single column is needed
+                    width_cap = Some(v);
+                    v
+                }
+                Err(_) => fatal_error("Could not work with max width input value", Some(65)),
+            },
+            //only the height is capped, so use the largest width
+            None => 230,
+        }
+    } else if matches.is_present("height") {
         //use max terminal height
         trace!("Using terminal height as target size");
         //change dimension to height
         options_builder.dimension(util::ResizingDimension::Height);
 
         //read terminal size, error when STDOUT is not a tty
-        match terminal_size::terminal_size() {
-            Some(value) => value.1 .0 as u32,
-            None => util::fatal_error(
+        match terminal_dimensions().1 {
+            Some(height) => height,
+            None => fatal_error(
                 "Failed to read terminal size, STDOUT is not a tty",
                 Some(72),
             ),
@@ -129,9 +243,9 @@ fn main() {
         trace!("Using terminal width as target size");
 
         //read terminal size, error when STDOUT is not a tty
-        match terminal_size::terminal_size() {
-            Some(value) => value.0 .0 as u32,
-            None => util::fatal_error(
+        match terminal_dimensions().0 {
+            Some(width) => width,
+            None => fatal_error(
                 "Failed to read terminal size, STDOUT is not a tty",
                 Some(72),
             ),
@@ -145,17 +259,34 @@ fn main() {
             .parse::<u32>()
         {
             Ok(v) => v,
-            Err(_) => util::fatal_error("Could not work with size input value", Some(65)),
+            Err(_) => fatal_error("Could not work with size input value", Some(65)),
         }
     }
     .clamp(
         20,  //min should be 20 to ensure a somewhat visible picture
         230, //img above 230 might not be displayed properly
     );
+    let target_size = width_cap.map_or(target_size, |cap| target_size.min(cap));
 
     debug!("Target Size: {target_size}");
     options_builder.target_size(NonZeroU32::new(target_size).unwrap()); //safe to unwrap, since it is clamped before
 
+    let max_display_width = matches.value_of("max-display-width").map(|max_width| {
+        match max_width.parse::<u32>() {
+            Ok(v) => v.max(1), //at least a single column is needed
+            Err(_) => fatal_error(
+                "Could not work with max display width input value",
+                Some(65),
+            ),
+        }
+    });
+    debug!("Max display width: {max_display_width:?}");
+    let no_wrap_warn = matches.is_present("no-wrap-warn");
+    debug!("No wrap warn: {no_wrap_warn}");
+    if !no_wrap_warn {
+        options_builder.max_display_width(max_display_width);
+    }
+
     //best ratio between height and width is 0.43
     let scale = match matches
         .value_of("scale")
@@ -166,18 +297,256 @@ fn main() {
             0.1f32, //a negative or 0 scale is not allowed
             1f32,   //even a scale above 0.43 is not looking good
         ),
-        Err(_) => util::fatal_error("Could not work with ratio input value", Some(65)),
+        Err(_) => fatal_error("Could not work with ratio input value", Some(65)),
     };
     debug!("Scale: {scale}");
     options_builder.scale(scale);
 
+    //use the cell size of the terminal, so the pixels of the image are square
+    if matches.is_present("square-pixels") {
+        #[cfg(feature = "terminal")]
+        {
+            //only an interactive terminal can answer the query
+            let cell_size = if terminal_size::terminal_size().is_some() {
+                terminal::query_cell_size(std::time::Duration::from_millis(100))
+            } else {
+                None
+            };
+            if cell_size.is_none() {
+                warn!("The terminal did not answer with its cell size, using the default ratio.");
+            }
+            debug!("Cell size: {cell_size:?}");
+            let scale = terminal::square_scale(cell_size);
+            debug!("Square pixel scale: {scale}");
+            options_builder.scale(scale);
+        }
+
+        #[cfg(not(feature = "terminal"))]
+        warn!("Square pixels require the terminal feature, using the default ratio.");
+    }
+
+    let auto_ratio = matches.is_present("auto-ratio");
+    options_builder.auto_char_scale(auto_ratio);
+    debug!("Auto ratio: {auto_ratio}");
+
     let invert = matches.is_present("invert-density");
     debug!("Invert is set to: {invert}");
     options_builder.invert(invert);
 
+    let auto_invert = matches.is_present("auto-invert");
+    debug!("Auto invert: {auto_invert}");
+    options_builder.auto_invert(auto_invert);
+
+    let both_themes = matches.is_present("both-themes");
+    debug!("Both themes is set to: {both_themes}");
+
     let background_color = matches.is_present("background-color");
     debug!("BackgroundColor is set to: {background_color}");
 
+    if let Some(rounding) = matches.value_of("rounding") {
+        let rounding = match rounding {
+            "round" => IndexRounding::Round,
+            "nearest" => IndexRounding::Nearest,
+            _ => IndexRounding::Floor,
+        };
+        options_builder.rounding(rounding);
+        debug!("Rounding: {rounding:?}");
+    }
+
+    let pattern_match = matches.is_present("pattern-match");
+    options_builder.pattern_match(pattern_match);
+    debug!("Pattern match: {pattern_match}");
+
+    if matches.is_present("braille") {
+        let threshold = match matches.value_of("braille-threshold").unwrap().parse::<u8>() {
+            Ok(v) => v,
+            Err(_) => fatal_error(
+                "Could not work with braille threshold input value",
+                Some(65),
+            ),
+        };
+        options_builder.braille(threshold);
+        debug!("Braille threshold: {threshold}");
+    }
+
+    //get the channel, which selects the characters
+    if let Some(channel) = matches.value_of("density-channel") {
+        let channel = match channel {
+            "red" => DensityChannel::Red,
+            "green" => DensityChannel::Green,
+            "blue" => DensityChannel::Blue,
+            "alpha" => DensityChannel::Alpha,
+            _ => DensityChannel::Luminosity,
+        };
+        options_builder.density_channel(channel);
+        debug!("Density channel: {channel:?}");
+    }
+
+    if matches.is_present("grayscale") {
+        debug!("Using grayscale color mode");
+        options_builder.color_mode(ColorMode::Grayscale256);
+    }
+
+    if let Some(tie_break) = matches.value_of("ansi-tie-break") {
+        let tie_break = match tie_break {
+            "bright" => AnsiTieBreak::Bright,
+            _ => AnsiTieBreak::Standard,
+        };
+        options_builder.ansi_tie_break(tie_break);
+        debug!("Ansi tie break: {tie_break:?}");
+    }
+
+    if let Some(distance) = matches.value_of("color-distance") {
+        let distance = match distance {
+            "weighted" => ColorDistance::Weighted,
+            "lab" => ColorDistance::Lab,
+            _ => ColorDistance::Euclidean,
+        };
+        options_builder.color_distance(distance);
+        debug!("Color distance: {distance:?}");
+    }
+
+    if matches.is_present("backdrop") {
+        let backdrop = match util::parse_hex_color(matches.value_of("backdrop").unwrap()) {
+            Some(color) => color,
+            None => fatal_error("Could not work with backdrop color input value", Some(65)),
+        };
+        debug!("Backdrop: {backdrop:?}");
+        options_builder.backdrop(Some(backdrop));
+    }
+
+    //get the fixed color of the characters
+    if matches.is_present("color-fg") {
+        let color = match util::parse_hex_color(matches.value_of("color-fg").unwrap()) {
+            Some(color) => color,
+            None => fatal_error("Could not work with foreground color input value", Some(65)),
+        };
+        debug!("Foreground color: {color:?}");
+        options_builder.fixed_color(Some(color));
+    }
+
+    //get the step for rounding the colors
+    if matches.is_present("color-step") {
+        let step = match matches.value_of("color-step").unwrap().parse::<u8>() {
+            Ok(v) => v.max(1), //a step of 0 would not change the colors
+            Err(_) => fatal_error("Could not work with color step input value", Some(65)),
+        };
+        options_builder.color_quantize_step(Some(step));
+        debug!("Color step: {step}");
+    }
+
+    //get the maximum number of color runs in each line
+    if matches.is_present("max-color-switches") {
+        let max = match matches
+            .value_of("max-color-switches")
+            .unwrap()
+            .parse::<NonZeroU32>()
+        {
+            Ok(v) => v.get(),
+            Err(_) => fatal_error(
+                "Could not work with max color switches input value",
+                Some(65),
+            ),
+        };
+        options_builder.max_color_switches_per_line(Some(max));
+        debug!("Max color switches: {max}");
+    }
+
+    let web_safe = matches.is_present("web-safe");
+    options_builder.web_safe_colors(web_safe);
+    debug!("Web-safe colors: {web_safe}");
+
+    //get the minimum brightness of the colors
+    if matches.is_present("min-brightness") {
+        let min_brightness = match matches.value_of("min-brightness").unwrap().parse::<f32>() {
+            Ok(v) => v.clamp(0f32, 255f32),
+            Err(_) => fatal_error("Could not work with min brightness input value", Some(65)),
+        };
+        options_builder.min_color_luminance(min_brightness);
+        debug!("Min brightness: {min_brightness}");
+    }
+
+    //get the colors of the palette
+    if matches.is_present("palette") {
+        let palette = match matches
+            .value_of("palette")
+            .unwrap()
+            .split(',')
+            .map(|color| util::parse_hex_color(color.trim()))
+            .collect::<Option<Vec<[u8; 3]>>>()
+        {
+            Some(palette) => palette,
+            None => fatal_error("Could not work with palette input value", Some(65)),
+        };
+        debug!("Palette: {palette:?}");
+        options_builder.palette(Some(palette));
+
+        let color_dither = matches.is_present("color-dither");
+        options_builder.color_dither(color_dither);
+        debug!("Color dither: {color_dither}");
+    }
+
+    //get the line height of html files
+    if matches.is_present("line-height") {
+        let line_height = match matches.value_of("line-height").unwrap().parse::<f32>() {
+            Ok(v) if v > 0f32 && v.is_finite() => v,
+            _ => fatal_error("Could not work with line height input value", Some(65)),
+        };
+        options_builder.line_height(Some(line_height));
+        debug!("Line height: {line_height}");
+    }
+
+    //get the prefix for embedding the image as a comment
+    if let Some(prefix) = matches.value_of("comment") {
+        options_builder.comment_prefix(Some(prefix.to_string()));
+        debug!("Comment prefix: {prefix}");
+    }
+
+    let legend = matches.is_present("legend");
+    options_builder.legend(legend);
+    debug!("Legend: {legend}");
+
+    let tile = matches.is_present("tile");
+    options_builder.tile_source(tile);
+    debug!("Tile: {tile}");
+
+    let auto_rotate = matches.is_present("auto-rotate");
+    options_builder.auto_rotate(auto_rotate);
+    debug!("Auto rotate: {auto_rotate}");
+
+    let linear_downscale = matches.is_present("linear-downscale");
+    options_builder.linear_downscale(linear_downscale);
+    debug!("Linear downscale: {linear_downscale}");
+
+    let pixel_art = matches.is_present("pixel-art");
+    options_builder.pixel_art(pixel_art);
+    debug!("Pixel art: {pixel_art}");
+
+    let dither = matches.is_present("dither");
+    options_builder.dither(dither);
+    debug!("Dither: {dither}");
+
+    if let Some(edge_mode) = matches.value_of("edge-mode") {
+        let edge_mode = match edge_mode {
+            "pad" => EdgeMode::Pad,
+            _ => EdgeMode::Crop,
+        };
+        options_builder.edge_mode(edge_mode);
+        debug!("Edge mode: {edge_mode:?}");
+    }
+
+    let border_inside = !matches.is_present("border-outside");
+    options_builder.border_inside(border_inside);
+    debug!("Border inside: {border_inside}");
+
+    let color_border = matches.is_present("color-border");
+    options_builder.color_border(color_border);
+    debug!("Color border: {color_border}");
+
+    let edge_color = matches.is_present("edge-color");
+    options_builder.edge_color_only(edge_color);
+    debug!("Edge color: {edge_color}");
+
     //check if no colors should be used or the if a output file will be used
     //since text documents don`t support ansi ascii colors
     let color = if matches.is_present("no-color") {
@@ -204,6 +573,10 @@ fn main() {
         true
     };
 
+    let clear_screen = matches.is_present("clear");
+    debug!("Clear screen is set to: {clear_screen}");
+    options_builder.clear_screen(clear_screen);
+
     //get flag for border around image
     let border = matches.is_present("border");
     options_builder.border(border);
@@ -219,15 +592,44 @@ fn main() {
     options_builder.transform_y(transform_y);
     debug!("Flipping Y-Axis: {transform_y}");
 
+    let transpose = matches.is_present("transpose");
+    options_builder.transpose(transpose);
+    debug!("Transpose: {transpose}");
+
     //get flags for centering the image
     let center_x = matches.is_present("centerX");
-    options_builder.center_x(center_x);
+    if center_x {
+        options_builder.anchor_x(HorizontalAnchor::Center);
+    }
     debug!("Centering X-Axis: {center_x}");
 
     let center_y = matches.is_present("centerY");
-    options_builder.center_y(center_y);
+    if center_y {
+        options_builder.anchor_y(VerticalAnchor::Center);
+    }
     debug!("Center Y-Axis: {center_y}");
 
+    //get the anchors for placing the image
+    if let Some(align_x) = matches.value_of("align-x") {
+        let anchor_x = match align_x {
+            "center" => HorizontalAnchor::Center,
+            "end" => HorizontalAnchor::End,
+            _ => HorizontalAnchor::Start,
+        };
+        options_builder.anchor_x(anchor_x);
+        debug!("Anchor X-Axis: {anchor_x:?}");
+    }
+
+    if let Some(align_y) = matches.value_of("align-y") {
+        let anchor_y = match align_y {
+            "center" => VerticalAnchor::Center,
+            "end" => VerticalAnchor::End,
+            _ => VerticalAnchor::Start,
+        };
+        options_builder.anchor_y(anchor_y);
+        debug!("Anchor Y-Axis: {anchor_y:?}");
+    }
+
     //get flag for creating an outline
     let outline = matches.is_present("outline");
     options_builder.outline(outline);
@@ -241,6 +643,82 @@ fn main() {
         if hysteresis {
             warn!("Using hysteresis might result in an worse looking ascii image than only using --outline")
         }
+
+        let outline_normalize = matches.is_present("outline-normalize");
+        options_builder.outline_normalize(outline_normalize);
+        debug!("Outline normalize: {outline_normalize}");
+
+        //get the gamma for the hysteresis thresholds
+        if matches.is_present("hysteresis-gamma") {
+            let gamma = match matches.value_of("hysteresis-gamma").unwrap().parse::<f32>() {
+                Ok(v) if v > 0f32 && v.is_finite() => v,
+                _ => fatal_error("Could not work with hysteresis gamma input value", Some(65)),
+            };
+            options_builder.hysteresis_gamma(gamma);
+            debug!("Hysteresis gamma: {gamma}");
+        }
+    }
+
+    let ruler = matches.is_present("ruler");
+    options_builder.ruler(ruler);
+    debug!("Ruler: {ruler}");
+
+    let tone_map = matches.is_present("tone-map");
+    options_builder.tone_map(tone_map);
+    debug!("Tone map: {tone_map}");
+
+    let equalize = matches.is_present("equalize");
+    options_builder.equalize(equalize);
+    debug!("Equalize: {equalize}");
+
+    if matches.is_present("min-contrast") {
+        let min_contrast = match matches.value_of("min-contrast").unwrap().parse::<f32>() {
+            Ok(v) if (0f32..=255f32).contains(&v) => v,
+            _ => fatal_error("Could not work with min contrast input value", Some(65)),
+        };
+        options_builder.min_contrast(min_contrast);
+        debug!("Min contrast: {min_contrast}");
+    }
+
+    let smooth = matches.is_present("smooth");
+    options_builder.smooth_horizontal(smooth);
+    debug!("Smooth: {smooth}");
+
+    //get size of the gaussian blur kernel, which is used for sharpening
+    if matches.is_present("kernel-size") {
+        let kernel_size = match matches.value_of("kernel-size").unwrap().parse::<u32>() {
+            //the kernel needs a center pixel
+            Ok(v) if v % 2 == 1 => v,
+            _ => fatal_error("Could not work with kernel size input value", Some(65)),
+        };
+        options_builder.kernel_size(kernel_size);
+        debug!("Kernel size: {kernel_size}");
+    }
+
+    //get amount of sharpening
+    if matches.is_present("sharpen") {
+        let sharpen = match matches.value_of("sharpen").unwrap().parse::<f32>() {
+            Ok(v) => v.clamp(
+                0f32,  //a negative amount would blur the image
+                10f32, //larger values only produce noise
+            ),
+            Err(_) => fatal_error("Could not work with sharpen input value", Some(65)),
+        };
+        options_builder.sharpen(sharpen);
+        debug!("Sharpen: {sharpen}");
+    }
+
+    let html_fragment = matches.is_present("html-fragment");
+    options_builder.html_fragment(html_fragment);
+    debug!("Html fragment: {html_fragment}");
+
+    if let Some(format) = matches.value_of("html-color") {
+        let format = match format {
+            "hsl" => HtmlColorFormat::Hsl,
+            _ => HtmlColorFormat::Hex,
+        };
+        options_builder.html_color_format(format);
+        debug!("Html color format: {format:?}");
     }
 
     //get output file extension for specific output, default to plain text
@@ -252,6 +730,17 @@ fn main() {
         let file_extension = file_path.extension().and_then(std::ffi::OsStr::to_str);
         debug!("FileExtension: {:?}", file_extension);
 
+        //the frames in zip archives use the extension before it, for example frames.html.zip
+        let file_extension = if file_extension == Some("zip") {
+            file_path
+                .file_stem()
+                .map(Path::new)
+                .and_then(Path::extension)
+                .and_then(std::ffi::OsStr::to_str)
+        } else {
+            file_extension
+        };
+
         options_builder.target(match file_extension {
             Some("html") | Some("htm") => {
                 debug!("Target: Html-File");
@@ -272,31 +761,239 @@ fn main() {
                     TargetType::AnsiFile(background_color)
                 }
             }
+            Some("bbcode") => {
+                debug!("Target: BBCode-File");
+
+                //bbcode files are always colored, since there is no reason to use them otherwise
+                if matches.is_present("no-color") {
+                    warn!("The --no-color argument conflicts with the target file type. Falling back to plain text file without colors.");
+                    TargetType::File
+                } else {
+                    if background_color {
+                        warn!("BBCode does not support background colors, the background flag will be ignored.")
+                    }
+                    TargetType::BBCode
+                }
+            }
+            Some("rle") => {
+                debug!("Target: Rle-File");
+                TargetType::Rle
+            }
+            Some("jsonl") => {
+                debug!("Target: Json-Lines-File");
+                TargetType::JsonLines
+            }
             _ => {
                 debug!("Target: File");
 
                 if !matches.is_present("no-color") {
                     //warn user that output is not colored
-                    warn!("Filetype does not support using colors. For colored output file please use either .html or .ansi files");
+                    warn!("Filetype does not support using colors. For colored output file please use either .html, .ansi or .bbcode files");
                 }
                 TargetType::File
             }
         });
+    } else if matches.is_present("clipboard") {
+        debug!("Target: Clipboard");
+        //the escape sequences of colors would be pasted as text
+        options_builder.target(TargetType::File);
     } else {
         debug!("Target: Shell");
         options_builder.target(TargetType::Shell(color, background_color));
     }
 
+    let half_blocks = matches.is_present("half-blocks");
+    if half_blocks
+        && !matches!(
+            options_builder.build().target,
+            TargetType::Shell(true, _) | TargetType::AnsiFile(_)
+        )
+    {
+        fatal_error(
+            "Half blocks are only supported in the terminal and in .ans files",
+            Some(64),
+        );
+    }
+    options_builder.half_blocks(half_blocks);
+    debug!("Half blocks: {half_blocks}");
+
+    if let Some(title) = matches.value_of("sauce-title") {
+        if matches!(options_builder.build().target, TargetType::AnsiFile(_)) {
+            let days = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs() / 86400)
+                .unwrap_or_default();
+            let sauce = SauceInfo {
+                title: title.to_string(),
+                author: matches
+                    .value_of("sauce-author")
+                    .unwrap_or_default()
+                    .to_string(),
+                group: matches
+                    .value_of("sauce-group")
+                    .unwrap_or_default()
+                    .to_string(),
+                date: sauce_date(days),
+            };
+            debug!("Sauce: {sauce:?}");
+            options_builder.sauce(Some(sauce));
+        } else {
+            warn!("Only .ans files support SAUCE records, the sauce arguments will be ignored.");
+        }
+    }
+
+    //pick the invert and minimum brightness using the background color of the terminal
+    if matches.is_present("detect-background") {
+        #[cfg(feature = "terminal")]
+        {
+            //only an interactive terminal can answer the query
+            if !matches.is_present("output-file") && terminal_size::terminal_size().is_some() {
+                match terminal::query_background(std::time::Duration::from_millis(100)) {
+                    Some(background) => {
+                        let (invert, min_brightness) = terminal::settings(background);
+                        debug!("Terminal background: {background:?}");
+                        options_builder.invert(invert);
+                        //an explicit minimum brightness is kept
+                        if !matches.is_present("min-brightness") {
+                            options_builder.min_color_luminance(min_brightness);
+                        }
+                    }
+                    None => warn!("The terminal did not answer with its background color, using the default settings."),
+                }
+            }
+        }
+
+        #[cfg(not(feature = "terminal"))]
+        warn!("Detecting the terminal background requires the terminal feature, using the default settings.");
+    }
+
+    //use the options of a config file in the format of the library, the command line arguments take precedence
+    if let Some(merge) = file_options {
+        options_builder = merge(&options_builder, &matches);
+        debug!("Merged the options of the config file");
+    }
+
+    //the protocol for sending the original image, if it is forced or detected
+    let inline_protocol = match matches.value_of("inline-image") {
+        Some("kitty") => Some(inline::Protocol::Kitty),
+        Some("iterm") => Some(inline::Protocol::ITerm),
+        Some(_) => inline::detect(),
+        None => None,
+    };
+    debug!("Inline image protocol: {inline_protocol:?}");
+
+    //the frame of animated images, which is converted
+    let frame = match matches.value_of("frame").unwrap().parse::<usize>() {
+        Ok(v) => v,
+        Err(_) => fatal_error("Could not work with frame input value", Some(65)),
+    };
+    debug!("Frame: {frame}");
+
+    let dump_codepoints = matches.is_present("dump-codepoints");
+    debug!("Dump codepoints: {dump_codepoints}");
+
+    let color_map_path = matches.value_of("color-map");
+    debug!("Color map: {color_map_path:?}");
+    //colors of all images, which are written to the color map file
+    let mut color_map_output = String::new();
+
+    let dump_luminance_path = matches.value_of("dump-luminance");
+    debug!("Dump luminance: {dump_luminance_path:?}");
+    //luminance grids of all images, which are written to the pgm file
+    let mut luminance_output = Vec::new();
+
+    let animate = matches.is_present("animate");
+    debug!("Animate: {animate}");
+
+    //print the converted frames directly, instead of a script
+    let print_frames = matches.is_present("frames");
+    debug!("Print frames: {print_frames}");
+    let frame_delimiter = matches.value_of("frame-delimiter");
+    debug!("Frame delimiter: {frame_delimiter:?}");
+
+    //write the frames of the animation to a zip archive, instead of a script
+    let archive = matches
+        .value_of("output-file")
+        .map(Path::new)
+        .and_then(Path::extension)
+        == Some(std::ffi::OsStr::new("zip"));
+    debug!("Archive: {archive}");
+    if archive && !cfg!(feature = "archive") {
+        fatal_error(
+            "Zip archives are only supported with the archive feature",
+            Some(64),
+        );
+    }
+    if archive && !animate {
+        fatal_error(
+            "Zip archives are only supported for animations, use --animate",
+            Some(64),
+        );
+    }
+
+    let clipboard = matches.is_present("clipboard");
+    debug!("Clipboard: {clipboard}");
+    if clipboard && !cfg!(feature = "clipboard") {
+        fatal_error(
+            "Copying to the clipboard is only supported with the clipboard feature",
+            Some(64),
+        );
+    }
+
+    let dry_run = matches.is_present("dry-run");
+    debug!("Dry run: {dry_run}");
+
+    //the label would break the structure of html files
+    let label = matches.is_present("label");
+    let label = if label && matches!(options_builder.build().target, TargetType::HtmlFile(_, _)) {
+        warn!("Html files do not support labels, the label flag will be ignored.");
+        false
+    } else {
+        label
+    };
+    debug!("Label: {label}");
+
     let mut output = String::new();
+    //converted frames of all animations, they are combined in a single script
+    let mut frames = Vec::new();
+    //width of the widest image, which is stored in the SAUCE record
+    let mut art_columns = 0;
+
+    //the library returns errors instead of exiting, so the exit code is chosen here
+    let convert = |img, options| match artem::try_convert(img, options) {
+        Ok(ascii) => ascii,
+        Err(err) => fatal_error(&err.to_string(), Some(err.exit_code())),
+    };
 
     for (index, path) in img_paths.iter().enumerate() {
+        if animate || print_frames {
+            info!("Converting frames of: {}", path);
+            //stdin can not be opened as a file, so its frames are decoded from memory
+            let loaded = if *path == "-" {
+                animate::decode_frames(&read_stdin(), "stdin")
+            } else {
+                animate::load_frames(path)
+            };
+            match loaded {
+                Ok(value) => frames.extend(artem::convert_frames(value, options_builder.build())),
+                Err((msg, code)) => fatal_error(&msg, Some(code)),
+            }
+            continue;
+        }
+
         //try to load img
-        let img = load_image(path);
+        //inline images and the summary use the full size image
+        let size_hint = if inline_protocol.is_some() || dry_run {
+            None
+        } else {
+            Some(options_builder.build().target_size)
+        };
+        let img = load_image(path, frame, size_hint);
 
         trace!("Checking if img dimensions are larger than 0");
         //the image-rs lib does not state if images can have a size 0, so check here
         if img.height() == 0 || img.width() == 0 {
-            util::fatal_error("Image dimensions can not be 0", Some(66))
+            fatal_error("Image dimensions can not be 0", Some(66))
         }
 
         if index != 0 && index - 1 != img_paths.len() {
@@ -304,9 +1001,136 @@ fn main() {
             output.push('\n');
         }
 
+        if dry_run {
+            output.push_str(&summary(path, &img, &options_builder.build()));
+            continue;
+        }
+
+        if label {
+            output.push_str(path);
+            output.push('\n');
+        }
+
+        if let Some(protocol) = inline_protocol {
+            info!("Sending img inline: {}", path);
+            match inline::escape_sequence(&img, protocol) {
+                Ok(sequence) => output.push_str(&sequence),
+                Err((msg, code)) => fatal_error(&msg, Some(code)),
+            }
+            continue;
+        }
+
         //convert the img to ascii string
         info!("Converting img: {}", path);
-        output.push_str(artem::convert(img, options_builder.build()).as_str());
+        let options = options_builder.build();
+        if let (true, Some(max_width)) = (no_wrap_warn, max_display_width) {
+            let border_columns = if options.border { 2 } else { 0 };
+            let width = artem::output_size(&img, &options).0 + border_columns;
+            if width > max_width {
+                warn!("The output of {path} is {width} columns wide, it will be wrapped by viewers with a width of {max_width}");
+            }
+        }
+
+        if dump_codepoints {
+            //use plain text, so no color sequences are included
+            let options = options.to_builder().target(TargetType::File).build();
+            output.push_str(&codepoints(&artem::convert(img, options)));
+            continue;
+        }
+
+        if options.sauce.is_some() {
+            let border_columns = if options.border { 2 } else { 0 };
+            art_columns = art_columns.max(artem::output_size(&img, &options).0 + border_columns);
+        }
+
+        if dump_luminance_path.is_some() {
+            let (columns, rows, grid) = artem::luminance_grid(img.clone(), &options);
+            luminance_output.extend(pgm(columns, rows, &grid));
+        }
+
+        if color_map_path.is_some() {
+            if index != 0 {
+                color_map_output.push('\n');
+            }
+            let (chars, colors) = artem::convert_layers(img.clone(), &options);
+            color_map_output.push_str(&color_map(&chars, &colors));
+        }
+
+        if both_themes {
+            //convert the image a second time with the inverted density,
+            //so one of the versions will look right on light and dark terminals
+            let inverted_options = options.to_builder().invert(!options.invert).build();
+            output.push_str(&convert(img.clone(), options));
+            output.push_str("\n--- inverted ---\n");
+            output.push_str(&convert(img, inverted_options));
+        } else {
+            output.push_str(&convert(img, options));
+        }
+    }
+
+    if dry_run {
+        //only print the summary, without writing the output file
+        print!("{output}");
+        return;
+    }
+
+    if let Some(path) = dump_luminance_path {
+        info!("Writing luminance grid to {path}");
+        let mut file = match File::create(path) {
+            Ok(f) => f,
+            Err(_) => fatal_error("Could not create luminance file", Some(73)),
+        };
+        if file.write_all(&luminance_output).is_err() {
+            fatal_error("Could not write to luminance file", Some(74));
+        }
+    }
+
+    if let Some(path) = color_map_path {
+        info!("Writing color map to {path}");
+        let mut file = match File::create(path) {
+            Ok(f) => f,
+            Err(_) => fatal_error("Could not create color map file", Some(73)),
+        };
+        if file.write_all(color_map_output.as_bytes()).is_err() {
+            fatal_error("Could not write to color map file", Some(74));
+        }
+    }
+
+    #[cfg(feature = "archive")]
+    if archive {
+        let path = matches.value_of("output-file").unwrap();
+        info!("Writing frames to zip archive");
+        let file = match File::create(path) {
+            Ok(f) => f,
+            Err(_) => fatal_error("Could not create output file", Some(73)),
+        };
+        let extension = archive::entry_extension(options_builder.build().target);
+        if archive::write_zip(file, &frames, extension).is_err() {
+            fatal_error("Could not write to output file", Some(74));
+        }
+        println!("Written {} frames to {path}", frames.len());
+        return;
+    }
+
+    if animate {
+        trace!("Creating animation script");
+        output = animate::script(&frames);
+    } else if print_frames {
+        trace!("Joining frames");
+        output = match frame_delimiter {
+            //the delimiter is placed on its own line between the frames
+            Some(delimiter) => frames
+                .iter()
+                .map(|(frame, _)| frame.as_str())
+                .collect::<Vec<_>>()
+                .join(&format!("\n{delimiter}\n")),
+            //by default the terminal is cleared before each frame, so they can be played back
+            None => frames
+                .iter()
+                .map(|(frame, _)| format!("\x1b[2J\x1b[H{frame}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
     }
 
     //create and write to output file
@@ -314,11 +1138,33 @@ fn main() {
         info!("Writing output to output file");
         let mut file = match File::create(matches.value_of("output-file").unwrap()) {
             Ok(f) => f,
-            Err(_) => util::fatal_error("Could not create output file", Some(73)),
+            Err(_) => fatal_error("Could not create output file", Some(73)),
         };
         trace!("Created output file");
 
-        match file.write(output.as_bytes()) {
+        let encoding = matches.value_of("encoding").unwrap_or("utf8");
+        debug!("Encoding: {encoding}");
+        let output = match encoding {
+            //the byte order mark is encoded as EF BB BF
+            "utf8-bom" => format!("\u{FEFF}{output}"),
+            "ascii" => transliterate(&output),
+            _ => output,
+        };
+
+        let mut output = output.into_bytes();
+        if let Some(sauce) = options_builder.build().sauce {
+            let rows = output.iter().filter(|byte| **byte == b'\n').count() + 1;
+            let record = sauce.record(
+                output.len() as u32,
+                art_columns.min(u16::MAX as u32) as u16,
+                rows.min(u16::MAX as usize) as u16,
+            );
+            //the record is separated from the art by the end of file character
+            output.push(0x1A);
+            output.extend_from_slice(&record);
+        }
+
+        match file.write(&output) {
             Ok(result) => {
                 info!("Written ascii chars to output file");
                 println!(
@@ -326,26 +1172,115 @@ fn main() {
                     matches.value_of("output-file").unwrap()
                 )
             }
-            Err(_) => util::fatal_error("Could not write to output file", Some(74)),
+            Err(_) => fatal_error("Could not write to output file", Some(74)),
         };
+    } else if clipboard {
+        info!("Copying output to the clipboard");
+        #[cfg(feature = "clipboard")]
+        match copy_to_clipboard(output) {
+            Ok(_) => println!("Copied the ascii art to the clipboard"),
+            Err(err) => fatal_error(&format!("Could not copy to the clipboard: {err}"), Some(69)),
+        }
     } else {
         //print the ascii img to the terminal
         info!("Printing output");
-        println!("{output}");
+        if output.ends_with('\n') {
+            //the bottom spacing already moved the cursor to the last line of the terminal,
+            //another newline would scroll the top of the image out of it
+            print!("{output}");
+        } else {
+            println!("{output}");
+        }
+    }
+}
+
+///Function for fatal errors.
+///
+///A fatal error is an error, from which the program can no recover, meaning the only option left ist to print
+/// an error message letting the user know what went wrong. For example if a non-existing file was passed in,
+/// this program can not work correctly and should print an error message and exit.
+///
+/// This function will print the passed in error message as well as a exit message, then it will exit the program with the exit code.
+/// If non is specified, it will use exit code 1 by default.
+/// A list of exit code can be found here: <https://www.freebsd.org/cgi/man.cgi?query=sysexits&apropos=0&sektion=0&manpath=FreeBSD+4.3-RELEASE&format=html>
+///
+/// # Examples
+/// ```no_run
+/// use std::fs::File;
+///
+/// let f = File::open("hello.txt");
+/// let f = match f {
+///     Ok(file) => file,
+///     Err(error) => fatal_error(error.to_string().as_str(), Some(66)),
+/// };
+/// ```
+fn fatal_error(message: &str, code: Option<i32>) -> ! {
+    //This function never returns, since it always exit the program
+    error!("{}", message);
+    error!("Artem exited with code: {}", code.unwrap_or(1));
+    process::exit(code.unwrap_or(1));
+}
+
+/// Copy the text to the system clipboard.
+///
+/// Returns the reason as an error, if the clipboard can not be used, for example on headless systems without a display server.
+///
+/// # Examples
+/// ```
+/// copy_to_clipboard("ascii".to_string()).unwrap();
+/// ```
+#[cfg(feature = "clipboard")]
+fn copy_to_clipboard(text: String) -> Result<(), String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(|err| err.to_string())?;
+    clipboard.set_text(text).map_err(|err| err.to_string())
+}
+
+/// Return the width and the height of the terminal.
+///
+/// If STDOUT is not a tty, for example when piping the output, the `COLUMNS` and `LINES` environment variables are used instead,
+/// since they are still set by many shells and CI environments. A dimension is `None`, if it can not be determined.
+///
+/// # Examples
+/// ```
+/// let (width, height) = terminal_dimensions();
+/// ```
+fn terminal_dimensions() -> (Option<u32>, Option<u32>) {
+    match terminal_size::terminal_size() {
+        Some((width, height)) => (Some(width.0 as u32), Some(height.0 as u32)),
+        None => (
+            std::env::var("COLUMNS").ok().and_then(parse_dimension),
+            std::env::var("LINES").ok().and_then(parse_dimension),
+        ),
     }
 }
 
+/// Parse a terminal dimension from an environment variable.
+///
+/// Only positive numbers are valid dimensions.
+///
+/// # Examples
+/// ```
+/// assert_eq!(Some(80), parse_dimension("80".to_string()));
+/// ```
+fn parse_dimension(value: String) -> Option<u32> {
+    value.trim().parse::<u32>().ok().filter(|value| *value > 0)
+}
+
 /// Return the image from the specified path.
 ///
 /// Loads the image from the specified path.
 /// If the path is a url and the web_image feature is enabled,
 /// the image will be downloaded and opened from memory.
+/// If the path is `-`, the image is read from stdin.
+/// For animated images, the `frame` selects the frame which is returned.
+/// If a `size_hint` is given, huge jpeg images are already downscaled while decoding,
+/// see [`decode_scaled`].
 ///
 /// # Examples
 /// ```
-/// let image = load_image("../examples/abraham_lincoln.jpg")
+/// let image = load_image("../examples/abraham_lincoln.jpg", 0, Some(80))
 /// ```
-fn load_image(path: &str) -> image::DynamicImage {
+fn load_image(path: &str, frame: usize, size_hint: Option<u32>) -> image::DynamicImage {
     #[cfg(feature = "web_image")]
     {
         if path.starts_with("http") {
@@ -357,7 +1292,7 @@ fn load_image(path: &str) -> image::DynamicImage {
                 //get bytes of the image
                 let bytes = match resp {
                     Ok(value) => value.into_bytes(),
-                    Err(_) => util::fatal_error(
+                    Err(_) => fatal_error(
                         format!("Failed to parse image bytes from {path}").as_str(),
                         Some(66),
                     ),
@@ -365,17 +1300,535 @@ fn load_image(path: &str) -> image::DynamicImage {
                 info!("Downloading took {:3} ms", now.elapsed().as_millis());
 
                 debug!("Opening downloaded image from memory");
-                return match image::load_from_memory(&bytes) {
-                    Ok(img) => img,
-                    Err(err) => util::fatal_error(err.to_string().as_str(), Some(66)),
-                };
+                return load_bytes(bytes, frame, size_hint);
             }
         }
     }
 
+    if path == "-" {
+        let bytes = read_stdin();
+        debug!("Opening image from memory");
+        return load_bytes(bytes, frame, size_hint);
+    }
+
+    if frame != 0 {
+        let bytes = match std::fs::read(path) {
+            Ok(value) => value,
+            Err(err) => fatal_error(err.to_string().as_str(), Some(66)),
+        };
+        return load_frame(&bytes, frame);
+    }
+
     info!("Opening image");
-    match image::open(path) {
+    match image::io::Reader::open(path)
+        .map_err(image::ImageError::from)
+        .and_then(|reader| decode_scaled(reader, size_hint))
+    {
+        Ok(img) => img,
+        Err(err) => fatal_error(err.to_string().as_str(), Some(66)),
+    }
+}
+
+/// Return all bytes given on stdin.
+///
+/// Stdin can only be read once, so the bytes have to be decoded from memory.
+/// Exits the program, if stdin could not be read or is empty.
+fn read_stdin() -> Vec<u8> {
+    info!("Reading image from stdin");
+    let mut bytes = Vec::new();
+    if let Err(err) = std::io::stdin().lock().read_to_end(&mut bytes) {
+        fatal_error(
+            format!("Failed to read image from stdin: {err}").as_str(),
+            Some(66),
+        );
+    }
+    if bytes.is_empty() {
+        fatal_error("No image data was given on stdin", Some(66));
+    }
+    bytes
+}
+
+/// Return the image from the bytes of an encoded image.
+///
+/// The format is guessed from the bytes, otherwise this behaves like [`load_image`].
+///
+/// # Examples
+/// ```
+/// let image = load_bytes(std::fs::read("../examples/abraham_lincoln.jpg")?, 0, Some(80))
+/// ```
+fn load_bytes(bytes: Vec<u8>, frame: usize, size_hint: Option<u32>) -> image::DynamicImage {
+    if frame != 0 {
+        return load_frame(&bytes, frame);
+    }
+
+    let reader = image::io::Reader::new(std::io::Cursor::new(bytes)).with_guessed_format();
+    match reader
+        .map_err(image::ImageError::from)
+        .and_then(|reader| decode_scaled(reader, size_hint))
+    {
+        Ok(img) => img,
+        Err(err) => fatal_error(err.to_string().as_str(), Some(66)),
+    }
+}
+
+/// Minimum number of decoded pixels per character along the shorter side of the image.
+///
+/// Downscaling more would reduce the number of pixels, which are averaged for a single character.
+const MIN_CELL_PIXELS: u32 = 16;
+
+/// Decode the image, letting the jpeg decoder downscale huge images.
+///
+/// The jpeg decoder can skip parts of the image data, which reduces the size by a power of two (up to 1/8).
+/// This saves the memory and time of fully decoding a huge image, just to resize it afterwards.
+/// The image is only downscaled, as long as it keeps [`MIN_CELL_PIXELS`] per character of the `size_hint`,
+/// other formats are always fully decoded.
+///
+/// # Examples
+/// ```
+/// let image = decode_scaled(image::io::Reader::open("../examples/abraham_lincoln.jpg")?, Some(80))?;
+/// ```
+fn decode_scaled<R: std::io::BufRead + std::io::Seek>(
+    reader: image::io::Reader<R>,
+    size_hint: Option<u32>,
+) -> image::ImageResult<image::DynamicImage> {
+    let size_hint = match (reader.format(), size_hint) {
+        (Some(image::ImageFormat::Jpeg), Some(size_hint)) => size_hint,
+        _ => return reader.decode(),
+    };
+
+    let mut decoder = image::codecs::jpeg::JpegDecoder::new(reader.into_inner())?;
+    let (width, height) = image::ImageDecoder::dimensions(&decoder);
+    if let Some((requested_width, requested_height)) = decode_size(width, height, size_hint) {
+        let (scaled_width, scaled_height) = decoder.scale(requested_width, requested_height)?;
+        debug!("Decoding {width}x{height} jpeg as {scaled_width}x{scaled_height}");
+    }
+    image::DynamicImage::from_decoder(decoder)
+}
+
+/// Return the smallest size the image can be decoded with, while keeping enough pixels for the target size.
+///
+/// The aspect ratio is kept, so the requested width and height are reduced by the same factor.
+/// If the image is already too small to be reduced, `None` is returned.
+///
+/// # Examples
+/// ```
+/// assert_eq!(Some((2560, 1280)), decode_size(8000, 4000, 80));
+/// ```
+fn decode_size(width: u32, height: u32, size_hint: u32) -> Option<(u16, u16)> {
+    let min_side = size_hint.saturating_mul(MIN_CELL_PIXELS);
+    let shorter_side = width.min(height);
+    if shorter_side <= min_side {
+        return None;
+    }
+
+    //round up, so the decoded image is never smaller than needed
+    let scale = |side: u32| {
+        let scaled =
+            (side as u64 * min_side as u64 + shorter_side as u64 - 1) / shorter_side as u64;
+        scaled.min(u16::MAX as u64) as u16
+    };
+    Some((scale(width), scale(height)))
+}
+
+/// Return the frame with the given index of the animated image.
+///
+/// If the frame does not exist, the program will exit with an error.
+///
+/// # Examples
+/// ```
+/// let image = load_frame(&bytes, 1)
+/// ```
+fn load_frame(bytes: &[u8], frame: usize) -> image::DynamicImage {
+    info!("Opening frame {frame} of image");
+    match animate::select_frame(bytes, frame) {
         Ok(img) => img,
-        Err(err) => util::fatal_error(err.to_string().as_str(), Some(66)),
+        Err((msg, code)) => fatal_error(&msg, Some(code)),
+    }
+}
+
+/// Return the characters from the first line of the specified file.
+///
+/// The line is used exactly as it is, only the line break at the end is removed,
+/// so characters like backslashes do not need to be escaped.
+/// If the file can not be read or the first line is empty, the program will exit with an error.
+///
+/// # Examples
+/// ```
+/// let characters = load_characters_file("ramp.txt")
+/// ```
+fn load_characters_file(path: &str) -> String {
+    info!("Reading characters from file: {}", path);
+    let content = match std::fs::read_to_string(path) {
+        Ok(value) => value,
+        Err(err) => fatal_error(
+            format!("Could not read characters file {path}: {err}").as_str(),
+            Some(66),
+        ),
+    };
+
+    //only the first line is used, lines() already removes the line break
+    match content.lines().next() {
+        Some(characters) if !characters.is_empty() => characters.to_string(),
+        _ => fatal_error("Characters cannot be empty", Some(64)),
     }
 }
+
+/// Return a summary of the settings, which are used for converting the image.
+///
+/// It contains the input path, the target size, the target type, the used colors and the size of the converted image.
+fn summary(path: &str, img: &image::DynamicImage, options: &artem::options::Option) -> String {
+    let colors = match options.target {
+        TargetType::Shell(true, _) | TargetType::AnsiFile(_)
+            if !colored::control::SHOULD_COLORIZE.should_colorize() =>
+        {
+            "none"
+        }
+        TargetType::Shell(true, _) | TargetType::AnsiFile(_) => match options.color_mode {
+            ColorMode::Grayscale256 => "256 color grayscale",
+            ColorMode::Auto if util::supports_truecolor() => "truecolor",
+            ColorMode::Auto => "16 colors",
+        },
+        TargetType::HtmlFile(true, _) | TargetType::BBCode => "truecolor",
+        _ => "none",
+    };
+    let (columns, rows) = artem::output_size(img, options);
+
+    format!(
+        "Input: {path}\nSize: {}\nTarget: {:?}\nColors: {colors}\nColumns: {columns}\nRows: {rows}\n",
+        options.target_size, options.target
+    )
+}
+
+/// Return the colors of the non-blank characters, one per line.
+///
+/// Every line contains the row and the column of the character, followed by its color as hex.
+/// The `chars` and `colors` are the aligned layers of [`artem::convert_layers`].
+///
+/// # Examples
+/// ```
+/// assert_eq!("0 1 #FF0000\n", color_map(&[" x".to_string()], &[vec![[0, 0, 0], [255, 0, 0]]]))
+/// ```
+fn color_map(chars: &[String], colors: &[Vec<[u8; 3]>]) -> String {
+    let mut output = String::new();
+    for (row, (row_chars, row_colors)) in chars.iter().zip(colors).enumerate() {
+        for (column, (char, color)) in row_chars.chars().zip(row_colors).enumerate() {
+            if !char.is_whitespace() {
+                output.push_str(&format!(
+                    "{row} {column} #{:02X}{:02X}{:02X}\n",
+                    color[0], color[1], color[2]
+                ));
+            }
+        }
+    }
+    output
+}
+
+/// Return a binary pgm image with a gray pixel for every value of the grid.
+///
+/// The values are given row by row, with the maximum value 255 being white.
+///
+/// # Examples
+/// ```
+/// assert_eq!(b"P5\n2 1\n255\n\x00\xFF".to_vec(), pgm(2, 1, &[0, 255]));
+/// ```
+fn pgm(columns: u32, rows: u32, grid: &[u8]) -> Vec<u8> {
+    let mut output = format!("P5\n{columns} {rows}\n255\n").into_bytes();
+    output.extend_from_slice(grid);
+    output
+}
+
+#[cfg(test)]
+mod test_pgm {
+    use super::*;
+
+    #[test]
+    fn header_and_pixels() {
+        assert_eq!(
+            b"P5\n3 2\n255\n\x01\x02\x03\x04\x05\x06".to_vec(),
+            pgm(3, 2, &[1, 2, 3, 4, 5, 6])
+        );
+    }
+}
+
+/// Return the date in the SAUCE format `CCYYMMDD`.
+///
+/// The date is given as the number of days since the unix epoch (1970-01-01).
+///
+/// # Examples
+/// ```
+/// assert_eq!("19700102", sauce_date(1));
+/// ```
+fn sauce_date(days: u64) -> String {
+    //convert the days to the civil date, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    //the year starts in march, so january and february belong to the next year
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{year:04}{month:02}{day:02}")
+}
+
+/// Return the distinct characters of the ascii image with their unicode codepoints.
+///
+/// Every character is listed on its own line, in the order of their first appearance. Line breaks are ignored.
+///
+/// # Examples
+/// ```
+/// assert_eq!("U+0041 'A'\n", codepoints("AA\nA"))
+/// ```
+fn codepoints(ascii: &str) -> String {
+    let mut seen = Vec::new();
+    ascii
+        .chars()
+        .filter(|char| {
+            *char != '\n' && !seen.contains(char) && {
+                seen.push(*char);
+                true
+            }
+        })
+        .map(|char| format!("U+{:04X} '{char}'\n", char as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_codepoints {
+    use super::*;
+
+    #[test]
+    fn space_is_reported() {
+        assert_eq!("U+0020 ' '\n", codepoints(" "));
+    }
+
+    #[test]
+    fn distinct_in_order() {
+        assert_eq!(
+            "U+004D 'M'\nU+0020 ' '\nU+002E '.'\n",
+            codepoints("M M\n.M ")
+        );
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        assert_eq!("U+00D1 'Ñ'\nU+2588 '█'\n", codepoints("Ñ█"));
+    }
+}
+
+/// Replace the non-ascii characters with similar ascii characters.
+///
+/// The box-drawing characters of the border and the shaded blocks are replaced by characters with a similar shape or density,
+/// every other non-ascii character is replaced by a `?`.
+///
+/// # Examples
+/// ```
+/// assert_eq!("+-+\n|#|", transliterate("╔═╗\n║█║"))
+/// ```
+fn transliterate(text: &str) -> String {
+    text.chars()
+        .map(|char| match char {
+            _ if char.is_ascii() => char,
+            '╔' | '╗' | '╚' | '╝' | '┌' | '┐' | '└' | '┘' | '┼' => '+',
+            '═' | '─' => '-',
+            '║' | '│' => '|',
+            '█' | '▓' => '#',
+            '▒' => '+',
+            '░' => '.',
+            _ => '?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_transliterate {
+    use super::*;
+
+    #[test]
+    fn ascii_unchanged() {
+        assert_eq!("MW#. ", transliterate("MW#. "));
+    }
+
+    #[test]
+    fn border_replaced() {
+        assert_eq!("+--+\n|  |\n+--+", transliterate("╔══╗\n║  ║\n╚══╝"));
+    }
+
+    #[test]
+    fn unknown_replaced() {
+        assert_eq!("?#", transliterate("Ñ█"));
+    }
+}
+
+#[cfg(test)]
+mod test_decode_size {
+    use super::*;
+
+    #[test]
+    fn small_image_unchanged() {
+        assert_eq!(None, decode_size(1000, 800, 80));
+    }
+
+    #[test]
+    fn huge_image_reduced() {
+        assert_eq!(Some((2560, 1280)), decode_size(8000, 4000, 80));
+    }
+
+    #[test]
+    fn rounds_up() {
+        assert_eq!(Some((1281, 1280)), decode_size(4001, 4000, 80));
+    }
+
+    #[test]
+    fn jpeg_is_decoded_smaller() {
+        let path = "assets/images/abraham_lincoln.jpg";
+        let full = image::open(path).unwrap();
+        let reader = image::io::Reader::open(path).unwrap();
+        let scaled = decode_scaled(reader, Some(20)).unwrap();
+
+        //the decoder only reduces the size by powers of two
+        assert!(scaled.width() < full.width());
+        assert!(scaled.width().min(scaled.height()) >= 20 * MIN_CELL_PIXELS);
+        assert_eq!(
+            full.width() / scaled.width(),
+            full.height() / scaled.height()
+        );
+    }
+
+    #[test]
+    fn png_is_decoded_fully() {
+        let path = "assets/images/standard_test_img.png";
+        let reader = image::io::Reader::open(path).unwrap();
+        assert_eq!(
+            image::open(path).unwrap(),
+            decode_scaled(reader, Some(20)).unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_color_map {
+    use super::*;
+
+    #[test]
+    fn blank_chars_are_skipped() {
+        assert_eq!(
+            "0 1 #FF0000\n",
+            color_map(&[" x".to_string()], &[vec![[0, 0, 0], [255, 0, 0]]])
+        );
+    }
+
+    #[test]
+    fn one_entry_per_char() {
+        let chars = vec!["ab".to_string(), " c".to_string()];
+        let colors = vec![vec![[1, 2, 3], [4, 5, 6]], vec![[0, 0, 0], [170, 187, 204]]];
+        assert_eq!(
+            "0 0 #010203\n0 1 #040506\n1 1 #AABBCC\n",
+            color_map(&chars, &colors)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_parse_dimension {
+    use super::*;
+
+    #[test]
+    fn number() {
+        assert_eq!(Some(120), parse_dimension(" 120\n".to_string()));
+    }
+
+    #[test]
+    fn zero_is_invalid() {
+        assert_eq!(None, parse_dimension("0".to_string()));
+    }
+
+    #[test]
+    fn text_is_invalid() {
+        assert_eq!(None, parse_dimension("wide".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod test_sauce_date {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!("19700101", sauce_date(0));
+    }
+
+    #[test]
+    fn new_year() {
+        assert_eq!("20240101", sauce_date(19723));
+    }
+
+    #[test]
+    fn leap_day() {
+        assert_eq!("20240229", sauce_date(19782));
+    }
+}
+
+///Merges the options of a config file in the format of the library with the options of the command line.
+type MergeOptions = Box<dyn Fn(&OptionBuilder, &clap::ArgMatches) -> OptionBuilder>;
+
+///Merge the values of the config file into the given matches.
+///
+/// Returns the merged matches, the options of a config file in the format of the library
+/// and the error message and exit code, if the config could not be used.
+#[cfg(feature = "config")]
+fn load_config(
+    matches: clap::ArgMatches,
+) -> (
+    clap::ArgMatches,
+    Option<MergeOptions>,
+    Option<(String, i32)>,
+) {
+    match matches.value_of("config").map(str::to_string) {
+        Some(path) => match config::merge_config_file(
+            &path,
+            cli::build_cli(),
+            &matches,
+            std::env::args_os().collect(),
+        ) {
+            Ok((merged, file_options)) => (
+                merged,
+                file_options.map(|file_options| {
+                    Box::new(
+                        move |options_builder: &OptionBuilder, matches: &clap::ArgMatches| {
+                            file_options.merge(options_builder, matches)
+                        },
+                    ) as MergeOptions
+                }),
+                None,
+            ),
+            Err(err) => (matches, None, Some(err)),
+        },
+        None => (matches, None, None),
+    }
+}
+
+///Reject config files, as reading them requires the config feature.
+#[cfg(not(feature = "config"))]
+fn load_config(
+    matches: clap::ArgMatches,
+) -> (
+    clap::ArgMatches,
+    Option<MergeOptions>,
+    Option<(String, i32)>,
+) {
+    let error = matches.is_present("config").then(|| {
+        (
+            "Reading config files requires the config feature".to_string(),
+            64,
+        )
+    });
+    (matches, None, error)
+}
@@ -1,6 +1,11 @@
 use std::num::NonZeroU32;
 
-use crate::util::{self, ResizingDimension};
+use image::Rgb;
+
+use crate::{
+    pixel,
+    util::{self, HorizontalAnchor, ResizingDimension, VerticalAnchor},
+};
 
 /// Target for the Ascii conversion.
 ///
@@ -17,6 +22,7 @@ use crate::util::{self, ResizingDimension};
 ///
 ///```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TargetType {
     /// Shell target, Supports color and background colors.
     Shell(bool, bool),
@@ -24,6 +30,12 @@ pub enum TargetType {
     AnsiFile(bool),
     /// Shell target, Supports color and background colors.
     HtmlFile(bool, bool),
+    /// BBCode markup for forums, which will always have colors enabled. Does not support background colors.
+    BBCode,
+    /// Plain text file, where every row is run-length encoded. Does not support colored outputs.
+    Rle,
+    /// JSON lines, where every row is a JSON object with the chars and colors of its cells.
+    JsonLines,
     /// Every other file, does not support either colored outputs.
     File,
 }
@@ -55,427 +67,5919 @@ mod tests {
     }
 }
 
-///Configuration for the conversion of the image to the ascii image.
-#[derive(Debug, PartialEq)]
-pub struct Option {
-    pub characters: String,
-    pub scale: f32,
-    pub target_size: u32,
-    pub invert: bool,
-    pub border: bool,
-    pub dimension: ResizingDimension,
-    pub transform_x: bool,
-    pub transform_y: bool,
-    pub center_x: bool,
-    pub center_y: bool,
-    pub outline: bool,
-    pub hysteresis: bool,
-    pub target: TargetType,
+/// Color mode for targets, which use ANSI escape sequences.
+///
+/// This changes how the color of a character is represented in the shell or in `.ans`/`.ansi` files.
+///
+/// # Examples
+///```
+/// use artem::options::ColorMode;
+///
+/// assert_eq!(ColorMode::Auto, ColorMode::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorMode {
+    /// Use truecolor if it is supported, otherwise fall back to the 16 ANSI colors.
+    Auto,
+    /// Use the 24 step grayscale ramp of the 256 color palette, based on the luminosity.
+    Grayscale256,
 }
 
-impl Option {
-    /// Create [`OptionBuilder`] with default properties.
+impl Default for ColorMode {
+    /// Default [`ColorMode`]
+    ///
+    /// By default the colors are used, as they are supported by the terminal.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::Option;
+    /// use artem::options::ColorMode;
     ///
-    /// let default = Option::builder();
+    /// assert_eq!(ColorMode::Auto, ColorMode::default());
     /// ```
-    pub fn builder() -> OptionBuilder {
-        OptionBuilder::default()
+    fn default() -> ColorMode {
+        ColorMode::Auto
     }
 }
 
 #[cfg(test)]
-mod test_option {
+mod test_color_mode {
     use super::*;
+
     #[test]
-    fn builder_default() {
-        assert_eq!(
-            OptionBuilder {
-                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
-                scale: 0.42f32,
-                target_size: 80,
-                invert: false,
-                border: false,
-                dimension: util::ResizingDimension::Width,
-                transform_x: false,
-                transform_y: false,
-                center_x: false,
-                center_y: false,
-                outline: false,
-                hysteresis: false,
-                target: TargetType::default(),
-            },
-            Option::builder()
-        );
+    fn default() {
+        assert_eq!(ColorMode::Auto, ColorMode::default());
     }
 }
 
-///A builder to create a [`Option`] struct.
-#[derive(PartialEq, Debug)]
-pub struct OptionBuilder {
-    characters: String,
-    scale: f32,
-    target_size: u32,
-    invert: bool,
-    border: bool,
-    dimension: ResizingDimension,
-    transform_x: bool,
-    transform_y: bool,
-    center_x: bool,
-    center_y: bool,
-    outline: bool,
-    hysteresis: bool,
-    target: TargetType,
+/// Rounding of the density index, which selects the character of a cell.
+///
+/// # Examples
+///```
+/// use artem::options::IndexRounding;
+///
+/// assert_eq!(IndexRounding::Floor, IndexRounding::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexRounding {
+    /// Round down, which slightly favors the end of the characters.
+    Floor,
+    /// Round to the nearest index, halfway values are rounded away from zero.
+    Round,
+    /// Round to the nearest index, halfway values are rounded to the even index.
+    Nearest,
 }
 
-impl Default for OptionBuilder {
-    fn default() -> Self {
-        Self {
-            //these have to be set to custom defaults for the program to work
-            characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
-            scale: 0.42f32,
-            target_size: 80,
-            invert: Default::default(),
-            border: Default::default(),
-            dimension: Default::default(),
-            transform_x: Default::default(),
-            transform_y: Default::default(),
-            center_x: Default::default(),
-            center_y: Default::default(),
-            outline: Default::default(),
-            hysteresis: Default::default(),
-            target: Default::default(),
-        }
+impl Default for IndexRounding {
+    /// Default [`IndexRounding`]
+    ///
+    /// By default the index is rounded down.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::IndexRounding;
+    ///
+    /// assert_eq!(IndexRounding::Floor, IndexRounding::default());
+    /// ```
+    fn default() -> IndexRounding {
+        IndexRounding::Floor
     }
 }
 
-/// Generate a builder property.
+#[cfg(test)]
+mod test_index_rounding {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(IndexRounding::Floor, IndexRounding::default());
+    }
+}
+
+/// Preferred color, when a color is equally close to two of the 16 basic ansi colors.
 ///
-/// This macro generates a function, which sets the specified property of the Builder.
-/// It does NOT check the value passed in, so for example it would be possible to pass in an empty string, which could lead to
-/// errors later this. This should be done before setting the value.
+/// This is only used, if the terminal does not support truecolor. Ties between two colors of the same kind
+/// are always won by the color, which comes first in the ansi color order.
 ///
 /// # Examples
+///```
+/// use artem::options::AnsiTieBreak;
 ///
-/// ``` compile_fail, just an internal example code
-/// property!{
-/// ///Example doc
-/// ///This generates a name setter function.
-/// => name, String
-/// }
-/// ```
-/// ## Generated function
-/// ``` compile_fail, just an internal example code
-/// ///Example doc
-/// ///This generates a name setter function.
-/// pub fn name<'a>(&'a mut self, name: String) -> &'a Self {
-///     self.name = name;
-///     self
-/// }
-/// ```
-macro_rules! property {
-    ($(#[$attr:meta])* => $field:ident, $field_type:ty) => {
-        $(#[$attr])*
-        pub fn $field<'a>(&'a mut self, $field: $field_type) -> &'a mut Self {
-            self.$field = $field;
-            self
-        }
-    };
-    ($(#[$attr:meta])* => $field:ident, $field_type:ty, $func:ident) => {
-        $(#[$attr])*
-        pub fn $field<'a>(&'a mut self, $field: $field_type) -> &'a mut Self {
-            self.$field = $field.$func();
-            self
-        }
-    };
+/// assert_eq!(AnsiTieBreak::Standard, AnsiTieBreak::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnsiTieBreak {
+    /// Prefer the standard colors (30-37) over the bright colors (90-97).
+    Standard,
+    /// Prefer the bright colors (90-97) over the standard colors (30-37).
+    Bright,
 }
 
-// impl Default  {
-
-// }
-
-impl OptionBuilder {
-    ///Create a new OptionBuilder.
+impl Default for AnsiTieBreak {
+    /// Default [`AnsiTieBreak`]
     ///
-    /// If an option is not specified, the rust default value will be used, unless specified otherwise.
+    /// By default the standard colors are preferred.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::options::AnsiTieBreak;
     ///
-    /// let mut builder = OptionBuilder::new();
+    /// assert_eq!(AnsiTieBreak::Standard, AnsiTieBreak::default());
     /// ```
-    pub fn new() -> OptionBuilder {
-        Option::builder()
+    fn default() -> AnsiTieBreak {
+        AnsiTieBreak::Standard
     }
+}
 
-    ///Set the characters.
-    ///
-    /// The characters will determine how 'visible'/light/dark a character will be perceived.
+#[cfg(test)]
+mod test_ansi_tie_break {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(AnsiTieBreak::Standard, AnsiTieBreak::default());
+    }
+}
+
+/// Distance between two colors, which is used to snap them to a palette or to rounded colors.
+///
+/// # Examples
+///```
+/// use artem::options::ColorDistance;
+///
+/// assert_eq!(ColorDistance::Euclidean, ColorDistance::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorDistance {
+    /// Euclidean distance between the rgb values.
+    Euclidean,
+    /// Euclidean distance between the rgb values, weighted by how strong the eye perceives each channel (0.3, 0.59 and 0.11).
+    Weighted,
+    /// Euclidean distance in the CIELAB color space, which is close to the perceived difference.
+    Lab,
+}
+
+impl Default for ColorDistance {
+    /// Default [`ColorDistance`]
     ///
-    /// # Errors
-    /// When the given characters are empty, the characters will not be changed.
+    /// By default the euclidean distance is used.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::options::ColorDistance;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.characters("Mkl. ".to_string());
+    /// assert_eq!(ColorDistance::Euclidean, ColorDistance::default());
     /// ```
-    #[allow(clippy::needless_lifetimes)] //disable this, as the life is needed for the builder
-    pub fn characters<'a>(&'a mut self, characters: String) -> &'a Self {
-        if !characters.is_empty() {
-            self.characters = characters;
-        }
-        self
+    fn default() -> ColorDistance {
+        ColorDistance::Euclidean
     }
+}
 
-    property! {
-    /// Set the scale.
+#[cfg(test)]
+mod test_color_distance {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(ColorDistance::Euclidean, ColorDistance::default());
+    }
+}
+
+/// Handling of the pixels at the right and bottom edge, which do not fill a whole tile.
+///
+/// # Examples
+///```
+/// use artem::options::EdgeMode;
+///
+/// assert_eq!(EdgeMode::Crop, EdgeMode::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeMode {
+    /// Resize the image to whole tiles, so the pixels of a partial tile are squeezed into the last full tiles.
+    Crop,
+    /// Keep the full image and pad the last partial tiles with the pixels at the edge.
+    Pad,
+}
+
+impl Default for EdgeMode {
+    /// Default [`EdgeMode`]
     ///
-    /// Used to change the ratio between width and height of an character.
-    /// Since a char is a bit higher than wide, the scale should compensate for that.
+    /// By default the image is resized to whole tiles.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::options::EdgeMode;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.scale(0.42f32);
+    /// assert_eq!(EdgeMode::Crop, EdgeMode::default());
     /// ```
-       => scale, f32
+    fn default() -> EdgeMode {
+        EdgeMode::Crop
     }
-    // pub fn scale(&'a mut self, scale: f32) -> &'a mut Self {
-    //     self.scale = scale;
-    //     self
-    // }
+}
 
-    property! {
-    /// Set the target_size.
+#[cfg(test)]
+mod test_edge_mode {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(EdgeMode::Crop, EdgeMode::default());
+    }
+}
+
+/// Format of the colors in html files.
+///
+/// # Examples
+///```
+/// use artem::options::HtmlColorFormat;
+///
+/// assert_eq!(HtmlColorFormat::Hex, HtmlColorFormat::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HtmlColorFormat {
+    /// Hexadecimal rgb colors, for example `#FF0000`.
+    Hex,
+    /// Hue, saturation and lightness, for example `hsl(0, 100%, 50%)`, which can be themed using css filters.
+    Hsl,
+}
+
+impl Default for HtmlColorFormat {
+    /// Default [`HtmlColorFormat`]
     ///
-    /// This is the number of characters that the resulting ascii image will be heigh/wide.
+    /// By default the hexadecimal format is used.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
-    /// use core::num::NonZeroU32;
+    /// use artem::options::HtmlColorFormat;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.target_size(NonZeroU32::new(80).unwrap());
+    /// assert_eq!(HtmlColorFormat::Hex, HtmlColorFormat::default());
     /// ```
-    => target_size, NonZeroU32, get
+    fn default() -> HtmlColorFormat {
+        HtmlColorFormat::Hex
     }
+}
 
-    property! {
-    ///Invert to density map/characters.
+#[cfg(test)]
+mod test_html_color_format {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(HtmlColorFormat::Hex, HtmlColorFormat::default());
+    }
+}
+
+/// Value of a cell, which selects its character.
+///
+/// The color of the character is not changed by this, it always uses the full rgb color.
+///
+/// # Examples
+///```
+/// use artem::options::DensityChannel;
+///
+/// assert_eq!(DensityChannel::Luminosity, DensityChannel::default());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DensityChannel {
+    /// Use the luminosity of the combined color channels.
+    Luminosity,
+    /// Use only the red channel.
+    Red,
+    /// Use only the green channel.
+    Green,
+    /// Use only the blue channel.
+    Blue,
+    /// Use the opacity, so transparent parts of the image use the same characters as dark parts.
+    Alpha,
+}
+
+impl Default for DensityChannel {
+    /// Default [`DensityChannel`]
     ///
-    /// This inverts the mapping from light to dark characters. It can be useful when
-    /// the image has a dark background. It defaults to false.
+    /// By default the luminosity is used.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::options::DensityChannel;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.invert(true);
+    /// assert_eq!(DensityChannel::Luminosity, DensityChannel::default());
     /// ```
-    => invert, bool
-    // pub fn invert(mut self, invert: bool) -> Self {
-    //     self.invert = invert;
-    //     self
+    fn default() -> DensityChannel {
+        DensityChannel::Luminosity
     }
+}
 
-    property! {
-    ///Enable a border surrounding the image.
+#[cfg(test)]
+mod test_density_channel {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(DensityChannel::Luminosity, DensityChannel::default());
+    }
+}
+
+/// Metadata of a SAUCE record, which is appended to ansi art files.
+///
+/// SAUCE is the metadata format of the ansi art scene, it describes the title, the author and the dimensions of the art.
+/// Text, which does not fit into its field, is cut off, non-ascii characters are replaced by `?`.
+///
+/// # Examples
+///```
+/// use artem::options::SauceInfo;
+///
+/// let info = SauceInfo {
+///     title: "Lincoln".to_string(),
+///     author: "artem".to_string(),
+///     group: String::new(),
+///     date: "20240101".to_string(),
+/// };
+/// let record = info.record(1000, 80, 25);
+/// assert_eq!(b"SAUCE00Lincoln", &record[..14]);
+///```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SauceInfo {
+    /// Title of the art, up to 35 characters.
+    pub title: String,
+    /// Name of the author, up to 20 characters.
+    pub author: String,
+    /// Name of the group of the author, up to 20 characters.
+    pub group: String,
+    /// Creation date in the format `CCYYMMDD`.
+    pub date: String,
+}
+
+impl SauceInfo {
+    /// Return the 128 byte SAUCE record of an ansi file.
     ///
-    /// The border will take reduced the space of the ascii image, since it will still
-    /// use the same target size.
+    /// The `file_size` is the size of the art in bytes, without the record and the end of file character (`0x1A`),
+    /// which has to be written in front of it. The `columns` and `rows` are the dimensions of the art in characters.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::options::SauceInfo;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.border(true);
+    /// let record = SauceInfo::default().record(1000, 80, 25);
+    /// //the width and the height are stored in little endian
+    /// assert_eq!([80, 0, 25, 0], record[96..100]);
     /// ```
-    => border, bool
+    pub fn record(&self, file_size: u32, columns: u16, rows: u16) -> [u8; 128] {
+        let mut record = [0u8; 128];
+        record[..7].copy_from_slice(b"SAUCE00");
+        sauce_field(&mut record[7..42], &self.title);
+        sauce_field(&mut record[42..62], &self.author);
+        sauce_field(&mut record[62..82], &self.group);
+        sauce_field(&mut record[82..90], &self.date);
+        record[90..94].copy_from_slice(&file_size.to_le_bytes());
+        //character data type, with the ansi file type
+        record[94] = 1;
+        record[95] = 1;
+        record[96..98].copy_from_slice(&columns.to_le_bytes());
+        record[98..100].copy_from_slice(&rows.to_le_bytes());
+        //the remaining info fields, the comments, the flags and the font name stay empty
+        record
     }
+}
 
-    property! {
-    /// Set which dimension should be scaled first.
+/// Write the text into the field, padded with spaces.
+fn sauce_field(field: &mut [u8], text: &str) {
+    let mut chars = text.chars();
+    for byte in field.iter_mut() {
+        *byte = match chars.next() {
+            Some(char) if char.is_ascii() => char as u8,
+            Some(_) => b'?',
+            None => b' ',
+        };
+    }
+}
+
+#[cfg(test)]
+mod test_sauce_info {
+    use super::*;
+
+    #[test]
+    fn fields_are_padded() {
+        let info = SauceInfo {
+            title: "Title".to_string(),
+            author: "Author".to_string(),
+            group: "Group".to_string(),
+            date: "20240101".to_string(),
+        };
+        let record = info.record(1000, 80, 25);
+        assert_eq!(128, record.len());
+        assert_eq!(b"SAUCE00", &record[..7]);
+        assert_eq!(format!("{:35}", "Title").as_bytes(), &record[7..42]);
+        assert_eq!(format!("{:20}", "Author").as_bytes(), &record[42..62]);
+        assert_eq!(format!("{:20}", "Group").as_bytes(), &record[62..82]);
+        assert_eq!(b"20240101", &record[82..90]);
+    }
+
+    #[test]
+    fn dimensions() {
+        let record = SauceInfo::default().record(70000, 300, 2);
+        assert_eq!(70000u32.to_le_bytes(), record[90..94]);
+        assert_eq!([1, 1], record[94..96]);
+        assert_eq!([44, 1], record[96..98]);
+        assert_eq!([2, 0], record[98..100]);
+        assert!(record[100..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn long_text_is_cut() {
+        let info = SauceInfo {
+            title: "x".repeat(50),
+            ..Default::default()
+        };
+        let record = info.record(0, 0, 0);
+        assert_eq!(&[b'x'; 35][..], &record[7..42]);
+        assert_eq!(b' ', record[42]);
+    }
+
+    #[test]
+    fn non_ascii_is_replaced() {
+        let info = SauceInfo {
+            author: "Zoë".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(b"Zo? ", &info.record(0, 0, 0)[42..46]);
+    }
+}
+
+/// Weights of the color channels, which are added up to the luminosity of a color.
+///
+/// The luminosity selects the characters, so different weights change which colors are shown as bright or dark.
+///
+/// # Examples
+///```
+/// use artem::options::LuminanceWeights;
+///
+/// assert_eq!(LuminanceWeights::DEFAULT, LuminanceWeights::default());
+/// assert_eq!(0.299, LuminanceWeights::REC_601.red);
+///```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuminanceWeights {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl LuminanceWeights {
+    /// Weights used by artem, from <http://www.johndcook.com/blog/2009/08/24/algorithms-convert-color-grayscale/>.
+    pub const DEFAULT: LuminanceWeights = LuminanceWeights {
+        red: 0.21,
+        green: 0.72,
+        blue: 0.07,
+    };
+
+    /// Weights of the ITU-R BT.601 standard, which are used by many image tools.
+    pub const REC_601: LuminanceWeights = LuminanceWeights {
+        red: 0.299,
+        green: 0.587,
+        blue: 0.114,
+    };
+
+    /// Weights of the ITU-R BT.709 standard, which is used for sRGB and HDTV.
+    pub const REC_709: LuminanceWeights = LuminanceWeights {
+        red: 0.2126,
+        green: 0.7152,
+        blue: 0.0722,
+    };
+}
+
+impl Default for LuminanceWeights {
+    /// Default [`LuminanceWeights`]
     ///
-    /// See [`ResizingDimension`] for more information.
+    /// By default [`LuminanceWeights::DEFAULT`] is used.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
-    /// use artem::util::ResizingDimension;
+    /// use artem::options::LuminanceWeights;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.dimension(ResizingDimension::Height);
+    /// assert_eq!(LuminanceWeights::DEFAULT, LuminanceWeights::default());
     /// ```
-    => dimension, util::ResizingDimension
+    fn default() -> LuminanceWeights {
+        LuminanceWeights::DEFAULT
     }
+}
 
-    property! {
-    ///Flip the image along the X-axis
+#[cfg(test)]
+mod test_luminance_weights {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(LuminanceWeights::DEFAULT, LuminanceWeights::default());
+    }
+
+    #[test]
+    fn presets_add_up_to_one() {
+        for weights in [
+            LuminanceWeights::DEFAULT,
+            LuminanceWeights::REC_601,
+            LuminanceWeights::REC_709,
+        ] {
+            assert!((weights.red + weights.green + weights.blue - 1f32).abs() < 1e-6);
+        }
+    }
+}
+
+///Configuration for the conversion of the image to the ascii image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Option {
+    pub characters: String,
+    pub scale: f32,
+    pub target_size: u32,
+    pub invert: bool,
+    pub border: bool,
+    pub dimension: ResizingDimension,
+    pub transform_x: bool,
+    pub transform_y: bool,
+    pub anchor_x: HorizontalAnchor,
+    pub anchor_y: VerticalAnchor,
+    pub outline: bool,
+    pub hysteresis: bool,
+    pub target: TargetType,
+    pub sharpen: f32,
+    pub color_mode: ColorMode,
+    pub clear_screen: bool,
+    pub backdrop: std::option::Option<[u8; 3]>,
+    pub color_quantize_step: std::option::Option<u8>,
+    pub min_color_luminance: f32,
+    pub smooth_horizontal: bool,
+    pub palette: std::option::Option<Vec<[u8; 3]>>,
+    pub color_dither: bool,
+    pub hysteresis_gamma: f32,
+    pub ruler: bool,
+    pub edge_color_only: bool,
+    pub line_height: std::option::Option<f32>,
+    pub auto_char_scale: bool,
+    pub tone_map: bool,
+    pub color_border: bool,
+    pub ramp_cycle: Vec<String>,
+    pub border_inside: bool,
+    pub web_safe_colors: bool,
+    pub density_channel: DensityChannel,
+    pub comment_prefix: std::option::Option<String>,
+    pub auto_invert: bool,
+    pub html_color_format: HtmlColorFormat,
+    pub legend: bool,
+    pub tile_source: bool,
+    pub rounding: IndexRounding,
+    pub fixed_color: std::option::Option<[u8; 3]>,
+    pub linear_downscale: bool,
+    pub max_display_width: std::option::Option<u32>,
+    pub ansi_tie_break: AnsiTieBreak,
+    pub auto_rotate: bool,
+    #[cfg_attr(feature = "serde", serde(with = "serde_lut"))]
+    pub luminance_lut: std::option::Option<Box<[u8; 256]>>,
+    pub sauce: std::option::Option<SauceInfo>,
+    pub pattern_match: bool,
+    pub html_fragment: bool,
+    pub outline_normalize: bool,
+    pub pixel_art: bool,
+    pub equalize: bool,
+    pub max_color_switches_per_line: std::option::Option<u32>,
+    pub color_distance: ColorDistance,
+    pub min_contrast: f32,
+    pub check_width: bool,
+    pub edge_mode: EdgeMode,
+    pub transpose: bool,
+    pub kernel_size: u32,
+    pub half_blocks: bool,
+    pub dither: bool,
+    pub luminance_weights: LuminanceWeights,
+    #[cfg_attr(feature = "serde", serde(with = "serde_rgb"))]
+    pub background_fill: Rgb<u8>,
+    pub braille: std::option::Option<u8>,
+}
+
+impl Option {
+    /// Create [`OptionBuilder`] with default properties.
     ///
-    /// This will flip the image horizontally by reversing reading of the horizontal part of the image.
+    /// # Examples
+    /// ```
+    /// use artem::options::Option;
+    ///
+    /// let default = Option::builder();
+    /// ```
+    pub fn builder() -> OptionBuilder {
+        OptionBuilder::default()
+    }
+
+    /// Create a [`OptionBuilder`] with the properties of this [`Option`].
+    ///
+    /// This can be used to change a single property of an existing [`Option`], without
+    /// having to set every other property again.
     ///
     /// # Examples
     /// ```
     /// use artem::options::OptionBuilder;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.transform_x(true);
+    /// let options = OptionBuilder::new().build();
+    /// let inverted = options.to_builder().invert(true).build();
+    /// assert!(inverted.invert);
     /// ```
-    => transform_x, bool
+    pub fn to_builder(&self) -> OptionBuilder {
+        OptionBuilder {
+            characters: self.characters.to_owned(),
+            scale: self.scale,
+            target_size: self.target_size,
+            invert: self.invert,
+            border: self.border,
+            dimension: self.dimension,
+            transform_x: self.transform_x,
+            transform_y: self.transform_y,
+            anchor_x: self.anchor_x,
+            anchor_y: self.anchor_y,
+            outline: self.outline,
+            hysteresis: self.hysteresis,
+            target: self.target,
+            sharpen: self.sharpen,
+            color_mode: self.color_mode,
+            clear_screen: self.clear_screen,
+            backdrop: self.backdrop,
+            color_quantize_step: self.color_quantize_step,
+            min_color_luminance: self.min_color_luminance,
+            smooth_horizontal: self.smooth_horizontal,
+            palette: self.palette.clone(),
+            color_dither: self.color_dither,
+            hysteresis_gamma: self.hysteresis_gamma,
+            ruler: self.ruler,
+            edge_color_only: self.edge_color_only,
+            line_height: self.line_height,
+            auto_char_scale: self.auto_char_scale,
+            tone_map: self.tone_map,
+            color_border: self.color_border,
+            ramp_cycle: self.ramp_cycle.clone(),
+            border_inside: self.border_inside,
+            web_safe_colors: self.web_safe_colors,
+            density_channel: self.density_channel,
+            comment_prefix: self.comment_prefix.clone(),
+            auto_invert: self.auto_invert,
+            html_color_format: self.html_color_format,
+            legend: self.legend,
+            tile_source: self.tile_source,
+            rounding: self.rounding,
+            fixed_color: self.fixed_color,
+            linear_downscale: self.linear_downscale,
+            max_display_width: self.max_display_width,
+            ansi_tie_break: self.ansi_tie_break,
+            auto_rotate: self.auto_rotate,
+            luminance_lut: self.luminance_lut.clone(),
+            sauce: self.sauce.clone(),
+            //the characters are already padded
+            pad_characters: false,
+            pattern_match: self.pattern_match,
+            html_fragment: self.html_fragment,
+            outline_normalize: self.outline_normalize,
+            pixel_art: self.pixel_art,
+            equalize: self.equalize,
+            max_color_switches_per_line: self.max_color_switches_per_line,
+            color_distance: self.color_distance,
+            min_contrast: self.min_contrast,
+            check_width: self.check_width,
+            edge_mode: self.edge_mode,
+            transpose: self.transpose,
+            kernel_size: self.kernel_size,
+            half_blocks: self.half_blocks,
+            dither: self.dither,
+            luminance_weights: self.luminance_weights,
+            background_fill: self.background_fill,
+            braille: self.braille,
+        }
     }
 
-    property! {
-    ///Flip the image along the Y-axis
+    /// Return if the image is centered horizontally.
     ///
-    /// This will flip the image vertically by reversing reading of the vertical part of the image.
+    /// This replaces the former `center_x` field, use [`Option::anchor_x`] instead.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::{options::OptionBuilder, util::HorizontalAnchor};
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.transform_y(true);
+    /// let options = OptionBuilder::new().anchor_x(HorizontalAnchor::Center).build();
+    /// #[allow(deprecated)]
+    /// let centered = options.center_x();
+    /// assert!(centered);
     /// ```
-    => transform_y, bool
+    #[deprecated(since = "2.0.0", note = "use the `anchor_x` field instead")]
+    pub fn center_x(&self) -> bool {
+        self.anchor_x == HorizontalAnchor::Center
     }
 
-    property! {
-    /// Center the image horizontally in the terminal
+    /// Return if the image is centered vertically.
     ///
-    /// It will center the image by adding spaces in front of the text.
-    /// Since the terminal might have an uneven width, and only monospace chars are allowed,
-    /// the spacing might not always be accurate.
+    /// This replaces the former `center_y` field, use [`Option::anchor_y`] instead.
     ///
     /// # Examples
     /// ```
-    /// use artem::options::OptionBuilder;
+    /// use artem::{options::OptionBuilder, util::VerticalAnchor};
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.center_x(true);
+    /// let options = OptionBuilder::new().anchor_y(VerticalAnchor::Center).build();
+    /// #[allow(deprecated)]
+    /// let centered = options.center_y();
+    /// assert!(centered);
     /// ```
-    => center_x, bool
+    #[deprecated(since = "2.0.0", note = "use the `anchor_y` field instead")]
+    pub fn center_y(&self) -> bool {
+        self.anchor_y == VerticalAnchor::Center
     }
 
-    property! {
-    /// Center the image vertically in the terminal
+    /// Return the characters, which are used for the given row.
     ///
-    /// It will center the image by adding new lines  above and below the text.
-    /// Since the terminal might have an uneven height, and only monospace chars are allowed,
-    /// the spacing might not always be accurate.
+    /// If a ramp cycle is set, the rows cycle through it, otherwise the characters are used.
     ///
     /// # Examples
     /// ```
     /// use artem::options::OptionBuilder;
     ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.center_y(true);
+    /// let options = OptionBuilder::new()
+    ///     .ramp_cycle(vec!["MW. ".to_string(), "#k. ".to_string()])
+    ///     .build();
+    /// assert_eq!("#k. ", options.row_characters(3));
     /// ```
-    => center_y, bool
+    pub fn row_characters(&self, row: usize) -> &str {
+        if self.ramp_cycle.is_empty() {
+            &self.characters
+        } else {
+            &self.ramp_cycle[row % self.ramp_cycle.len()]
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_option {
+    use super::*;
+
+    #[test]
+    fn row_characters_without_cycle() {
+        let options = OptionBuilder::new().build();
+        assert_eq!(options.characters, options.row_characters(5));
+    }
+
+    #[test]
+    fn row_characters_cycle() {
+        let options = OptionBuilder::new()
+            .ramp_cycle(vec!["ab".to_string(), "cd".to_string(), "ef".to_string()])
+            .build();
+        assert_eq!("ab", options.row_characters(0));
+        assert_eq!("cd", options.row_characters(1));
+        assert_eq!("ef", options.row_characters(2));
+        assert_eq!("ab", options.row_characters(3));
+    }
+    #[test]
+    fn builder_default() {
+        assert_eq!(
+            OptionBuilder {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pad_characters: false,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            Option::builder()
+        );
+    }
+
+    #[test]
+    fn to_builder_round_trip() {
+        let options = OptionBuilder::new().border(true).build();
+        assert_eq!(options, options.to_builder().build());
+    }
+
+    #[test]
+    fn clone_modify_rebuild() {
+        let options = OptionBuilder::new().border(true).build();
+        let modified = options.clone().to_builder().invert(true).build();
+
+        //the original options are not changed
+        assert!(!options.invert);
+        assert!(modified.invert);
+        //all other properties are kept
+        assert_eq!(options.border, modified.border);
+        assert_eq!(options.characters, modified.characters);
+    }
+}
+
+///A builder to create a [`Option`] struct.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OptionBuilder {
+    characters: String,
+    scale: f32,
+    target_size: u32,
+    invert: bool,
+    border: bool,
+    dimension: ResizingDimension,
+    transform_x: bool,
+    transform_y: bool,
+    anchor_x: HorizontalAnchor,
+    anchor_y: VerticalAnchor,
+    outline: bool,
+    hysteresis: bool,
+    target: TargetType,
+    sharpen: f32,
+    color_mode: ColorMode,
+    clear_screen: bool,
+    backdrop: std::option::Option<[u8; 3]>,
+    color_quantize_step: std::option::Option<u8>,
+    min_color_luminance: f32,
+    smooth_horizontal: bool,
+    palette: std::option::Option<Vec<[u8; 3]>>,
+    color_dither: bool,
+    hysteresis_gamma: f32,
+    ruler: bool,
+    edge_color_only: bool,
+    line_height: std::option::Option<f32>,
+    auto_char_scale: bool,
+    tone_map: bool,
+    color_border: bool,
+    ramp_cycle: Vec<String>,
+    border_inside: bool,
+    web_safe_colors: bool,
+    density_channel: DensityChannel,
+    comment_prefix: std::option::Option<String>,
+    auto_invert: bool,
+    html_color_format: HtmlColorFormat,
+    legend: bool,
+    tile_source: bool,
+    rounding: IndexRounding,
+    fixed_color: std::option::Option<[u8; 3]>,
+    linear_downscale: bool,
+    max_display_width: std::option::Option<u32>,
+    ansi_tie_break: AnsiTieBreak,
+    auto_rotate: bool,
+    #[cfg_attr(feature = "serde", serde(with = "serde_lut"))]
+    luminance_lut: std::option::Option<Box<[u8; 256]>>,
+    sauce: std::option::Option<SauceInfo>,
+    pad_characters: bool,
+    pattern_match: bool,
+    html_fragment: bool,
+    outline_normalize: bool,
+    pixel_art: bool,
+    equalize: bool,
+    max_color_switches_per_line: std::option::Option<u32>,
+    color_distance: ColorDistance,
+    min_contrast: f32,
+    check_width: bool,
+    edge_mode: EdgeMode,
+    transpose: bool,
+    kernel_size: u32,
+    half_blocks: bool,
+    dither: bool,
+    luminance_weights: LuminanceWeights,
+    #[cfg_attr(feature = "serde", serde(with = "serde_rgb"))]
+    background_fill: Rgb<u8>,
+    braille: std::option::Option<u8>,
+}
+
+impl Default for OptionBuilder {
+    fn default() -> Self {
+        Self {
+            //these have to be set to custom defaults for the program to work
+            characters: pixel::DEFAULT_CHARACTERS.to_string(),
+            scale: 0.42f32,
+            target_size: 80,
+            invert: Default::default(),
+            border: Default::default(),
+            dimension: Default::default(),
+            transform_x: Default::default(),
+            transform_y: Default::default(),
+            anchor_x: Default::default(),
+            anchor_y: Default::default(),
+            outline: Default::default(),
+            hysteresis: Default::default(),
+            target: Default::default(),
+            sharpen: 0f32,
+            color_mode: Default::default(),
+            clear_screen: false,
+            backdrop: None,
+            color_quantize_step: None,
+            min_color_luminance: 0f32,
+            smooth_horizontal: false,
+            palette: None,
+            color_dither: false,
+            hysteresis_gamma: 1f32,
+            ruler: false,
+            edge_color_only: false,
+            line_height: None,
+            auto_char_scale: false,
+            tone_map: false,
+            color_border: false,
+            ramp_cycle: Vec::new(),
+            border_inside: true,
+            web_safe_colors: false,
+            density_channel: DensityChannel::Luminosity,
+            comment_prefix: None,
+            auto_invert: false,
+            html_color_format: HtmlColorFormat::Hex,
+            legend: false,
+            tile_source: false,
+            rounding: IndexRounding::Floor,
+            fixed_color: None,
+            linear_downscale: false,
+            max_display_width: None,
+            ansi_tie_break: AnsiTieBreak::Standard,
+            auto_rotate: false,
+            luminance_lut: None,
+            sauce: None,
+            pad_characters: false,
+            pattern_match: false,
+            html_fragment: false,
+            outline_normalize: false,
+            pixel_art: false,
+            equalize: false,
+            max_color_switches_per_line: None,
+            color_distance: ColorDistance::default(),
+            min_contrast: 0f32,
+            check_width: false,
+            edge_mode: EdgeMode::default(),
+            transpose: Default::default(),
+            kernel_size: 3,
+            half_blocks: Default::default(),
+            dither: false,
+            luminance_weights: LuminanceWeights::default(),
+            background_fill: Rgb([255, 255, 255]),
+            braille: None,
+        }
+    }
+}
+
+/// Generate a builder property.
+///
+/// This macro generates a function, which sets the specified property of the Builder.
+/// It does NOT check the value passed in, so for example it would be possible to pass in an empty string, which could lead to
+/// errors later this. This should be done before setting the value.
+///
+/// # Examples
+///
+/// ``` compile_fail, just an internal example code
+/// property!{
+/// ///Example doc
+/// ///This generates a name setter function.
+/// => name, String
+/// }
+/// ```
+/// ## Generated function
+/// ``` compile_fail, just an internal example code
+/// ///Example doc
+/// ///This generates a name setter function.
+/// pub fn name(&mut self, name: String) -> &mut Self {
+///     self.name = name;
+///     self
+/// }
+/// ```
+macro_rules! property {
+    ($(#[$attr:meta])* => $field:ident, $field_type:ty) => {
+        $(#[$attr])*
+        pub fn $field(&mut self, $field: $field_type) -> &mut Self {
+            self.$field = $field;
+            self
+        }
+    };
+    ($(#[$attr:meta])* => $field:ident, $field_type:ty, $func:ident) => {
+        $(#[$attr])*
+        pub fn $field(&mut self, $field: $field_type) -> &mut Self {
+            self.$field = $field.$func();
+            self
+        }
+    };
+}
+
+// impl Default  {
+
+// }
+
+impl OptionBuilder {
+    ///Create a new OptionBuilder.
+    ///
+    /// If an option is not specified, the rust default value will be used, unless specified otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// ```
+    pub fn new() -> OptionBuilder {
+        Option::builder()
+    }
+
+    ///Create an OptionBuilder from a toml document.
+    ///
+    /// The keys are the names of the options, missing options keep their default value.
+    /// This requires the `serde` and `config` features.
+    ///
+    /// # Errors
+    /// Returns an error, if the document is not valid toml or an option has the wrong type.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let builder = OptionBuilder::from_toml("target_size = 40\nborder = true").unwrap();
+    /// let options = builder.build();
+    /// assert_eq!(40, options.target_size);
+    /// assert!(options.border);
+    /// ```
+    #[cfg(all(feature = "serde", feature = "config"))]
+    pub fn from_toml(content: &str) -> Result<OptionBuilder, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    ///Set the characters.
+    ///
+    /// The characters will determine how 'visible'/light/dark a character will be perceived.
+    ///
+    /// # Errors
+    /// When the given characters are empty, the characters will not be changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.characters("Mkl. ".to_string());
+    /// ```
+    #[allow(clippy::needless_lifetimes)] //disable this, as the life is needed for the builder
+    pub fn characters<'a>(&'a mut self, characters: String) -> &'a Self {
+        if !characters.is_empty() {
+            self.characters = characters;
+        }
+        self
+    }
+
+    property! {
+    /// Set the scale.
+    ///
+    /// Used to change the ratio between width and height of an character.
+    /// Since a char is a bit higher than wide, the scale should compensate for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.scale(0.42f32);
+    /// ```
+       => scale, f32
+    }
+    // pub fn scale(&'a mut self, scale: f32) -> &'a mut Self {
+    //     self.scale = scale;
+    //     self
+    // }
+
+    property! {
+    /// Set the target_size.
+    ///
+    /// This is the number of characters that the resulting ascii image will be heigh/wide.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use core::num::NonZeroU32;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.target_size(NonZeroU32::new(80).unwrap());
+    /// ```
+    => target_size, NonZeroU32, get
+    }
+
+    property! {
+    ///Invert to density map/characters.
+    ///
+    /// This inverts the mapping from light to dark characters. It can be useful when
+    /// the image has a dark background. It defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.invert(true);
+    /// ```
+    => invert, bool
+    // pub fn invert(mut self, invert: bool) -> Self {
+    //     self.invert = invert;
+    //     self
+    }
+
+    property! {
+    ///Enable a border surrounding the image.
+    ///
+    /// The border will take reduced the space of the ascii image, since it will still
+    /// use the same target size.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.border(true);
+    /// ```
+    => border, bool
+    }
+
+    property! {
+    /// Set which dimension should be scaled first.
+    ///
+    /// See [`ResizingDimension`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use artem::util::ResizingDimension;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.dimension(ResizingDimension::Height);
+    /// ```
+    => dimension, util::ResizingDimension
+    }
+
+    property! {
+    ///Flip the image along the X-axis
+    ///
+    /// This will flip the image horizontally by reversing reading of the horizontal part of the image.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.transform_x(true);
+    /// ```
+    => transform_x, bool
+    }
+
+    property! {
+    ///Flip the image along the Y-axis
+    ///
+    /// This will flip the image vertically by reversing reading of the vertical part of the image.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.transform_y(true);
+    /// ```
+    => transform_y, bool
+    }
+
+    /// Center the image horizontally in the terminal
+    ///
+    /// It will center the image by adding spaces in front of the text.
+    /// Since the terminal might have an uneven width, and only monospace chars are allowed,
+    /// the spacing might not always be accurate.
+    /// This is a shorthand for setting [`OptionBuilder::anchor_x`] to [`HorizontalAnchor::Center`],
+    /// `false` resets it to [`HorizontalAnchor::Start`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.center_x(true);
+    /// ```
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `anchor_x` with `HorizontalAnchor::Center` instead"
+    )]
+    pub fn center_x(&mut self, center_x: bool) -> &mut Self {
+        self.anchor_x = if center_x {
+            HorizontalAnchor::Center
+        } else {
+            HorizontalAnchor::Start
+        };
+        self
+    }
+
+    /// Center the image vertically in the terminal
+    ///
+    /// It will center the image by adding new lines  above and below the text.
+    /// Since the terminal might have an uneven height, and only monospace chars are allowed,
+    /// the spacing might not always be accurate.
+    /// This is a shorthand for setting [`OptionBuilder::anchor_y`] to [`VerticalAnchor::Center`],
+    /// `false` resets it to [`VerticalAnchor::Start`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.center_y(true);
+    /// ```
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `anchor_y` with `VerticalAnchor::Center` instead"
+    )]
+    pub fn center_y(&mut self, center_y: bool) -> &mut Self {
+        self.anchor_y = if center_y {
+            VerticalAnchor::Center
+        } else {
+            VerticalAnchor::Start
+        };
+        self
+    }
+
+    property! {
+    /// Set where the image is placed horizontally in the terminal
+    ///
+    /// The image is moved by adding spaces in front of the text, see [`HorizontalAnchor`] for more information.
+    /// It defaults to [`HorizontalAnchor::Start`], which does not add any spacing.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use artem::util::HorizontalAnchor;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.anchor_x(HorizontalAnchor::End);
+    /// ```
+    => anchor_x, HorizontalAnchor
+    }
+
+    property! {
+    /// Set where the image is placed vertically in the terminal
+    ///
+    /// The image is moved by adding new lines above and below the text, see [`VerticalAnchor`] for more information.
+    /// It defaults to [`VerticalAnchor::Start`], which does not add any spacing.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use artem::util::VerticalAnchor;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.anchor_y(VerticalAnchor::End);
+    /// ```
+    => anchor_y, VerticalAnchor
+    }
+
+    property! {
+    ///Convert the image to it's outline
+    ///
+    /// This will use gaussian blur and sobel operators to only it's outline,
+    /// it might not produce the best result on all images.
+    /// Caution, this will take some additional time.
+    /// Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.outline(true);
+    /// ```
+    => outline, bool
+    }
+
+    property! {
+    /// When converting the image to an outline, also use double threshold and hysteresis
+    ///
+    /// It will remove small imperfection in the outline for the cost of smaller/thinner lines, which can make
+    /// the resulting ascii looking less visible, which might not be desired.
+    ///
+    /// It will only be used when outlining is set to true.
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.hysteresis(true);
+    /// ```
+    => hysteresis, bool
+    }
+
+    property! {
+    ///Set the target type
+    ///
+    /// This will effect the output, for example if the [`TargetType`] is set to html,
+    /// the output will be the content of a Html-file.
+    ///
+    /// See [`TargetType`] for more information. It defaults to the shell as the target.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use artem::options::TargetType;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// //use color, but don't color the background
+    /// builder.target(TargetType::HtmlFile(true, false));
+    /// ```
+        => target,  TargetType
+    }
+
+    property! {
+    /// Sharpen the resized image before converting it.
+    ///
+    /// This uses unsharp masking, which adds the difference between the image and a blurred
+    /// version of it back onto the image. The amount controls how strong the effect is, fine textures
+    /// and edges will be more visible in the ascii image.
+    /// An amount of 0 (the default) disables sharpening.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.sharpen(1.5f32);
+    /// ```
+    => sharpen, f32
+    }
+
+    property! {
+    ///Set the color mode
+    ///
+    /// This changes how colors are represented for targets, which use ANSI escape sequences,
+    /// for example the shell. It has no effect on other targets.
+    ///
+    /// See [`ColorMode`] for more information. It defaults to [`ColorMode::Auto`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{ColorMode, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.color_mode(ColorMode::Grayscale256);
+    /// ```
+    => color_mode, ColorMode
+    }
+
+    property! {
+    /// Clear the screen before printing the image
+    ///
+    /// This prepends the ANSI clear screen and cursor home sequence to the output,
+    /// so leftovers from a previous, larger image are removed.
+    /// It only has an effect on the shell target, files are never cleared.
+    /// Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.clear_screen(true);
+    /// ```
+    => clear_screen, bool
+    }
+
+    property! {
+    /// Set a backdrop color behind the image
+    ///
+    /// Unlike the per-character background color of [`TargetType::Shell`], this uses a single
+    /// RGB color as the background for the whole image. It is set at the start of every row
+    /// and reset at the end of it.
+    /// It only has an effect on the colored shell target. Defaults to `None`, which does not change the background.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.backdrop(Some([30, 30, 30]));
+    /// ```
+    => backdrop, std::option::Option<[u8; 3]>
+    }
+
+    property! {
+    /// Round the colors of the image to multiples of the step
+    ///
+    /// Each color channel is snapped to the nearest multiple of the step, so tiles with nearly identical
+    /// colors share the same color. This reduces the amount of different colors, which keeps colored files smaller.
+    /// Defaults to `None`, which uses the exact colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.color_quantize_step(Some(16));
+    /// ```
+    => color_quantize_step, std::option::Option<u8>
+    }
+
+    property! {
+    /// Set the minimum luminance of the colors
+    ///
+    /// Colors darker than the minimum are brightened to it, so the characters stay readable on a dark background.
+    /// The characters of the image are not changed. The luminance is between 0 and 255,
+    /// it defaults to 0, which does not change any colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.min_color_luminance(64f32);
+    /// ```
+    => min_color_luminance, f32
+    }
+
+    property! {
+    /// Smooth the transitions between horizontally adjacent characters
+    ///
+    /// If two neighboring tiles differ by more than one character of the density string, the brighter
+    /// one is moved half a character towards the darker one. This reduces harsh banding along horizontal gradients.
+    /// Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.smooth_horizontal(true);
+    /// ```
+    => smooth_horizontal, bool
+    }
+
+    property! {
+    /// Set the palette, which the colors are reduced to
+    ///
+    /// Every color is replaced with the closest color of the palette. The characters are not changed.
+    /// Defaults to `None`, which uses all colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.palette(Some(vec![[0, 0, 0], [255, 0, 0], [255, 255, 255]]));
+    /// ```
+    => palette, std::option::Option<Vec<[u8; 3]>>
+    }
+
+    property! {
+    /// Dither the colors when reducing them to the palette
+    ///
+    /// Instead of replacing each color with the closest color of the palette, the difference is spread to the neighboring
+    /// characters (Floyd–Steinberg dithering). This represents gradients with a mix of the palette colors instead of hard bands.
+    /// It only has an effect, when a palette is set. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.color_dither(true);
+    /// ```
+    => color_dither, bool
+    }
+
+    property! {
+    /// Set the gamma, which is applied to the edge magnitudes before the hysteresis thresholds
+    ///
+    /// The edge magnitudes are not gamma encoded, which makes the thresholds behave unintuitively.
+    /// A gamma larger than 1.0 lifts weak edges, so more of them survive, smaller values remove more of them.
+    /// It only has an effect when using hysteresis. Defaults to 1.0, which uses the magnitudes unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.hysteresis_gamma(2.2);
+    /// ```
+    => hysteresis_gamma, f32
+    }
+
+    property! {
+    /// Add a ruler, which marks every 10th column and row with its index
+    ///
+    /// The column indices are added in a row above the image, the row indices in a column left of the image.
+    /// This makes the output larger. It is only used for the shell and plain text files, since it would break
+    /// the markup of the other targets. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.ruler(true);
+    /// ```
+    => ruler, bool
+    }
+
+    property! {
+    /// Only color the cells containing edges
+    ///
+    /// Cells in flat regions use a gray with the same luminosity instead, which results in a glowing outline effect.
+    /// The characters are not changed. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.edge_color_only(true);
+    /// ```
+    => edge_color_only, bool
+    }
+
+    property! {
+    /// Set the line height of the html output
+    ///
+    /// It is used as the css line-height of the ascii image, a smaller value removes the gaps between the rows.
+    /// It only has an effect for html files. Defaults to `None`, which uses the default line height of the browser.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.line_height(Some(0.8));
+    /// ```
+    => line_height, std::option::Option<f32>
+    }
+
+    property! {
+    /// Derive the scale from the aspect ratio of the image
+    ///
+    /// Instead of using the scale directly, it is used as the ratio between the width and height of a terminal character.
+    /// The scale is then chosen, so that the ascii image has the closest aspect ratio to the input image.
+    /// This reduces the distortion of images with unusual aspect ratios. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.auto_char_scale(true);
+    /// ```
+    => auto_char_scale, bool
+    }
+
+    property! {
+    /// Compress the highlights of the image using tone mapping
+    ///
+    /// The Reinhard operator is applied to the luminosity in linear light, before choosing the characters.
+    /// This helps with images with blown out highlights, which would otherwise only use the densest characters.
+    /// The colors are not changed. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.tone_map(true);
+    /// ```
+    => tone_map, bool
+    }
+
+    property! {
+    /// Color the border using the colors of the adjacent cells
+    ///
+    /// Each border char uses the color of the closest cell, so the border matches the image.
+    /// It only has an effect when using a border with a colored target. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.color_border(true);
+    /// ```
+    => color_border, bool
+    }
+
+    property! {
+    /// Set the characters, which are cycled through by row
+    ///
+    /// Row `i` uses the characters at index `i % len`, which results in a woven texture. Like the characters, each entry
+    /// should be ordered from darkest/densest to lightest. Defaults to an empty list, which uses the characters for every row.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.ramp_cycle(vec!["MWX. ".to_string(), "#k. ".to_string()]);
+    /// ```
+    => ramp_cycle, Vec<String>
+    }
+
+    property! {
+    /// Set if the border is placed inside of the target size
+    ///
+    /// When true, the columns and rows of the border are subtracted from the image, so the total output stays within the target size.
+    /// When false, the image keeps its full size and the border is wrapped around it, making the output 2 columns and rows larger.
+    /// Defaults to true.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.border_inside(false);
+    /// ```
+    => border_inside, bool
+    }
+
+    property! {
+    /// Snap the colors to the 216 web-safe colors
+    ///
+    /// Each color channel is rounded to the nearest of 0, 51, 102, 153, 204 and 255. This reduces the amount of different
+    /// colors even further than rounding to a step, which is useful for very constrained html contexts. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.web_safe_colors(true);
+    /// ```
+    => web_safe_colors, bool
+    }
+
+    property! {
+    /// Set the value, which selects the character of a cell
+    ///
+    /// Instead of the luminosity, a single color channel can be used, which can highlight specific features of the image.
+    /// The colors are not changed by this. See [`DensityChannel`] for more information. It defaults to [`DensityChannel::Luminosity`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{DensityChannel, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.density_channel(DensityChannel::Red);
+    /// ```
+    => density_channel, DensityChannel
+    }
+
+    property! {
+    /// Set the prefix, which is added in front of every line
+    ///
+    /// This can be used to turn the ascii art into a comment, for example with `// ` or `# `, so it can be embedded in source code.
+    /// It is only used for the shell and plain text files. Since the escape sequences would break the comment, colors are disabled when it is set.
+    /// Defaults to `None`, which does not add a prefix.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.comment_prefix(Some("// ".to_string()));
+    /// ```
+    => comment_prefix, std::option::Option<String>
+    }
+
+    property! {
+    /// Set if the density should be inverted automatically, based on the background of the image
+    ///
+    /// The background is sampled from the edge pixels of the image. If it is dark, the density is inverted, otherwise it is not.
+    /// This replaces the `invert` option. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.auto_invert(true);
+    /// ```
+    => auto_invert, bool
+    }
+
+    property! {
+    /// Set the format of the colors in html files
+    ///
+    /// See [`HtmlColorFormat`] for more information. It defaults to [`HtmlColorFormat::Hex`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{HtmlColorFormat, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.html_color_format(HtmlColorFormat::Hsl);
+    /// ```
+    => html_color_format, HtmlColorFormat
+    }
+
+    property! {
+    /// Set if a legend of the characters should be added below the image
+    ///
+    /// The legend lists the characters from the densest to the lightest, so readers understand how the image is encoded.
+    /// It is only added to the shell and plain text files, since it would break the markup of the other targets. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.legend(true);
+    /// ```
+    => legend, bool
+    }
+
+    property! {
+    /// Set if a small image should be repeated to fill the target size
+    ///
+    /// If the image is smaller than the target size, it is repeated instead of upscaled, which keeps small textures crisp.
+    /// Larger images are not changed. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.tile_source(true);
+    /// ```
+    => tile_source, bool
+    }
+
+    property! {
+    /// Set the rounding of the density index
+    ///
+    /// Rounding to the nearest index distributes the cells more evenly across the characters.
+    /// See [`IndexRounding`] for more information. It defaults to [`IndexRounding::Floor`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{IndexRounding, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.rounding(IndexRounding::Round);
+    /// ```
+    => rounding, IndexRounding
+    }
+
+    property! {
+    /// Set a fixed color for every character
+    ///
+    /// The characters are still selected using the image, but every character uses the same color instead of the color of the image.
+    /// This can be used for tinted, monochrome images. Defaults to `None`, which uses the colors of the image.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.fixed_color(Some([0, 255, 0]));
+    /// ```
+    => fixed_color, std::option::Option<[u8; 3]>
+    }
+
+    property! {
+    /// Set if the image should be downscaled in linear light
+    ///
+    /// Downscaling the srgb values directly darkens bright details, for example a black and white checkerboard becomes a dark gray.
+    /// Converting the image to linear light before resizing averages the light correctly, but takes some additional time. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.linear_downscale(true);
+    /// ```
+    => linear_downscale, bool
+    }
+
+    property! {
+    /// Set the maximum width of the output in columns
+    ///
+    /// If the converted image, including the border, would be wider than the maximum width, the target size is reduced until it fits,
+    /// so the output is not wrapped by narrow viewers. It defaults to None, which does not limit the width.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.max_display_width(Some(60));
+    /// ```
+    => max_display_width, std::option::Option<u32>
+    }
+
+    property! {
+    /// Set the preferred color, when a color is equally close to two ansi colors
+    ///
+    /// This is only used for the 16 basic ansi colors, when the terminal does not support truecolor.
+    /// See [`AnsiTieBreak`] for more information. It defaults to [`AnsiTieBreak::Standard`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{AnsiTieBreak, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.ansi_tie_break(AnsiTieBreak::Bright);
+    /// ```
+    => ansi_tie_break, AnsiTieBreak
+    }
+
+    property! {
+    /// Set if the image should be rotated to fit the terminal better
+    ///
+    /// If a portrait image would cover more of a wide area when rotated by 90 degrees (or a landscape image of a high area),
+    /// it is rotated clockwise. The area is the target size and the maximum rows of [`ResizingDimension::Fit`] or
+    /// [`ResizingDimension::Contain`], otherwise the height of the terminal. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.auto_rotate(true);
+    /// ```
+    => auto_rotate, bool
+    }
+
+    property! {
+    /// Set a lookup table, which is applied to the luminosity before choosing the characters
+    ///
+    /// The luminosity (0 - 255) of every cell is replaced by the value at its index, which allows arbitrary tone curves,
+    /// for example to emulate a film. The colors are not changed. It defaults to None, which keeps the luminosity.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// //a curve, which brightens the dark tones
+    /// let mut curve = [0u8; 256];
+    /// for (index, value) in curve.iter_mut().enumerate() {
+    ///     *value = ((index as f32 / 255f32).sqrt() * 255f32) as u8;
+    /// }
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.luminance_lut(Some(Box::new(curve)));
+    /// ```
+    => luminance_lut, std::option::Option<Box<[u8; 256]>>
+    }
+
+    property! {
+    /// Set the SAUCE metadata of ansi files
+    ///
+    /// If it is set, a SAUCE record with the metadata and the dimensions of the art is appended when writing an ansi file.
+    /// See [`SauceInfo`] for more information. It defaults to None, which does not add a record.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{OptionBuilder, SauceInfo};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.sauce(Some(SauceInfo::default()));
+    /// ```
+    => sauce, std::option::Option<SauceInfo>
+    }
+
+    property! {
+    /// Set if a single character should be padded with a space.
+    ///
+    /// With only one character, every cell is converted to the same character, which results in a solid block.
+    /// When enabled, a space is added as the lightest character, so the image has at least two levels.
+    /// It defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.characters("#".to_string());
+    /// builder.pad_characters(true);
+    /// assert_eq!("# ", builder.build().characters);
+    /// ```
+    => pad_characters, bool
+    }
+
+    property! {
+    /// Set if the characters are selected by matching the pattern of the cells.
+    ///
+    /// Every cell is split into 3x3 sub-pixels and the glyph, which resembles their pattern best, is used,
+    /// for example a `+` for a cross. Cells without a clear pattern use the characters as usual.
+    /// It defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.pattern_match(true);
+    /// ```
+    => pattern_match, bool
+    }
+
+    property! {
+    /// Set if html files only contain the pre element.
+    ///
+    /// The document around it, like the doctype and the head, is left out, so the ascii image can be embedded in an existing page.
+    /// The line height is set on the pre element instead. It defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.html_fragment(true);
+    /// ```
+    => html_fragment, bool
+    }
+
+    property! {
+    /// Set if the edges of the outline are stretched to the full brightness range.
+    ///
+    /// Faint edges only use a few of the characters, stretching the magnitudes of the edges
+    /// from the weakest to the strongest one to 0 - 255 results in crisper outlines.
+    /// It is only used together with the outline. It defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.outline_normalize(true);
+    /// ```
+    => outline_normalize, bool
+    }
+
+    property! {
+    /// Set if the image should be converted as pixel art
+    ///
+    /// This is a preset, which resizes the image using the nearest neighbor, instead of averaging the pixels.
+    /// The color of each character is the exact color of the pixel in the center of its tile, so hard color edges are not blended,
+    /// whilst the characters are selected using an ordered dithering of the luminosity, which keeps the tonal texture. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.pixel_art(true);
+    /// ```
+    => pixel_art, bool
+    }
+
+    property! {
+    /// Equalize the histogram of the luminosity
+    ///
+    /// The luminosity of each cell is replaced by its position in the cumulative histogram of all cells, before choosing the characters.
+    /// This spreads flat, low contrast images over all characters, so each character is used about equally often.
+    /// The colors are not changed. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.equalize(true);
+    /// ```
+    => equalize, bool
+    }
+
+    property! {
+    /// Set the maximum number of color runs in each line of the colored shell output
+    ///
+    /// Slow terminals render many color changes sluggishly. If a line would use more runs of the same color,
+    /// similar adjacent colors are merged, until the line has at most this many runs. Each run only uses a single color sequence.
+    /// This trades the accuracy of the colors for the speed of rendering. Defaults to None, which does not limit the runs.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.max_color_switches_per_line(Some(8));
+    /// ```
+    => max_color_switches_per_line, std::option::Option<u32>
+    }
+
+    property! {
+    /// Set the distance, which is used to find the closest color
+    ///
+    /// The distance is used when reducing the colors to the palette, as well as when rounding them using the color step
+    /// or to the web-safe colors. The weighted and the lab distance match the perceived difference more closely,
+    /// since the eye is more sensitive to green. Defaults to [`ColorDistance::Euclidean`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{ColorDistance, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.color_distance(ColorDistance::Weighted);
+    /// ```
+    => color_distance, ColorDistance
+    }
+
+    property! {
+    /// Set the minimum contrast of the luminosity, between 0.0 and 255.0
+    ///
+    /// If the luminosity of the cells spreads over less than the minimum contrast, it is stretched to the full range,
+    /// so subtle variations of nearly uniform images use different characters, instead of a featureless block.
+    /// Images with a single luminosity are not changed. Defaults to 0.0, which never stretches the luminosity.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.min_contrast(16f32);
+    /// ```
+    => min_contrast, f32
+    }
+
+    property! {
+    /// Set if the display width of the characters should be checked
+    ///
+    /// Wide characters, such as full-width east asian characters, take up two columns in the terminal, which breaks the alignment.
+    /// If any of the characters is wide, the number of columns is halved, so the image keeps its size and aspect ratio. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.check_width(true);
+    /// ```
+    => check_width, bool
+    }
+
+    property! {
+    /// Set how the pixels at the right and bottom edge are handled, if they do not fill a whole tile
+    ///
+    /// With [`EdgeMode::Pad`] the image is not resized to whole tiles, instead the last partial tiles are padded with the pixels at the edge,
+    /// so thin details at the edges are kept. The tiles can become slightly larger, so the image may use a few columns less.
+    /// Since the image is not resized, padding can not be combined with pixel art or linear downscaling.
+    /// Defaults to [`EdgeMode::Crop`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{EdgeMode, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.edge_mode(EdgeMode::Pad);
+    /// ```
+    => edge_mode, EdgeMode
+    }
+
+    property! {
+    /// Set if the columns of the image should be output as rows
+    ///
+    /// The text itself is transposed after the characters have been calculated, so reading the output from top to bottom
+    /// walks the image from left to right. Unlike flipping or rotating, the characters are not calculated from a rotated image,
+    /// so the aspect ratio of the characters is kept for the source image.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.transpose(true);
+    /// ```
+    => transpose, bool
+    }
+
+    property! {
+    /// Set the width and height of the gaussian kernel, which blurs the image for the outline and sharpening
+    ///
+    /// Larger kernels blur the image stronger, which results in smoother outlines and a stronger sharpening.
+    /// The size has to be odd, since the kernel needs a center, [`try_convert`](crate::try_convert) returns an error otherwise.
+    /// Defaults to 3.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.kernel_size(5);
+    /// ```
+    => kernel_size, u32
+    }
+
+    /// Convert every character to a braille pattern, with a dot for each bright part of the character
+    ///
+    /// Every character is split into a 2x4 grid of sub-pixels, each sub-pixel brighter than the `threshold` is shown as a dot,
+    /// or the darker ones, when inverting the image. This shows much finer details than the characters,
+    /// especially for outlines, since they only contain black and white. 128 is a good threshold for most images.
+    /// Defaults to `None`, which uses the characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.braille(128);
+    /// ```
+    pub fn braille(&mut self, threshold: u8) -> &mut Self {
+        self.braille = Some(threshold);
+        self
+    }
+
+    property! {
+    /// Set if the image should be output as upper half blocks, which doubles the vertical resolution
+    ///
+    /// Every character is an upper half block (`▀`), where the foreground is the color of the top half of the tile
+    /// and the background is the color of the bottom half. If the tile height is odd, the bottom half is a pixel smaller.
+    /// The colors are inverted, when inverting the image.
+    /// The half blocks are only used for the colored shell and ansi files, since they need colors,
+    /// other targets are converted normally. The border, the ruler and the other decorations are not added.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.half_blocks(true);
+    /// ```
+    => half_blocks, bool
+    }
+
+    property! {
+    /// Dither the luminosity of the characters
+    ///
+    /// The luminosity of each character is rounded to the closest character, and the difference is spread to the
+    /// neighboring characters (Floyd–Steinberg dithering). This keeps tonal gradients, even when only a few characters are used.
+    /// It can not be combined with the outline, converting with both returns an error. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.dither(true);
+    /// ```
+    => dither, bool
+    }
+
+    property! {
+    /// Set the weights of the color channels, which are used to calculate the luminosity
+    ///
+    /// The luminosity selects the characters, it is also used for the grayscale image of the outline.
+    /// Presets for common standards are available as associated constants. Defaults to [`LuminanceWeights::DEFAULT`].
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::{LuminanceWeights, OptionBuilder};
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.luminance_weights(LuminanceWeights::REC_709);
+    /// ```
+    => luminance_weights, LuminanceWeights
+    }
+
+    property! {
+    /// Set the color, which the transparent pixels are blended with
+    ///
+    /// Transparent parts of the image are resolved to this color before the characters are selected, so they do not turn black.
+    /// Defaults to white.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    /// use image::Rgb;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// builder.background_fill(Rgb([0, 0, 0]));
+    /// ```
+    => background_fill, Rgb<u8>
+    }
+
+    ///Build the [`Option`] struct.
+    ///
+    /// This returns a [`Option`], which can than be used for the image conversion using [`convert()`].
+    /// If values are not explicitly specified, the default values will be used.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::options::OptionBuilder;
+    ///
+    /// let mut builder = OptionBuilder::new();
+    /// let options = builder.build();
+    /// ```
+    pub fn build(&self) -> Option {
+        Option {
+            characters: if self.pad_characters && self.characters.chars().count() == 1 {
+                format!("{} ", self.characters)
+            } else {
+                self.characters.to_owned()
+            },
+            scale: self.scale,
+            target_size: self.target_size,
+            invert: self.invert,
+            border: self.border,
+            dimension: self.dimension,
+            transform_x: self.transform_x,
+            transform_y: self.transform_y,
+            anchor_x: self.anchor_x,
+            anchor_y: self.anchor_y,
+            outline: self.outline,
+            hysteresis: self.hysteresis,
+            target: self.target,
+            sharpen: self.sharpen,
+            color_mode: self.color_mode,
+            clear_screen: self.clear_screen,
+            backdrop: self.backdrop,
+            color_quantize_step: self.color_quantize_step,
+            min_color_luminance: self.min_color_luminance,
+            smooth_horizontal: self.smooth_horizontal,
+            palette: self.palette.clone(),
+            color_dither: self.color_dither,
+            hysteresis_gamma: self.hysteresis_gamma,
+            ruler: self.ruler,
+            edge_color_only: self.edge_color_only,
+            line_height: self.line_height,
+            auto_char_scale: self.auto_char_scale,
+            tone_map: self.tone_map,
+            color_border: self.color_border,
+            ramp_cycle: self.ramp_cycle.clone(),
+            border_inside: self.border_inside,
+            web_safe_colors: self.web_safe_colors,
+            density_channel: self.density_channel,
+            comment_prefix: self.comment_prefix.clone(),
+            auto_invert: self.auto_invert,
+            html_color_format: self.html_color_format,
+            legend: self.legend,
+            tile_source: self.tile_source,
+            rounding: self.rounding,
+            fixed_color: self.fixed_color,
+            linear_downscale: self.linear_downscale,
+            max_display_width: self.max_display_width,
+            ansi_tie_break: self.ansi_tie_break,
+            auto_rotate: self.auto_rotate,
+            luminance_lut: self.luminance_lut.clone(),
+            sauce: self.sauce.clone(),
+            pattern_match: self.pattern_match,
+            html_fragment: self.html_fragment,
+            outline_normalize: self.outline_normalize,
+            pixel_art: self.pixel_art,
+            equalize: self.equalize,
+            max_color_switches_per_line: self.max_color_switches_per_line,
+            color_distance: self.color_distance,
+            min_contrast: self.min_contrast,
+            check_width: self.check_width,
+            edge_mode: self.edge_mode,
+            transpose: self.transpose,
+            kernel_size: self.kernel_size,
+            half_blocks: self.half_blocks,
+            dither: self.dither,
+            luminance_weights: self.luminance_weights,
+            background_fill: self.background_fill,
+            braille: self.braille,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_conversion_option_builder {
+    use super::*;
+
+    #[test]
+    fn default_characters() {
+        assert_eq!(
+            pixel::DEFAULT_CHARACTERS,
+            OptionBuilder::new().build().characters
+        );
+    }
+
+    #[test]
+    fn build_default() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().build()
+        );
+    }
+
+    #[test]
+    fn change_characters() {
+        assert_eq!(
+            Option {
+                characters: r#"characters"#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .characters("characters".to_string())
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_scale() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 3.14f32, //change attribute
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().scale(3.14f32).build()
+        );
+    }
+
+    #[test]
+    fn change_target_size() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 314, //change attribute
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .target_size(NonZeroU32::new(314).unwrap())
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_invert() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: true, //change attribute
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().invert(true).build()
+        );
+    }
+
+    #[test]
+    fn change_border() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: true, //change attribute
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().border(true).build()
+        );
+    }
+
+    #[test]
+    fn change_dimension() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Height, //change attribute
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .dimension(util::ResizingDimension::Height)
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_transform_x() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: true, //change attribute
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().transform_x(true).build()
+        );
+    }
+
+    #[test]
+    fn change_transform_y() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: true, //change attribute
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().transform_y(true).build()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn change_center_x() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Center, //change attribute
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().center_x(true).build()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn change_center_y() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Center, //change attribute
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().center_y(true).build()
+        );
+    }
+
+    #[test]
+    fn change_outline() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: true, //change attribute
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().outline(true).build()
+        );
+    }
+
+    #[test]
+    fn change_hysteresis() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: true, //change attribute
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().hysteresis(true).build()
+        );
+    }
+
+    #[test]
+    fn change_file_type() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::AnsiFile(false), //change attribute
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .target(TargetType::AnsiFile(false))
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_sharpen() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 1.5f32, //change attribute
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().sharpen(1.5f32).build()
+        );
+    }
+
+    #[test]
+    fn change_color_mode() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::Grayscale256, //change attribute
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .color_mode(ColorMode::Grayscale256)
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_clear_screen() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: true, //change attribute
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().clear_screen(true).build()
+        );
+    }
+
+    #[test]
+    fn change_backdrop() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: Some([30, 30, 30]), //change attribute
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().backdrop(Some([30, 30, 30])).build()
+        );
+    }
+
+    #[test]
+    fn change_anchor_x() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::End, //change attribute
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().anchor_x(HorizontalAnchor::End).build()
+        );
+    }
+
+    #[test]
+    fn change_anchor_y() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::End, //change attribute
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().anchor_y(VerticalAnchor::End).build()
+        );
+    }
+
+    #[test]
+    fn change_color_quantize_step() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: Some(16), //change attribute
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().color_quantize_step(Some(16)).build()
+        );
+    }
+
+    #[test]
+    fn change_min_color_luminance() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 64f32, //change attribute
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().min_color_luminance(64f32).build()
+        );
+    }
+
+    #[test]
+    fn change_smooth_horizontal() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: true, //change attribute
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().smooth_horizontal(true).build()
+        );
+    }
+
+    #[test]
+    fn change_palette() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: Some(vec![[0, 0, 0], [255, 255, 255]]), //change attribute
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .palette(Some(vec![[0, 0, 0], [255, 255, 255]]))
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_color_dither() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: true, //change attribute
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().color_dither(true).build()
+        );
+    }
+
+    #[test]
+    fn change_hysteresis_gamma() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 2.2, //change attribute
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().hysteresis_gamma(2.2).build()
+        );
+    }
+
+    #[test]
+    fn change_ruler() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: true, //change attribute
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().ruler(true).build()
+        );
+    }
+
+    #[test]
+    fn change_edge_color_only() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: true, //change attribute
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().edge_color_only(true).build()
+        );
+    }
+
+    #[test]
+    fn change_line_height() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: Some(0.8), //change attribute
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().line_height(Some(0.8)).build()
+        );
+    }
+
+    #[test]
+    fn change_auto_char_scale() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: true, //change attribute
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().auto_char_scale(true).build()
+        );
+    }
+
+    #[test]
+    fn change_tone_map() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: true, //change attribute
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().tone_map(true).build()
+        );
+    }
+
+    #[test]
+    fn change_color_border() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: true, //change attribute
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().color_border(true).build()
+        );
+    }
+
+    #[test]
+    fn change_ramp_cycle() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: vec!["#k. ".to_string()], //change attribute
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .ramp_cycle(vec!["#k. ".to_string()])
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_border_inside() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: false, //change attribute
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().border_inside(false).build()
+        );
+    }
+
+    #[test]
+    fn change_web_safe_colors() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: true, //change attribute
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().web_safe_colors(true).build()
+        );
+    }
+
+    #[test]
+    fn change_density_channel() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Red, //change attribute
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .density_channel(DensityChannel::Red)
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_comment_prefix() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: Some("// ".to_string()), //change attribute
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .comment_prefix(Some("// ".to_string()))
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_auto_invert() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: true, //change attribute
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().auto_invert(true).build()
+        );
+    }
+
+    #[test]
+    fn change_html_color_format() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hsl, //change attribute
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .html_color_format(HtmlColorFormat::Hsl)
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_legend() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: true, //change attribute
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().legend(true).build()
+        );
+    }
+
+    #[test]
+    fn change_tile_source() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: true, //change attribute
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().tile_source(true).build()
+        );
+    }
+
+    #[test]
+    fn change_rounding() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Round, //change attribute
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().rounding(IndexRounding::Round).build()
+        );
+    }
+
+    #[test]
+    fn change_fixed_color() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: Some([0, 255, 0]), //change attribute
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().fixed_color(Some([0, 255, 0])).build()
+        );
+    }
+
+    #[test]
+    fn change_linear_downscale() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: true, //change attribute
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().linear_downscale(true).build()
+        );
+    }
+
+    #[test]
+    fn change_max_display_width() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: Some(60), //change attribute
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().max_display_width(Some(60)).build()
+        );
+    }
+
+    #[test]
+    fn change_ansi_tie_break() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Bright, //change attribute
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .ansi_tie_break(AnsiTieBreak::Bright)
+                .build()
+        );
+    }
+
+    #[test]
+    fn change_auto_rotate() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: true, //change attribute
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().auto_rotate(true).build()
+        );
+    }
+
+    #[test]
+    fn change_luminance_lut() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: Some(Box::new([0; 256])), //change attribute
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .luminance_lut(Some(Box::new([0; 256])))
+                .build()
+        );
     }
 
-    property! {
-    ///Convert the image to it's outline
-    ///
-    /// This will use gaussian blur and sobel operators to only it's outline,
-    /// it might not produce the best result on all images.
-    /// Caution, this will take some additional time.
-    /// Defaults to false.
-    ///
-    /// # Examples
-    /// ```
-    /// use artem::options::OptionBuilder;
-    ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.outline(true);
-    /// ```
-    => outline, bool
+    #[test]
+    fn change_sauce() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: Some(SauceInfo::default()), //change attribute
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new()
+                .sauce(Some(SauceInfo::default()))
+                .build()
+        );
     }
 
-    property! {
-    /// When converting the image to an outline, also use double threshold and hysteresis
-    ///
-    /// It will remove small imperfection in the outline for the cost of smaller/thinner lines, which can make
-    /// the resulting ascii looking less visible, which might not be desired.
-    ///
-    /// It will only be used when outlining is set to true.
-    /// # Examples
-    /// ```
-    /// use artem::options::OptionBuilder;
-    ///
-    /// let mut builder = OptionBuilder::new();
-    /// builder.hysteresis(true);
-    /// ```
-    => hysteresis, bool
+    #[test]
+    fn pad_single_character() {
+        let mut builder = OptionBuilder::new();
+        builder.characters("#".to_string());
+        builder.pad_characters(true);
+        assert_eq!("# ", builder.build().characters);
     }
 
-    property! {
-    ///Set the target type
-    ///
-    /// This will effect the output, for example if the [`TargetType`] is set to html,
-    /// the output will be the content of a Html-file.
-    ///
-    /// See [`TargetType`] for more information. It defaults to the shell as the target.
-    ///
-    /// # Examples
-    /// ```
-    /// use artem::options::OptionBuilder;
-    /// use artem::options::TargetType;
-    ///
-    /// let mut builder = OptionBuilder::new();
-    /// //use color, but don't color the background
-    /// builder.target(TargetType::HtmlFile(true, false));
-    /// ```
-        => target,  TargetType
+    #[test]
+    fn pad_keeps_multiple_characters() {
+        let mut builder = OptionBuilder::new();
+        builder.characters("#k".to_string());
+        builder.pad_characters(true);
+        assert_eq!("#k", builder.build().characters);
     }
 
-    ///Build the [`Option`] struct.
-    ///
-    /// This returns a [`Option`], which can than be used for the image conversion using [`convert()`].
-    /// If values are not explicitly specified, the default values will be used.
-    ///
-    /// # Examples
-    /// ```
-    /// use artem::options::OptionBuilder;
-    ///
-    /// let mut builder = OptionBuilder::new();
-    /// let options = builder.build();
-    /// ```
-    pub fn build(&self) -> Option {
-        Option {
-            characters: self.characters.to_owned(),
-            scale: self.scale,
-            target_size: self.target_size,
-            invert: self.invert,
-            border: self.border,
-            dimension: self.dimension,
-            transform_x: self.transform_x,
-            transform_y: self.transform_y,
-            center_x: self.center_x,
-            center_y: self.center_y,
-            outline: self.outline,
-            hysteresis: self.hysteresis,
-            target: self.target,
-        }
+    #[test]
+    fn single_character_without_padding() {
+        let mut builder = OptionBuilder::new();
+        builder.characters("#".to_string());
+        assert_eq!("#", builder.build().characters);
     }
-}
 
-#[cfg(test)]
-mod test_conversion_option_builder {
-    use super::*;
     #[test]
-    fn build_default() {
+    fn change_pattern_match() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -486,21 +5990,71 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: true, //change attribute
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().build()
+            OptionBuilder::new().pattern_match(true).build()
         );
     }
 
     #[test]
-    fn change_characters() {
+    fn change_html_fragment() {
         assert_eq!(
             Option {
-                characters: r#"characters"#.to_string(),
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
                 scale: 0.42f32,
                 target_size: 80,
                 invert: false,
@@ -508,110 +6062,358 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: true, //change attribute
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new()
-                .characters("characters".to_string())
-                .build()
+            OptionBuilder::new().html_fragment(true).build()
         );
     }
 
     #[test]
-    fn change_scale() {
+    fn change_outline_normalize() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
-                scale: 3.14f32, //change attribute
+                scale: 0.42f32,
                 target_size: 80,
                 invert: false,
                 border: false,
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: true, //change attribute
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().scale(3.14f32).build()
+            OptionBuilder::new().outline_normalize(true).build()
         );
     }
 
     #[test]
-    fn change_target_size() {
+    fn change_pixel_art() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
                 scale: 0.42f32,
-                target_size: 314, //change attribute
+                target_size: 80,
                 invert: false,
                 border: false,
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: true, //change attribute
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new()
-                .target_size(NonZeroU32::new(314).unwrap())
-                .build()
+            OptionBuilder::new().pixel_art(true).build()
         );
     }
 
     #[test]
-    fn change_invert() {
+    fn change_equalize() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
                 scale: 0.42f32,
                 target_size: 80,
-                invert: true, //change attribute
+                invert: false,
                 border: false,
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: true, //change attribute
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().invert(true).build()
+            OptionBuilder::new().equalize(true).build()
         );
     }
 
     #[test]
-    fn change_border() {
+    fn change_max_color_switches_per_line() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
                 scale: 0.42f32,
                 target_size: 80,
                 invert: false,
-                border: true, //change attribute
+                border: false,
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: Some(8), //change attribute
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().border(true).build()
+            OptionBuilder::new()
+                .max_color_switches_per_line(Some(8))
+                .build()
         );
     }
 
     #[test]
-    fn change_dimension() {
+    fn change_color_distance() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -619,23 +6421,73 @@ mod test_conversion_option_builder {
                 target_size: 80,
                 invert: false,
                 border: false,
-                dimension: util::ResizingDimension::Height, //change attribute
+                dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Lab, //change attribute
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
             OptionBuilder::new()
-                .dimension(util::ResizingDimension::Height)
+                .color_distance(ColorDistance::Lab)
                 .build()
         );
     }
 
     #[test]
-    fn change_transform_x() {
+    fn change_min_contrast() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -644,20 +6496,70 @@ mod test_conversion_option_builder {
                 invert: false,
                 border: false,
                 dimension: util::ResizingDimension::Width,
-                transform_x: true, //change attribute
+                transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 16f32, //change attribute
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().transform_x(true).build()
+            OptionBuilder::new().min_contrast(16f32).build()
         );
     }
 
     #[test]
-    fn change_transform_y() {
+    fn change_check_width() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -667,19 +6569,69 @@ mod test_conversion_option_builder {
                 border: false,
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
-                transform_y: true, //change attribute
-                center_x: false,
-                center_y: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: true, //change attribute
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().transform_y(true).build()
+            OptionBuilder::new().check_width(true).build()
         );
     }
 
     #[test]
-    fn change_center_x() {
+    fn change_edge_mode() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -690,18 +6642,68 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: true, //change attribute
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Pad, //change attribute
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().center_x(true).build()
+            OptionBuilder::new().edge_mode(EdgeMode::Pad).build()
         );
     }
 
     #[test]
-    fn change_center_y() {
+    fn change_transpose() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -712,18 +6714,68 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: true, //change attribute
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: true, //change attribute
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().center_y(true).build()
+            OptionBuilder::new().transpose(true).build()
         );
     }
 
     #[test]
-    fn change_outline() {
+    fn change_kernel_size() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -734,18 +6786,77 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
-                outline: true, //change attribute
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
                 hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 5, //change attribute
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().outline(true).build()
+            OptionBuilder::new().kernel_size(5).build()
         );
     }
 
     #[test]
-    fn change_hysteresis() {
+    fn even_kernel_size_is_rejected() {
+        let options = OptionBuilder::new().kernel_size(4).build();
+        assert_eq!(
+            Err(crate::error::ConvertError::EvenKernelSize(4)),
+            crate::try_convert(image::DynamicImage::new_rgb8(10, 10), options)
+        );
+    }
+
+    #[test]
+    fn change_half_blocks() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -756,18 +6867,68 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
-                hysteresis: true, //change attribute
+                hysteresis: false,
                 target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: true, //change attribute
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
-            OptionBuilder::new().hysteresis(true).build()
+            OptionBuilder::new().half_blocks(true).build()
         );
     }
 
     #[test]
-    fn change_file_type() {
+    fn change_dither() {
         assert_eq!(
             Option {
                 characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
@@ -778,15 +6939,380 @@ mod test_conversion_option_builder {
                 dimension: util::ResizingDimension::Width,
                 transform_x: false,
                 transform_y: false,
-                center_x: false,
-                center_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
                 outline: false,
                 hysteresis: false,
-                target: TargetType::AnsiFile(false), //change attribute
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: true, //change attribute
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
+            },
+            OptionBuilder::new().dither(true).build()
+        );
+    }
+
+    #[test]
+    fn change_luminance_weights() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::REC_601, //change attribute
+                background_fill: Rgb([255, 255, 255]),
+                braille: None,
             },
             OptionBuilder::new()
-                .target(TargetType::AnsiFile(false))
+                .luminance_weights(LuminanceWeights::REC_601)
                 .build()
         );
     }
+
+    #[test]
+    fn change_background_fill() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([0, 0, 0]), //change attribute
+                braille: None,
+            },
+            OptionBuilder::new().background_fill(Rgb([0, 0, 0])).build()
+        );
+    }
+
+    #[test]
+    fn change_braille() {
+        assert_eq!(
+            Option {
+                characters: r#"MWNXK0Okxdolc:;,'...   "#.to_string(),
+                scale: 0.42f32,
+                target_size: 80,
+                invert: false,
+                border: false,
+                dimension: util::ResizingDimension::Width,
+                transform_x: false,
+                transform_y: false,
+                anchor_x: HorizontalAnchor::Start,
+                anchor_y: VerticalAnchor::Start,
+                outline: false,
+                hysteresis: false,
+                target: TargetType::default(),
+                sharpen: 0f32,
+                color_mode: ColorMode::default(),
+                clear_screen: false,
+                backdrop: None,
+                color_quantize_step: None,
+                min_color_luminance: 0f32,
+                smooth_horizontal: false,
+                palette: None,
+                color_dither: false,
+                hysteresis_gamma: 1f32,
+                ruler: false,
+                edge_color_only: false,
+                line_height: None,
+                auto_char_scale: false,
+                tone_map: false,
+                color_border: false,
+                ramp_cycle: Vec::new(),
+                border_inside: true,
+                web_safe_colors: false,
+                density_channel: DensityChannel::Luminosity,
+                comment_prefix: None,
+                auto_invert: false,
+                html_color_format: HtmlColorFormat::Hex,
+                legend: false,
+                tile_source: false,
+                rounding: IndexRounding::Floor,
+                fixed_color: None,
+                linear_downscale: false,
+                max_display_width: None,
+                ansi_tie_break: AnsiTieBreak::Standard,
+                auto_rotate: false,
+                luminance_lut: None,
+                sauce: None,
+                pattern_match: false,
+                html_fragment: false,
+                outline_normalize: false,
+                pixel_art: false,
+                equalize: false,
+                max_color_switches_per_line: None,
+                color_distance: ColorDistance::Euclidean,
+                min_contrast: 0f32,
+                check_width: false,
+                edge_mode: EdgeMode::Crop,
+                transpose: false,
+                kernel_size: 3,
+                half_blocks: false,
+                dither: false,
+                luminance_weights: LuminanceWeights::default(),
+                background_fill: Rgb([255, 255, 255]),
+                braille: Some(100), //change attribute
+            },
+            OptionBuilder::new().braille(100).build()
+        );
+    }
+}
+
+/// Serialize the color of an [`Rgb`] as an array of its channels.
+#[cfg(feature = "serde")]
+mod serde_rgb {
+    use image::Rgb;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Rgb<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        color.0.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb<u8>, D::Error> {
+        <[u8; 3]>::deserialize(deserializer).map(Rgb)
+    }
+}
+
+/// Serialize the luminance lookup table as a sequence, since serde only supports arrays with up to 32 elements.
+#[cfg(feature = "serde")]
+mod serde_lut {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        lut: &std::option::Option<Box<[u8; 256]>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        lut.as_ref().map(|lut| &lut[..]).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<std::option::Option<Box<[u8; 256]>>, D::Error> {
+        match std::option::Option::<Vec<u8>>::deserialize(deserializer)? {
+            Some(values) => {
+                let len = values.len();
+                let lut: Box<[u8; 256]> = values
+                    .into_boxed_slice()
+                    .try_into()
+                    .map_err(|_| D::Error::invalid_length(len, &"256 values"))?;
+                Ok(Some(lut))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn toml_defaults() {
+        assert_eq!(OptionBuilder::new(), OptionBuilder::from_toml("").unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn toml_values() {
+        let builder = OptionBuilder::from_toml(
+            "target_size = 20\nscale = 0.5\ndimension = \"Height\"\nbackground_fill = [0, 0, 0]",
+        )
+        .unwrap();
+        let options = builder.build();
+        assert_eq!(20, options.target_size);
+        assert_eq!(0.5, options.scale);
+        assert_eq!(ResizingDimension::Height, options.dimension);
+        assert_eq!(Rgb([0, 0, 0]), options.background_fill);
+    }
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn toml_wrong_type() {
+        assert!(OptionBuilder::from_toml("border = 3").is_err());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut builder = OptionBuilder::new();
+        builder
+            .target(TargetType::HtmlFile(true, false))
+            .luminance_lut(Some(Box::new([7; 256])))
+            .palette(Some(vec![[1, 2, 3]]));
+        let options = builder.build();
+
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(options, serde_json::from_str::<Option>(&json).unwrap());
+        let json = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            builder,
+            serde_json::from_str::<OptionBuilder>(&json).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn short_lut() {
+        assert!(OptionBuilder::from_toml("luminance_lut = [1, 2, 3]").is_err());
+    }
 }
@@ -0,0 +1,299 @@
+use crate::{
+    options::ColorDistance,
+    pixel::{self, Cell},
+};
+
+/// Returns the squared distance between the two rgb colors.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!(3f32, distance([0f32; 3], [1f32; 3], ColorDistance::Euclidean));
+/// ```
+pub fn distance(a: [f32; 3], b: [f32; 3], metric: ColorDistance) -> f32 {
+    let (a, b, weights) = match metric {
+        ColorDistance::Euclidean => (a, b, [1f32; 3]),
+        ColorDistance::Weighted => (a, b, [0.3, 0.59, 0.11]),
+        ColorDistance::Lab => (to_lab(a), to_lab(b), [1f32; 3]),
+    };
+    a.iter()
+        .zip(b)
+        .zip(weights)
+        .map(|((a, b), weight)| weight * (a - b).powi(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod test_distance {
+    use super::*;
+
+    #[test]
+    fn euclidean() {
+        assert_eq!(
+            25f32,
+            distance([0f32; 3], [3f32, 4f32, 0f32], ColorDistance::Euclidean)
+        );
+    }
+
+    #[test]
+    fn weighted_green_is_larger() {
+        let red = distance([0f32; 3], [10f32, 0f32, 0f32], ColorDistance::Weighted);
+        let green = distance([0f32; 3], [0f32, 10f32, 0f32], ColorDistance::Weighted);
+        assert!(green > red);
+    }
+
+    #[test]
+    fn lab_same_color() {
+        assert_eq!(0f32, distance([50f32; 3], [50f32; 3], ColorDistance::Lab));
+    }
+}
+
+/// Convert the srgb color (0 - 255) to the CIELAB color space, using the D65 white point.
+fn to_lab(color: [f32; 3]) -> [f32; 3] {
+    let [red, green, blue] =
+        color.map(|value| pixel::srgb_to_linear((value / 255f32).clamp(0f32, 1f32)));
+    //relative to the white point
+    let x = (0.4124 * red + 0.3576 * green + 0.1805 * blue) / 0.95047;
+    let y = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+    let z = (0.0193 * red + 0.1192 * green + 0.9505 * blue) / 1.08883;
+
+    let f = |t: f32| {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16f32 / 116f32
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116f32 * fy - 16f32, 500f32 * (fx - fy), 200f32 * (fy - fz)]
+}
+
+#[cfg(test)]
+mod test_to_lab {
+    use super::*;
+
+    #[test]
+    fn black_and_white() {
+        assert!(to_lab([0f32; 3])[0].abs() < 0.01);
+        assert!((to_lab([255f32; 3])[0] - 100f32).abs() < 0.01);
+    }
+
+    #[test]
+    fn gray_has_no_color() {
+        let [_, a, b] = to_lab([128f32; 3]);
+        assert!(a.abs() < 0.01 && b.abs() < 0.01);
+    }
+}
+
+/// Returns the color of the palette, which is closest to the given color.
+///
+/// The closest color is found using the `metric`. If the palette is empty,
+/// the color is returned unchanged.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!([255, 255, 255], nearest([200, 200, 200], &[[0, 0, 0], [255, 255, 255]], ColorDistance::Euclidean));
+/// ```
+pub fn nearest(color: [f32; 3], palette: &[[u8; 3]], metric: ColorDistance) -> [u8; 3] {
+    let distance = |entry: &&[u8; 3]| distance(entry.map(|channel| channel as f32), color, metric);
+
+    match palette.iter().min_by(|a, b| {
+        distance(a)
+            .partial_cmp(&distance(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    }) {
+        Some(entry) => *entry,
+        None => color.map(|value| value.round().clamp(0f32, 255f32) as u8),
+    }
+}
+
+#[cfg(test)]
+mod test_nearest {
+    use super::*;
+
+    #[test]
+    fn closest_color() {
+        assert_eq!(
+            [255, 255, 255],
+            nearest(
+                [200f32, 200f32, 200f32],
+                &[[0, 0, 0], [255, 255, 255]],
+                ColorDistance::Euclidean
+            )
+        );
+        assert_eq!(
+            [255, 0, 0],
+            nearest(
+                [150f32, 60f32, 10f32],
+                &[[0, 0, 0], [255, 0, 0]],
+                ColorDistance::Euclidean
+            )
+        );
+    }
+
+    #[test]
+    fn empty_palette_unchanged() {
+        assert_eq!(
+            [1, 2, 3],
+            nearest([1f32, 2f32, 3f32], &[], ColorDistance::Euclidean)
+        );
+    }
+
+    #[test]
+    fn weighted_green_snaps_differently() {
+        //the plain distance prefers the entry with the same red and blue, the weighted one the entry with the same green
+        let palette = [[0, 140, 0], [60, 200, 60]];
+        let green = [0f32, 200f32, 0f32];
+        assert_eq!(
+            [0, 140, 0],
+            nearest(green, &palette, ColorDistance::Euclidean)
+        );
+        assert_eq!(
+            [60, 200, 60],
+            nearest(green, &palette, ColorDistance::Weighted)
+        );
+    }
+}
+
+/// Replace the color of every cell with the closest color of the palette.
+///
+/// The luminosity of the cells is not changed, so the characters stay the same.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// reduce(&mut cells, &[[0, 0, 0], [255, 255, 255]], ColorDistance::Euclidean);
+/// ```
+pub fn reduce(cells: &mut [Cell], palette: &[[u8; 3]], metric: ColorDistance) {
+    for cell in cells {
+        set_color(
+            cell,
+            nearest(
+                [cell.red as f32, cell.green as f32, cell.blue as f32],
+                palette,
+                metric,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_reduce {
+    use super::*;
+
+    #[test]
+    fn colors_are_replaced() {
+        let mut cells = [gray(10), gray(200)];
+        reduce(
+            &mut cells,
+            &[[0, 0, 0], [255, 255, 255]],
+            ColorDistance::Euclidean,
+        );
+        assert_eq!([0, 255], [cells[0].red, cells[1].red]);
+        //the luminosity is not changed
+        assert_eq!(200f32, cells[1].luminosity);
+    }
+}
+
+/// Replace the color of every cell with a color of the palette, using Floyd–Steinberg dithering.
+///
+/// The cells are a grid with the given number of columns. The difference between the original color
+/// and the palette color is spread onto the neighboring cells, so a gradient is represented by a mix
+/// of the palette colors, instead of hard bands.
+/// The luminosity of the cells is not changed, so the characters stay the same.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// dither(&mut cells, 80, &[[0, 0, 0], [255, 255, 255]], ColorDistance::Euclidean);
+/// ```
+pub fn dither(cells: &mut [Cell], columns: usize, palette: &[[u8; 3]], metric: ColorDistance) {
+    if columns == 0 {
+        return;
+    }
+
+    //the colors including the diffused error
+    let mut colors: Vec<[f32; 3]> = cells
+        .iter()
+        .map(|cell| [cell.red as f32, cell.green as f32, cell.blue as f32])
+        .collect();
+
+    for index in 0..cells.len() {
+        let color = colors[index];
+        let new_color = nearest(color, palette, metric);
+        set_color(&mut cells[index], new_color);
+
+        let (x, y) = (index % columns, index / columns);
+        let mut diffuse = |dx: isize, dy: usize, factor: f32| {
+            let nx = x as isize + dx;
+            if nx < 0 || nx as usize >= columns {
+                return;
+            }
+            if let Some(target) = colors.get_mut((y + dy) * columns + nx as usize) {
+                for channel in 0..3 {
+                    target[channel] += (color[channel] - new_color[channel] as f32) * factor;
+                }
+            }
+        };
+
+        diffuse(1, 0, 7f32 / 16f32);
+        diffuse(-1, 1, 3f32 / 16f32);
+        diffuse(0, 1, 5f32 / 16f32);
+        diffuse(1, 1, 1f32 / 16f32);
+    }
+}
+
+#[cfg(test)]
+mod test_dither {
+    use super::*;
+
+    /// Count how often the color changes between neighbors.
+    fn transitions(cells: &[Cell]) -> usize {
+        cells
+            .windows(2)
+            .filter(|pair| pair[0].red != pair[1].red)
+            .count()
+    }
+
+    #[test]
+    fn gradient_is_interleaved() {
+        let palette = [[0, 0, 0], [255, 255, 255]];
+        let gradient: Vec<Cell> = (0..32u8).map(|value| gray(value * 8)).collect();
+
+        let mut banded = gradient.clone();
+        reduce(&mut banded, &palette, ColorDistance::Euclidean);
+        //only a single hard edge in the middle
+        assert_eq!(1, transitions(&banded));
+
+        let mut dithered = gradient;
+        dither(&mut dithered, 32, &palette, ColorDistance::Euclidean);
+        //only palette colors are used
+        assert!(dithered.iter().all(|cell| cell.red == 0 || cell.red == 255));
+        //the colors are mixed
+        assert!(transitions(&dithered) > 4);
+    }
+
+    #[test]
+    fn error_stays_in_grid() {
+        let palette = [[0, 0, 0], [255, 255, 255]];
+        let mut cells = vec![gray(128); 6];
+        dither(&mut cells, 3, &palette, ColorDistance::Euclidean);
+        assert!(cells.iter().all(|cell| cell.red == 0 || cell.red == 255));
+    }
+}
+
+/// Set the color of the cell, without changing the luminosity.
+fn set_color(cell: &mut Cell, color: [u8; 3]) {
+    cell.red = color[0];
+    cell.green = color[1];
+    cell.blue = color[2];
+}
+
+/// Create a gray cell for testing.
+#[cfg(test)]
+fn gray(value: u8) -> Cell {
+    Cell {
+        red: value,
+        green: value,
+        blue: value,
+        alpha: 255,
+        luminosity: value as f32,
+    }
+}
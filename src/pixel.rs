@@ -1,14 +1,135 @@
-use image::Rgba;
+use image::{Rgb, Rgba};
 
-use crate::{options, target, util};
+use crate::{options, palette, target, util};
 
-/// Convert a pixel block to a char (as a String) from the given density string.
+/// Color, opacity and luminosity of a single tile of the image.
+///
+/// The luminosity is calculated from the color, but can be changed afterwards,
+/// for example when smoothing, without changing the color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+    pub luminosity: f32,
+}
+
+impl Cell {
+    /// Create a cell from the average color of the pixel block.
+    ///
+    /// A block of a single pixel uses the exact color of that pixel, without averaging it.
+    /// The luminosity is calculated using the given weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use artem::{options::LuminanceWeights, pixel::Cell};
+    /// use image::Rgba;
+    ///
+    /// let cell = Cell::from_block(&[Rgba::<u8>::from([0, 0, 0, 255])], LuminanceWeights::DEFAULT);
+    /// assert_eq!(0f32, cell.luminosity);
+    /// ```
+    pub fn from_block(block: &[Rgba<u8>], weights: options::LuminanceWeights) -> Cell {
+        let (red, green, blue) = match block {
+            //there is nothing to average for 1:1 tiles
+            [pixel] => (pixel.0[0], pixel.0[1], pixel.0[2]),
+            _ => average_color(block),
+        };
+        //the opacity is not perceived, so it is averaged linearly
+        let alpha =
+            block.iter().map(|pixel| pixel.0[3] as u32).sum::<u32>() / block.len().max(1) as u32;
+        Cell {
+            red,
+            green,
+            blue,
+            alpha: alpha as u8,
+            luminosity: luminosity(red, green, blue, weights),
+        }
+    }
+
+    /// Replace the color of the cell with a gray of the same luminosity.
+    ///
+    /// The luminosity of the cell is not changed, so the character stays the same.
+    /// The `weights` should be the ones, which were used to create the cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use artem::{options::LuminanceWeights, pixel::Cell};
+    /// use image::Rgba;
+    ///
+    /// let mut cell = Cell::from_block(&[Rgba::<u8>::from([255, 0, 0, 255])], LuminanceWeights::DEFAULT);
+    /// cell.desaturate(LuminanceWeights::DEFAULT);
+    /// assert_eq!((54, 54, 54), (cell.red, cell.green, cell.blue));
+    /// ```
+    pub fn desaturate(&mut self, weights: options::LuminanceWeights) {
+        let gray = luminosity(self.red, self.green, self.blue, weights).round() as u8;
+        self.red = gray;
+        self.green = gray;
+        self.blue = gray;
+    }
+}
+
+#[cfg(test)]
+mod test_cell {
+    use super::*;
+
+    #[test]
+    fn single_pixel_exact_color() {
+        let cell = Cell::from_block(
+            &[Rgba::<u8>::from([17, 128, 251, 255])],
+            options::LuminanceWeights::DEFAULT,
+        );
+        assert_eq!((17, 128, 251), (cell.red, cell.green, cell.blue));
+    }
+
+    #[test]
+    fn single_pixel_emits_exact_color() {
+        let options = options::OptionBuilder::new()
+            .target(options::TargetType::HtmlFile(true, false))
+            .build();
+        let mut output = String::new();
+        correlating_char(
+            &[Rgba::<u8>::from([17, 128, 251, 255])],
+            &options,
+            &mut output,
+        );
+        assert!(output.starts_with("<span style=\"color: #1180FB\">"));
+    }
+
+    #[test]
+    fn desaturate_keeps_luminosity() {
+        let mut cell = Cell::from_block(
+            &[Rgba::<u8>::from([154, 85, 54, 255])],
+            options::LuminanceWeights::DEFAULT,
+        );
+        let luminosity = cell.luminosity;
+        cell.desaturate(options::LuminanceWeights::DEFAULT);
+        assert_eq!((97, 97, 97), (cell.red, cell.green, cell.blue));
+        assert_eq!(luminosity, cell.luminosity);
+    }
+
+    #[test]
+    fn multiple_pixel_averaged() {
+        let cell = Cell::from_block(
+            &[
+                Rgba::<u8>::from([255, 0, 0, 255]),
+                Rgba::<u8>::from([0, 255, 0, 255]),
+            ],
+            options::LuminanceWeights::DEFAULT,
+        );
+        assert_eq!((180, 180, 0), (cell.red, cell.green, cell.blue));
+    }
+}
+
+/// Convert a pixel block to a char from the given density string and append it to the `output`.
 ///
 /// # Examples
 ///
 /// ```compile_fail, compile will fail, this is an internal example
 /// use image::Rgba;
-/// use artem::options::TargetType;
+/// use artem::options::{OptionBuilder, TargetType};
 ///
 /// //example pixels, use them from the directly if possible
 /// let pixels = vec![
@@ -16,59 +137,578 @@ use crate::{options, target, util};
 ///     Rgba::<u8>::from([0, 0, 0, 255]),
 /// ];
 ///
-/// assert_eq!(".", correlating_char(&pixels, "#k. ", false, TargetType::default()));
+/// let mut builder = OptionBuilder::new();
+/// builder.characters("#k. ".to_string());
+/// let mut output = String::new();
+/// correlating_char(&pixels, &builder.build(), &mut output);
+/// assert_eq!(".", output);
 /// ```
 ///
-/// To use color, use the `color` argument, if only the background should be colored, use the `on_background_color` arg instead.
+/// The characters, the target and the color mode are taken from the `options`.
+/// If the target supports it, the appended char will be colored, either the foreground or the background.
+/// Uncolored chars are pushed directly, so they do not need an extra allocation.
 ///
-/// The `invert` arg, inverts the mapping from pixel luminosity to density string.
-pub fn correlating_char(
-    block: &[Rgba<u8>],
-    density: &str,
-    invert: bool,
-    target: options::TargetType,
-) -> String {
-    let (red, green, blue) = average_color(block);
+/// The `invert` option, inverts the mapping from pixel luminosity to density string.
+pub fn correlating_char(block: &[Rgba<u8>], options: &options::Option, output: &mut String) {
+    push_cell(
+        &Cell::from_block(block, options.luminance_weights),
+        options,
+        output,
+    );
+}
+
+/// Short characters preset, ordered from the densest to the lightest.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::SHORT_CHARACTERS;
+///
+/// assert!(SHORT_CHARACTERS.ends_with(' '));
+/// ```
+pub const SHORT_CHARACTERS: &str = r#"Ñ@#W$9876543210?!abc;:+=-,._ "#;
+
+/// Flat characters preset, ordered from the densest to the lightest.
+///
+/// It is the density map of jp2a, which is used by default.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{DEFAULT_CHARACTERS, FLAT_CHARACTERS};
+///
+/// assert_eq!(DEFAULT_CHARACTERS, FLAT_CHARACTERS);
+/// ```
+pub const FLAT_CHARACTERS: &str = r#"MWNXK0Okxdolc:;,'...   "#;
+
+/// Long characters preset, ordered from the densest to the lightest.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::LONG_CHARACTERS;
+///
+/// assert!(LONG_CHARACTERS.starts_with('$'));
+/// ```
+pub const LONG_CHARACTERS: &str =
+    r#"$@B%8&WM#*oahkbdpqwmZO0QLCJUYXzcvunxrjft/\|()1{}[]?-_+~<>i!lI;:,"^`'. "#;
+
+/// Characters, which are used if no other characters are set.
+///
+/// # Examples
+///
+/// ```
+/// use artem::{options::OptionBuilder, pixel::DEFAULT_CHARACTERS};
+///
+/// assert_eq!(DEFAULT_CHARACTERS, OptionBuilder::new().build().characters);
+/// ```
+pub const DEFAULT_CHARACTERS: &str = FLAT_CHARACTERS;
+
+/// Braille patterns ordered from the most to the fewest dots, which can be used as the characters.
+///
+/// Each pattern has one dot less than the previous one, so the luminosity of a cell selects the number of dots,
+/// instead of comparing each dot to a threshold. This results in a grayscale ramp using only braille patterns.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::BRAILLE_RAMP;
+///
+/// assert_eq!(9, BRAILLE_RAMP.chars().count());
+/// ```
+pub const BRAILLE_RAMP: &str =
+    "\u{28FF}\u{28F7}\u{28E7}\u{28C7}\u{2847}\u{2807}\u{2803}\u{2801}\u{2800}";
+
+#[cfg(test)]
+mod test_braille_ramp {
+    use super::*;
+    use crate::options::{OptionBuilder, TargetType};
+
+    /// Return the number of dots of the braille char, which the gray cell is converted to.
+    fn dots(value: u8, invert: bool) -> u32 {
+        let mut builder = OptionBuilder::new();
+        builder.target(TargetType::File).invert(invert);
+        builder.characters(BRAILLE_RAMP.to_string());
+        let cell = Cell::from_block(
+            &[Rgba::<u8>::from([value, value, value, 255])],
+            options::LuminanceWeights::DEFAULT,
+        );
+        let mut output = String::new();
+        push_cell(&cell, &builder.build(), &mut output);
+        //the cells after the last char are converted to a space
+        match output.chars().next().unwrap() {
+            ' ' => 0,
+            char => (char as u32 - 0x2800).count_ones(),
+        }
+    }
+
+    #[test]
+    fn dots_decrease() {
+        let dots: Vec<u32> = BRAILLE_RAMP
+            .chars()
+            .map(|char| (char as u32 - 0x2800).count_ones())
+            .collect();
+        assert_eq!(vec![8, 7, 6, 5, 4, 3, 2, 1, 0], dots);
+    }
+
+    #[test]
+    fn mid_gray_half_dots() {
+        assert_eq!(4, dots(128, false));
+    }
+
+    #[test]
+    fn white_all_dots() {
+        assert_eq!(8, dots(255, false));
+    }
+
+    #[test]
+    fn black_no_dots() {
+        assert_eq!(0, dots(0, false));
+    }
+
+    #[test]
+    fn invert_black_all_dots() {
+        assert_eq!(8, dots(0, true));
+    }
+}
+
+/// Convert a cell to a char from the given density string and append it to the `output`.
+///
+/// The char is selected using the luminosity of the cell, the color of the cell is used for colored targets.
+/// See [`correlating_char`] for more information.
+pub fn push_cell(cell: &Cell, options: &options::Option, output: &mut String) {
+    push_cell_with_characters(cell, &options.characters, options, output);
+}
 
-    //calculate luminosity from avg. pixel color
-    let luminosity = luminosity(red, green, blue);
+/// Convert a cell to a char from the given `density` string and append it to the `output`.
+///
+/// This is the same as [`push_cell`], but uses the `density` instead of the characters of the `options`,
+/// for example the characters of a row.
+pub fn push_cell_with_characters(
+    cell: &Cell,
+    density: &str,
+    options: &options::Option,
+    output: &mut String,
+) {
+    //the value, which selects the char
+    let luminosity = match options.density_channel {
+        options::DensityChannel::Luminosity => cell.luminosity,
+        options::DensityChannel::Red => cell.red as f32,
+        options::DensityChannel::Green => cell.green as f32,
+        options::DensityChannel::Blue => cell.blue as f32,
+        //transparent cells use the lightest chars, like dark cells
+        options::DensityChannel::Alpha => cell.alpha as f32,
+    };
+    //the lookup table replaces the value with its own curve
+    let luminosity = match &options.luminance_lut {
+        Some(lut) => lut[luminosity.round().clamp(0f32, 255f32) as usize] as f32,
+        None => luminosity,
+    };
 
+    //the characters are counted, since they can consist of multiple bytes
+    let len = density.chars().count();
     //swap to range for white to black values
     //convert from rgb values (0 - 255) to the density string index (0 - string length)
     let density_index = util::map_range(
         (0f32, 255f32),
-        if invert {
-            (0f32, density.len() as f32)
+        if options.invert {
+            (0f32, len as f32)
         } else {
-            (density.len() as f32, 0f32)
+            (len as f32, 0f32)
         },
         luminosity,
-    )
-    .floor()
-    .clamp(0f32, density.len() as f32);
+    );
+    let density_index = round_index(density_index, options.rounding, len).clamp(0f32, len as f32);
 
     //get correct char from map, default to a space
     let density_char = density.chars().nth(density_index as usize).unwrap_or(' ');
 
-    //return the correctly formatted/colored string depending on the target
-    match target {
+    push_colored_char(density_char, cell, options, output);
+}
+
+/// Round the density index using the given rounding.
+///
+/// Rounding up could select the index after the last char, so the rounded index is limited to the last char.
+/// Rounding down keeps the previous behavior, where only the darkest value selects the index after the last char.
+///
+/// # Examples
+///
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!(3f32, round_index(2.6, IndexRounding::Round, 4));
+/// ```
+fn round_index(index: f32, rounding: options::IndexRounding, len: usize) -> f32 {
+    let last = len.saturating_sub(1) as f32;
+    match rounding {
+        options::IndexRounding::Floor => index.floor(),
+        options::IndexRounding::Round => index.round().min(last),
+        options::IndexRounding::Nearest => {
+            let rounded = index.round();
+            //halfway values are rounded to the even index
+            //the index is never negative, so rounding away from zero always rounds up
+            let rounded = if index.fract() == 0.5 && rounded % 2f32 != 0f32 {
+                rounded - 1f32
+            } else {
+                rounded
+            };
+            rounded.min(last)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_round_index {
+    use super::*;
+
+    #[test]
+    fn floor() {
+        assert_eq!(2f32, round_index(2.6, options::IndexRounding::Floor, 4));
+        assert_eq!(4f32, round_index(4.0, options::IndexRounding::Floor, 4));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(3f32, round_index(2.6, options::IndexRounding::Round, 4));
+        assert_eq!(3f32, round_index(2.5, options::IndexRounding::Round, 4));
+        assert_eq!(3f32, round_index(3.8, options::IndexRounding::Round, 4));
+    }
+
+    #[test]
+    fn nearest() {
+        assert_eq!(3f32, round_index(2.6, options::IndexRounding::Nearest, 4));
+        assert_eq!(2f32, round_index(2.5, options::IndexRounding::Nearest, 4));
+        assert_eq!(2f32, round_index(1.5, options::IndexRounding::Nearest, 4));
+    }
+}
+
+/// Glyphs with the 3x3 sub-pixels they cover.
+///
+/// Each pattern is written row by row, starting with the top left sub-pixel as the highest bit.
+const PATTERNS: [(char, u16); 15] = [
+    ('+', 0b010_111_010),
+    ('-', 0b000_111_000),
+    ('|', 0b010_010_010),
+    ('/', 0b001_010_100),
+    ('\\', 0b100_010_001),
+    ('x', 0b101_010_101),
+    ('_', 0b000_000_111),
+    ('.', 0b000_000_010),
+    ('\'', 0b010_000_000),
+    (':', 0b010_000_010),
+    ('=', 0b111_000_111),
+    ('L', 0b100_100_111),
+    ('T', 0b111_010_010),
+    ('[', 0b110_100_110),
+    ('o', 0b111_101_111),
+];
+
+/// Minimum difference between the luminosities of the sub-pixels, so the cell has a pattern.
+const PATTERN_CONTRAST: f32 = 32f32;
+
+/// Return the glyph, which resembles the pattern of the 3x3 sub-pixel luminosities best.
+///
+/// The luminosities are given row by row. Sub-pixels brighter than the middle of the darkest and the brightest one
+/// are covered by the glyph, or the darker ones when `invert` is used, like the dense characters.
+/// If the sub-pixels are too similar to form a pattern, `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::pattern_glyph;
+///
+/// let cross = [0f32, 255f32, 0f32, 255f32, 255f32, 255f32, 0f32, 255f32, 0f32];
+/// assert_eq!(Some('+'), pattern_glyph(&cross, false));
+/// ```
+pub fn pattern_glyph(luminosities: &[f32; 9], invert: bool) -> Option<char> {
+    let min = luminosities.iter().copied().fold(f32::MAX, f32::min);
+    let max = luminosities.iter().copied().fold(f32::MIN, f32::max);
+    if max - min < PATTERN_CONTRAST {
+        return None;
+    }
+
+    let middle = (min + max) / 2f32;
+    let pattern = luminosities.iter().fold(0u16, |pattern, luminosity| {
+        (pattern << 1) | ((*luminosity > middle) != invert) as u16
+    });
+
+    //the first glyph with the fewest differing sub-pixels is used
+    PATTERNS
+        .iter()
+        .min_by_key(|(_, glyph)| (glyph ^ pattern).count_ones())
+        .map(|(glyph, _)| *glyph)
+}
+
+#[cfg(test)]
+mod test_pattern_glyph {
+    use super::*;
+
+    /// Convert the covered sub-pixels, given as 1, to luminosities.
+    fn luminosities(pattern: [u8; 9]) -> [f32; 9] {
+        pattern.map(|covered| covered as f32 * 255f32)
+    }
+
+    #[test]
+    fn cross_is_plus() {
+        assert_eq!(
+            Some('+'),
+            pattern_glyph(&luminosities([0, 1, 0, 1, 1, 1, 0, 1, 0]), false)
+        );
+    }
+
+    #[test]
+    fn inverted_cross_is_plus() {
+        assert_eq!(
+            Some('+'),
+            pattern_glyph(&luminosities([1, 0, 1, 0, 0, 0, 1, 0, 1]), true)
+        );
+    }
+
+    #[test]
+    fn diagonal() {
+        assert_eq!(
+            Some('/'),
+            pattern_glyph(&luminosities([0, 0, 1, 0, 1, 0, 1, 0, 0]), false)
+        );
+    }
+
+    #[test]
+    fn closest_pattern() {
+        //a horizontal line with a missing end
+        assert_eq!(
+            Some('-'),
+            pattern_glyph(&luminosities([0, 0, 0, 1, 1, 0, 0, 0, 0]), false)
+        );
+    }
+
+    #[test]
+    fn flat_has_no_pattern() {
+        assert_eq!(None, pattern_glyph(&[100f32; 9], false));
+        assert_eq!(
+            None,
+            pattern_glyph(
+                &[100f32, 120f32, 110f32, 100f32, 100f32, 100f32, 100f32, 100f32, 100f32],
+                false
+            )
+        );
+    }
+}
+
+/// Bits of the braille dots, row by row for the 2x4 dot grid.
+///
+/// The dots of the first three rows are numbered by column, the dots of the last row were added later and use the highest bits.
+const BRAILLE_DOTS: [u32; 8] = [0x01, 0x08, 0x02, 0x10, 0x04, 0x20, 0x40, 0x80];
+
+/// Return the braille char, which has a dot for every sub-pixel brighter than the `threshold`.
+///
+/// The luminosities are the 2x4 sub-pixels of the cell, given row by row. Like the dense characters, bright sub-pixels
+/// are covered by a dot, or the darker ones when `invert` is used. Without any dots, the blank braille pattern (`U+2800`) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::braille_char;
+///
+/// let left_column = [255f32, 0f32, 255f32, 0f32, 255f32, 0f32, 255f32, 0f32];
+/// assert_eq!('⡇', braille_char(&left_column, 128, false));
+/// assert_eq!('⢸', braille_char(&left_column, 128, true));
+/// ```
+pub fn braille_char(luminosities: &[f32; 8], threshold: u8, invert: bool) -> char {
+    let pattern = luminosities
+        .iter()
+        .zip(BRAILLE_DOTS)
+        .filter(|(luminosity, _)| (**luminosity > threshold as f32) != invert)
+        .fold(0, |pattern, (_, dot)| pattern | dot);
+    char::from_u32(0x2800 + pattern).unwrap_or(' ')
+}
+
+#[cfg(test)]
+mod test_braille_char {
+    use super::*;
+
+    #[test]
+    fn blank() {
+        assert_eq!('\u{2800}', braille_char(&[0f32; 8], 128, false));
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!('\u{28FF}', braille_char(&[255f32; 8], 128, false));
+        assert_eq!('\u{28FF}', braille_char(&[0f32; 8], 128, true));
+    }
+
+    #[test]
+    fn bottom_row() {
+        let mut luminosities = [0f32; 8];
+        luminosities[6] = 200f32;
+        luminosities[7] = 200f32;
+        assert_eq!('⣀', braille_char(&luminosities, 128, false));
+    }
+
+    #[test]
+    fn threshold() {
+        let luminosities = [100f32; 8];
+        assert_eq!('\u{28FF}', braille_char(&luminosities, 99, false));
+        assert_eq!('\u{2800}', braille_char(&luminosities, 100, false));
+    }
+}
+
+/// Append the char to the `output`, colored with the color of the cell.
+///
+/// The color is only used if the target supports it, otherwise the char is pushed directly.
+/// This can be used for chars, which are not selected from the density string, for example the border.
+///
+/// # Examples
+///
+/// ```
+/// use artem::{options::{OptionBuilder, TargetType}, pixel::{push_colored_char, Cell}};
+///
+/// let cell = Cell { red: 255, green: 0, blue: 0, alpha: 255, luminosity: 53.55 };
+/// let mut output = String::new();
+/// push_colored_char('║', &cell, &OptionBuilder::new().target(TargetType::File).build(), &mut output);
+/// assert_eq!("║", output);
+/// ```
+pub fn push_colored_char(char: char, cell: &Cell, options: &options::Option, output: &mut String) {
+    let luminosity = cell.luminosity;
+
+    let [red, green, blue] = output_color(cell, options);
+
+    //append the correctly formatted/colored string depending on the target
+    match options.target {
         //if no color, use default case
-        options::TargetType::Shell(true, background_color) => {
-            target::ansi::colored_char(red, green, blue, density_char, background_color)
+        //ansi file is always colored
+        options::TargetType::Shell(true, background_color)
+        | options::TargetType::AnsiFile(background_color) => match options.color_mode {
+            options::ColorMode::Auto => output.push_str(&target::ansi::colored_char(
+                red,
+                green,
+                blue,
+                char,
+                background_color,
+                options.ansi_tie_break,
+            )),
+            options::ColorMode::Grayscale256 => output.push_str(&target::ansi::grayscale_char(
+                luminosity,
+                char,
+                background_color,
+            )),
+        },
+        options::TargetType::HtmlFile(true, background_color) => {
+            output.push_str(&target::html::colored_char(
+                red,
+                green,
+                blue,
+                char,
+                background_color,
+                options.html_color_format,
+            ))
         }
-        options::TargetType::AnsiFile(background_color) => {
-            //ansi file is always colored
-            target::ansi::colored_char(red, green, blue, density_char, background_color)
+        options::TargetType::BBCode => {
+            output.push_str(&target::bbcode::colored_char(red, green, blue, char))
         }
-        options::TargetType::HtmlFile(color, background_color) => {
-            if color {
-                target::html::colored_char(red, green, blue, density_char, background_color)
-            } else {
-                density_char.to_string()
+        //all other case, including a plain text file, html and shell without colors
+        _ => output.push(char),
+    }
+}
+
+/// Return the color of the cell, as it is used for the output.
+///
+/// The color is snapped and brightened as set in the `options`, so it matches the color of the char appended
+/// by [`push_colored_char`]. If a fixed color is set, it replaces the color of the cell.
+///
+/// # Examples
+///
+/// ```
+/// use artem::{options::OptionBuilder, pixel::{output_color, Cell}};
+///
+/// let cell = Cell { red: 100, green: 150, blue: 200, alpha: 255, luminosity: 140.0 };
+/// let options = OptionBuilder::new().web_safe_colors(true).build();
+/// assert_eq!([102, 153, 204], output_color(&cell, &options));
+/// ```
+pub fn output_color(cell: &Cell, options: &options::Option) -> [u8; 3] {
+    if let Some(color) = options.fixed_color {
+        //the fixed color is used as it is
+        return color;
+    }
+
+    //snap the colors, so similar tiles share the same color
+    let [red, green, blue] = match options.color_quantize_step {
+        Some(step) => snap(
+            [cell.red, cell.green, cell.blue],
+            step,
+            options.color_distance,
+        ),
+        None => [cell.red, cell.green, cell.blue],
+    };
+
+    //lift dark colors, so they are still visible on a dark background
+    let (red, green, blue) = brighten(
+        red,
+        green,
+        blue,
+        options.min_color_luminance,
+        options.luminance_weights,
+    );
+
+    //snap the colors to the web-safe colors, which are multiples of 51
+    if options.web_safe_colors {
+        web_safe([red, green, blue], options.color_distance)
+    } else {
+        [red, green, blue]
+    }
+}
+
+/// Snap the color to the closest color, where every channel is a multiple of the step.
+///
+/// With the euclidean distance each channel is rounded on its own, otherwise the closest of the surrounding
+/// multiples is found using the `metric`, since the channels are no longer independent.
+///
+/// # Examples
+///
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!([16, 8, 0], snap([13, 11, 0], 8, ColorDistance::Euclidean));
+/// ```
+pub(crate) fn snap(color: [u8; 3], step: u8, metric: options::ColorDistance) -> [u8; 3] {
+    if metric == options::ColorDistance::Euclidean || step <= 1 {
+        return color.map(|value| quantize(value, step));
+    }
+
+    //the multiples below and above each channel
+    let bounds = color.map(|value| {
+        let lower = value / step * step;
+        let upper = lower.checked_add(step).unwrap_or(lower);
+        [lower, upper]
+    });
+    let mut candidates = Vec::with_capacity(8);
+    for red in bounds[0] {
+        for green in bounds[1] {
+            for blue in bounds[2] {
+                candidates.push([red, green, blue]);
             }
         }
-        //all other case, including a plain text file and shell without colors
-        _ => density_char.to_string(),
+    }
+    palette::nearest(color.map(|value| value as f32), &candidates, metric)
+}
+
+#[cfg(test)]
+mod test_snap {
+    use super::*;
+    use crate::options::ColorDistance;
+
+    #[test]
+    fn euclidean_rounds_channels() {
+        assert_eq!([16, 8, 0], snap([13, 11, 0], 8, ColorDistance::Euclidean));
+    }
+
+    #[test]
+    fn multiples_of_step() {
+        for metric in [ColorDistance::Weighted, ColorDistance::Lab] {
+            let snapped = snap([13, 200, 99], 51, metric);
+            assert!(snapped.iter().all(|value| value % 51 == 0));
+        }
+    }
+
+    #[test]
+    fn step_one_unchanged() {
+        assert_eq!([13, 11, 0], snap([13, 11, 0], 1, ColorDistance::Lab));
     }
 }
 
@@ -78,16 +718,29 @@ mod test_pixel_density {
 
     use super::*;
 
+    /// Create options with the given characters, invert and target, all other options are the default.
+    fn test_options(density: &str, invert: bool, target: options::TargetType) -> options::Option {
+        let mut builder = options::OptionBuilder::new();
+        builder.invert(invert).target(target);
+        builder.characters(density.to_string());
+        builder.build()
+    }
+
+    /// Convert the pixels into a new string.
+    fn convert_block(pixels: &[Rgba<u8>], options: &options::Option) -> String {
+        let mut output = String::new();
+        correlating_char(pixels, options, &mut output);
+        output
+    }
+
     #[test]
     fn empty_returns_last_char() {
         let pixels: Vec<Rgba<u8>> = Vec::new();
         assert_eq!(
             " ",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "# ",
-                false,
-                options::TargetType::Shell(false, false)
+                &test_options("# ", false, options::TargetType::Shell(false, false))
             )
         );
     }
@@ -101,11 +754,9 @@ mod test_pixel_density {
         ];
         assert_eq!(
             " ",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "# ",
-                true,
-                options::TargetType::Shell(false, false)
+                &test_options("# ", true, options::TargetType::Shell(false, false))
             )
         );
     }
@@ -118,11 +769,9 @@ mod test_pixel_density {
         ];
         assert_eq!(
             "k",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::Shell(false, false)
+                &test_options("#k. ", false, options::TargetType::Shell(false, false))
             )
         );
     }
@@ -136,11 +785,9 @@ mod test_pixel_density {
         ];
         assert_eq!(
             "#",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::Shell(false, false)
+                &test_options("#k. ", false, options::TargetType::Shell(false, false))
             )
         );
     }
@@ -155,11 +802,9 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             "\u{1b}[38;2;0;0;255m \u{1b}[0m", //blue color
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::Shell(true, false)
+                &test_options("#k. ", false, options::TargetType::Shell(true, false))
             )
         );
     }
@@ -174,11 +819,9 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([123, 42, 244, 255])];
         assert_eq!(
             "\u{1b}[35m.\u{1b}[0m",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::Shell(true, false)
+                &test_options("#k. ", false, options::TargetType::Shell(true, false))
             )
         );
     }
@@ -192,7 +835,10 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([123, 42, 244, 255])];
         assert_eq!(
             "\u{1b}[35m.\u{1b}[0m",
-            correlating_char(&pixels, "#k. ", false, options::TargetType::AnsiFile(false))
+            convert_block(
+                &pixels,
+                &test_options("#k. ", false, options::TargetType::AnsiFile(false))
+            )
         );
     }
 
@@ -206,11 +852,9 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             "\u{1b}[48;2;0;0;255m \u{1b}[0m",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::Shell(true, true)
+                &test_options("#k. ", false, options::TargetType::Shell(true, true))
             )
         );
     }
@@ -224,8 +868,11 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             "\u{1b}[48;2;0;0;255m \u{1b}[0m",
-            correlating_char(&pixels, "#k. ", false, options::TargetType::AnsiFile(true))
-        );
+            convert_block(
+                &pixels,
+                &test_options("#k. ", false, options::TargetType::AnsiFile(true))
+            )
+        );
     }
 
     #[test]
@@ -237,7 +884,10 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             " ",
-            correlating_char(&pixels, "#k. ", false, options::TargetType::File)
+            convert_block(
+                &pixels,
+                &test_options("#k. ", false, options::TargetType::File)
+            )
         );
     }
 
@@ -248,13 +898,12 @@ mod test_pixel_density {
         env::set_var("CLICOLOR_FORCE", "1");
 
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
+        //the color of a space is not visible without a background
         assert_eq!(
-            "<span style=\"color: #0000FF\"> </span>",
-            correlating_char(
+            " ",
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::HtmlFile(true, false)
+                &test_options("#k. ", false, options::TargetType::HtmlFile(true, false))
             )
         );
     }
@@ -268,11 +917,9 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             "<span style=\"background-color: #0000FF\"> </span>",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::HtmlFile(true, true)
+                &test_options("#k. ", false, options::TargetType::HtmlFile(true, true))
             )
         );
     }
@@ -286,45 +933,1076 @@ mod test_pixel_density {
         let pixels = vec![Rgba::<u8>::from([0, 0, 255, 255])];
         assert_eq!(
             " ",
-            correlating_char(
+            convert_block(
                 &pixels,
-                "#k. ",
-                false,
-                options::TargetType::HtmlFile(false, false)
+                &test_options("#k. ", false, options::TargetType::HtmlFile(false, false))
             )
         );
     }
+
+    #[test]
+    fn target_bbcode_colored_string() {
+        let pixels = vec![Rgba::<u8>::from([100, 100, 100, 255])];
+        assert_eq!(
+            "[color=#646464].[/color]",
+            convert_block(
+                &pixels,
+                &test_options("#k. ", false, options::TargetType::BBCode)
+            )
+        );
+    }
+
+    #[test]
+    fn quantize_merges_similar_colors() {
+        let mut options = test_options("#k. ", false, options::TargetType::HtmlFile(true, false));
+        options.color_quantize_step = Some(16);
+        //the tiles differ slightly, but should share the same color
+        let first = convert_block(&[Rgba::<u8>::from([100, 150, 200, 255])], &options);
+        let second = convert_block(&[Rgba::<u8>::from([103, 146, 202, 255])], &options);
+        assert_eq!(first, second);
+        assert_eq!("<span style=\"color: #6090D0\">k</span>", first);
+    }
+
+    #[test]
+    fn density_channel_red() {
+        let mut options = test_options("#k. ", false, options::TargetType::File);
+        options.density_channel = options::DensityChannel::Red;
+        //only the red channel selects the char, the other channels are ignored
+        assert_eq!(
+            "#",
+            convert_block(&[Rgba::<u8>::from([255, 0, 0, 255])], &options)
+        );
+        assert_eq!(
+            " ",
+            convert_block(&[Rgba::<u8>::from([0, 255, 255, 255])], &options)
+        );
+        assert_eq!(
+            convert_block(&[Rgba::<u8>::from([128, 0, 0, 255])], &options),
+            convert_block(&[Rgba::<u8>::from([128, 255, 0, 255])], &options)
+        );
+    }
+
+    #[test]
+    fn density_channel_alpha() {
+        let mut options = test_options("#k. ", false, options::TargetType::File);
+        options.density_channel = options::DensityChannel::Alpha;
+        assert_eq!(
+            "#",
+            convert_block(&[Rgba::<u8>::from([255, 255, 255, 255])], &options)
+        );
+        assert_eq!(
+            " ",
+            convert_block(&[Rgba::<u8>::from([0, 0, 0, 0])], &options)
+        );
+    }
+
+    #[test]
+    fn rounding_selects_next_char() {
+        let mut options = test_options("abcd", false, options::TargetType::File);
+        let cell = Cell {
+            red: 89,
+            green: 89,
+            blue: 89,
+            alpha: 255,
+            luminosity: 89f32,
+        };
+
+        //the index is 2.6
+        let mut output = String::new();
+        push_cell(&cell, &options, &mut output);
+        assert_eq!("c", output);
+
+        options.rounding = options::IndexRounding::Round;
+        let mut output = String::new();
+        push_cell(&cell, &options, &mut output);
+        assert_eq!("d", output);
+    }
+
+    #[test]
+    fn fixed_color_replaces_color() {
+        let mut options = test_options("#k.:", false, options::TargetType::HtmlFile(true, false));
+        options.fixed_color = Some([0, 255, 0]);
+        for pixel in [[255, 0, 0, 255], [10, 20, 30, 255], [200, 200, 200, 255]] {
+            assert!(convert_block(&[Rgba::<u8>::from(pixel)], &options)
+                .starts_with("<span style=\"color: #00FF00\">"));
+        }
+    }
+
+    #[test]
+    fn web_safe_snaps_color() {
+        let mut options = test_options("#k. ", false, options::TargetType::HtmlFile(true, false));
+        options.web_safe_colors = true;
+        assert_eq!(
+            "<span style=\"color: #6699CC\">k</span>",
+            convert_block(&[Rgba::<u8>::from([100, 150, 200, 255])], &options)
+        );
+    }
+
+    #[test]
+    fn min_luminance_brightens_dark_tile() {
+        //use invert, so the dark tile is not a space
+        let mut options = test_options("#k. ", true, options::TargetType::HtmlFile(true, false));
+        options.min_color_luminance = 64f32;
+        //the char stays the same, only the color is lifted
+        assert_eq!(
+            "<span style=\"color: #404040\">#</span>",
+            convert_block(&[Rgba::<u8>::from([2, 2, 2, 255])], &options)
+        );
+    }
+
+    #[test]
+    fn grayscale_char_shell() {
+        let pixels = vec![Rgba::<u8>::from([128, 128, 128, 255])];
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        let mut options = test_options("#k. ", false, options::TargetType::Shell(true, false));
+        options.color_mode = options::ColorMode::Grayscale256;
+        assert_eq!("\x1b[38;5;244mk\x1b[0m", convert_block(&pixels, &options));
+    }
+}
+
+#[cfg(test)]
+mod test_luminance_lut {
+    use super::*;
+
+    /// Convert a gray cell with every luminosity to a string.
+    fn convert_grays(options: &options::Option) -> String {
+        let mut output = String::new();
+        for value in 0..=255u8 {
+            let cell = Cell {
+                red: value,
+                green: value,
+                blue: value,
+                alpha: 255,
+                luminosity: value as f32,
+            };
+            push_cell_with_characters(&cell, &options.characters, options, &mut output);
+        }
+        output
+    }
+
+    /// Create plain text options with the given invert and lookup table.
+    fn test_options(invert: bool, lut: std::option::Option<Box<[u8; 256]>>) -> options::Option {
+        let mut builder = options::OptionBuilder::new();
+        builder
+            .target(options::TargetType::File)
+            .invert(invert)
+            .luminance_lut(lut);
+        builder.build()
+    }
+
+    #[test]
+    fn identity_is_unchanged() {
+        let mut lut = [0u8; 256];
+        for (index, value) in lut.iter_mut().enumerate() {
+            *value = index as u8;
+        }
+        assert_eq!(
+            convert_grays(&test_options(false, None)),
+            convert_grays(&test_options(false, Some(Box::new(lut))))
+        );
+    }
+
+    #[test]
+    fn inverting_lut_matches_invert() {
+        let mut lut = [0u8; 256];
+        for (index, value) in lut.iter_mut().enumerate() {
+            *value = 255 - index as u8;
+        }
+        assert_eq!(
+            convert_grays(&test_options(true, None)),
+            convert_grays(&test_options(false, Some(Box::new(lut))))
+        );
+    }
+}
+
+/// Soften harsh jumps between horizontally adjacent cells.
+///
+/// The luminosity range is split into `levels` steps, one for each character. If two neighboring cells
+/// differ by more than one level, the brighter one is nudged half a level towards the darker one.
+/// Each cell is nudged at most once and the colors are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{smooth_horizontal, Cell};
+///
+/// let mut cells = [
+///     Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 0f32 },
+///     Cell { red: 255, green: 255, blue: 255, alpha: 255, luminosity: 255f32 },
+/// ];
+/// smooth_horizontal(&mut cells, 10);
+/// assert_eq!(242.25f32, cells[1].luminosity);
+/// ```
+pub fn smooth_horizontal(cells: &mut [Cell], levels: usize) {
+    if levels == 0 || cells.len() < 2 {
+        return;
+    }
+
+    let level_size = 255f32 / levels as f32;
+    let level = |cell: &Cell| (cell.luminosity / level_size).floor() as i64;
+
+    //first find all cells, so the nudges do not influence each other
+    let mut nudged = vec![false; cells.len()];
+    for index in 0..cells.len() - 1 {
+        let (left, right) = (&cells[index], &cells[index + 1]);
+        if (level(left) - level(right)).abs() > 1 {
+            let brighter = if left.luminosity > right.luminosity {
+                index
+            } else {
+                index + 1
+            };
+            nudged[brighter] = true;
+        }
+    }
+
+    for (cell, nudge) in cells.iter_mut().zip(nudged) {
+        if nudge {
+            cell.luminosity -= level_size / 2f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_smooth_horizontal {
+    use super::*;
+
+    fn gray(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: 255,
+            luminosity: value as f32,
+        }
+    }
+
+    #[test]
+    fn close_cells_unchanged() {
+        let mut cells = [gray(0), gray(30), gray(60)];
+        smooth_horizontal(&mut cells, 10);
+        assert_eq!([gray(0), gray(30), gray(60)], cells);
+    }
+
+    #[test]
+    fn gradient_is_softer() {
+        //a steep gradient, jumping two levels between each cell
+        let mut cells = [gray(0), gray(60), gray(120), gray(180)];
+        smooth_horizontal(&mut cells, 10);
+        let luminosity: Vec<f32> = cells.iter().map(|cell| cell.luminosity).collect();
+        assert_eq!(vec![0f32, 47.25, 107.25, 167.25], luminosity);
+        //the colors are not changed
+        assert_eq!(60, cells[1].red);
+    }
+}
+
+/// Threshold map of the ordered dithering, with the values 0 to 15 spread evenly over a 4x4 block.
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Dither the luminosity of the cells using an ordered (Bayer) dithering.
+///
+/// The cells are given row by row, with `columns` cells in each row. Each cell is offset by up to half a level,
+/// depending on its position in a repeating 4x4 pattern, so areas between two levels use a mix of both characters.
+/// The colors are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{ordered_dither, Cell};
+///
+/// let mut cells = [Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 100f32 }; 2];
+/// ordered_dither(&mut cells, 2, 10);
+/// assert!(cells[0].luminosity < cells[1].luminosity);
+/// ```
+pub fn ordered_dither(cells: &mut [Cell], columns: usize, levels: usize) {
+    if levels == 0 || columns == 0 {
+        return;
+    }
+
+    let level_size = 255f32 / levels as f32;
+    for (index, cell) in cells.iter_mut().enumerate() {
+        let threshold = BAYER_MATRIX[(index / columns) % 4][(index % columns) % 4] as f32;
+        //center the thresholds around 0, so the average luminosity stays the same
+        let offset = ((threshold + 0.5) / 16f32 - 0.5) * level_size;
+        cell.luminosity = (cell.luminosity + offset).clamp(0f32, 255f32);
+    }
+}
+
+#[cfg(test)]
+mod test_ordered_dither {
+    use super::*;
+
+    fn gray(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: 255,
+            luminosity: value as f32,
+        }
+    }
+
+    #[test]
+    fn offset_is_at_most_half_a_level() {
+        let mut cells = [gray(128); 16];
+        ordered_dither(&mut cells, 4, 10);
+        assert!(cells
+            .iter()
+            .all(|cell| (cell.luminosity - 128f32).abs() <= 12.75));
+    }
+
+    #[test]
+    fn average_is_kept() {
+        let mut cells = [gray(128); 16];
+        ordered_dither(&mut cells, 4, 10);
+        let average = cells.iter().map(|cell| cell.luminosity).sum::<f32>() / 16f32;
+        assert!((average - 128f32).abs() < 0.01);
+    }
+
+    #[test]
+    fn colors_unchanged() {
+        let mut cells = [gray(128); 4];
+        ordered_dither(&mut cells, 2, 10);
+        assert!(cells.iter().all(|cell| cell.red == 128));
+    }
+
+    #[test]
+    fn luminosity_is_clamped() {
+        let mut cells = [gray(255); 4];
+        ordered_dither(&mut cells, 2, 10);
+        assert!(cells.iter().all(|cell| cell.luminosity <= 255f32));
+    }
+}
+
+/// Dither the luminosity of the cells using Floyd–Steinberg dithering.
+///
+/// The luminosity range is split into `levels` steps, one for each character. The cells are a grid with the given number of columns,
+/// which is processed row by row. The luminosity of each cell is set to the middle of its closest level and the difference is spread
+/// onto the following cells, so gradients are represented by a mix of neighboring characters.
+/// The colors are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{error_diffusion, Cell};
+///
+/// let mut cells = [Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 120f32 }; 4];
+/// error_diffusion(&mut cells, 2, 2);
+/// assert_eq!(63.75, cells[0].luminosity);
+/// assert_eq!(191.25, cells[1].luminosity);
+/// ```
+pub fn error_diffusion(cells: &mut [Cell], columns: usize, levels: usize) {
+    if levels == 0 || columns == 0 {
+        return;
+    }
+
+    let level_size = 255f32 / levels as f32;
+    //the luminosity including the diffused error
+    let mut luminosities: Vec<f32> = cells.iter().map(|cell| cell.luminosity).collect();
+
+    for index in 0..cells.len() {
+        let luminosity = luminosities[index];
+        let level = (luminosity / level_size)
+            .floor()
+            .clamp(0f32, levels as f32 - 1f32);
+        let new_luminosity = (level + 0.5) * level_size;
+        cells[index].luminosity = new_luminosity;
+
+        let error = luminosity - new_luminosity;
+        let (x, y) = (index % columns, index / columns);
+        let mut diffuse = |dx: isize, dy: usize, factor: f32| {
+            let nx = x as isize + dx;
+            if nx < 0 || nx as usize >= columns {
+                return;
+            }
+            if let Some(target) = luminosities.get_mut((y + dy) * columns + nx as usize) {
+                *target += error * factor;
+            }
+        };
+
+        diffuse(1, 0, 7f32 / 16f32);
+        diffuse(-1, 1, 3f32 / 16f32);
+        diffuse(0, 1, 5f32 / 16f32);
+        diffuse(1, 1, 1f32 / 16f32);
+    }
+}
+
+#[cfg(test)]
+mod test_error_diffusion {
+    use super::*;
+
+    fn gray(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: 255,
+            luminosity: value as f32,
+        }
+    }
+
+    #[test]
+    fn uses_level_centers() {
+        let mut cells: Vec<Cell> = (0..=255u8).map(gray).collect();
+        error_diffusion(&mut cells, 16, 4);
+        assert!(cells
+            .iter()
+            .all(|cell| [31.875, 95.625, 159.375, 223.125].contains(&cell.luminosity)));
+    }
+
+    #[test]
+    fn average_is_kept() {
+        //a luminosity between two levels is represented by a mix of both
+        let mut cells = vec![gray(100); 64];
+        error_diffusion(&mut cells, 8, 2);
+        let average = cells.iter().map(|cell| cell.luminosity).sum::<f32>() / 64f32;
+        assert!((average - 100f32).abs() < 5f32);
+        assert!(cells.iter().any(|cell| cell.luminosity > 128f32));
+    }
+
+    #[test]
+    fn colors_unchanged() {
+        let mut cells = vec![gray(100); 4];
+        error_diffusion(&mut cells, 2, 2);
+        assert!(cells.iter().all(|cell| cell.red == 100));
+    }
+
+    #[test]
+    fn extremes_are_clamped() {
+        let mut cells = vec![gray(255), gray(0)];
+        error_diffusion(&mut cells, 2, 4);
+        assert_eq!(223.125, cells[0].luminosity);
+        assert_eq!(31.875, cells[1].luminosity);
+    }
+}
+
+/// Stretch the luminosity of the cells to the full range, if it spreads over less than the `min_contrast`.
+///
+/// The darkest cell is mapped to black and the brightest to white, so subtle variations of nearly uniform
+/// images use different characters. Cells with a single luminosity are not changed, since there is nothing to stretch.
+/// The colors are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{expand_contrast, Cell};
+///
+/// let mut cells = [
+///     Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 120f32 },
+///     Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 124f32 },
+/// ];
+/// expand_contrast(&mut cells, 16f32);
+/// assert_eq!([0f32, 255f32], [cells[0].luminosity, cells[1].luminosity]);
+/// ```
+pub fn expand_contrast(cells: &mut [Cell], min_contrast: f32) {
+    let (min, max) = cells.iter().fold((f32::MAX, f32::MIN), |(min, max), cell| {
+        (min.min(cell.luminosity), max.max(cell.luminosity))
+    });
+    let spread = max - min;
+    if spread <= 0f32 || spread >= min_contrast {
+        return;
+    }
+
+    for cell in cells.iter_mut() {
+        cell.luminosity = (cell.luminosity - min) / spread * 255f32;
+    }
+}
+
+#[cfg(test)]
+mod test_expand_contrast {
+    use super::*;
+
+    fn gray(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: 255,
+            luminosity: value as f32,
+        }
+    }
+
+    #[test]
+    fn low_contrast_is_stretched() {
+        let mut cells = [gray(100), gray(102), gray(104)];
+        expand_contrast(&mut cells, 16f32);
+        let luminosity: Vec<f32> = cells.iter().map(|cell| cell.luminosity).collect();
+        assert_eq!(vec![0f32, 127.5, 255f32], luminosity);
+    }
+
+    #[test]
+    fn enough_contrast_unchanged() {
+        let mut cells = [gray(100), gray(150)];
+        expand_contrast(&mut cells, 16f32);
+        assert_eq!([gray(100), gray(150)], cells);
+    }
+
+    #[test]
+    fn uniform_unchanged() {
+        let mut cells = [gray(100); 3];
+        expand_contrast(&mut cells, 16f32);
+        assert_eq!([gray(100); 3], cells);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let mut cells = [gray(100), gray(101)];
+        expand_contrast(&mut cells, 0f32);
+        assert_eq!([gray(100), gray(101)], cells);
+    }
+}
+
+/// Equalize the histogram of the luminosity of the cells.
+///
+/// Each luminosity is mapped through the cumulative histogram of all cells, so the luminosities are spread evenly
+/// over the full range. Cells with the same luminosity are mapped to the same value and the colors are not changed.
+/// If all cells have the same luminosity, they are not changed, since there is nothing to spread.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{equalize, Cell};
+///
+/// let mut cells = [
+///     Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 100f32 },
+///     Cell { red: 0, green: 0, blue: 0, alpha: 255, luminosity: 110f32 },
+/// ];
+/// equalize(&mut cells);
+/// assert_eq!([0f32, 255f32], [cells[0].luminosity, cells[1].luminosity]);
+/// ```
+pub fn equalize(cells: &mut [Cell]) {
+    let bin = |luminosity: f32| luminosity.round().clamp(0f32, 255f32) as usize;
+
+    let mut cumulative = [0usize; 256];
+    for cell in cells.iter() {
+        cumulative[bin(cell.luminosity)] += 1;
+    }
+    for index in 1..cumulative.len() {
+        cumulative[index] += cumulative[index - 1];
+    }
+
+    //the darkest luminosity is mapped to black
+    let min = cumulative
+        .iter()
+        .copied()
+        .find(|count| *count > 0)
+        .unwrap_or(0);
+    let total = cells.len();
+    if total == min {
+        return;
+    }
+
+    for cell in cells.iter_mut() {
+        let count = cumulative[bin(cell.luminosity)];
+        cell.luminosity = (count - min) as f32 / (total - min) as f32 * 255f32;
+    }
+}
+
+#[cfg(test)]
+mod test_equalize {
+    use super::*;
+
+    fn gray(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: 255,
+            luminosity: value as f32,
+        }
+    }
+
+    #[test]
+    fn uses_full_range() {
+        let mut cells = [gray(100), gray(105), gray(110), gray(115)];
+        equalize(&mut cells);
+        let luminosity: Vec<f32> = cells.iter().map(|cell| cell.luminosity).collect();
+        assert_eq!(vec![0f32, 85f32, 170f32, 255f32], luminosity);
+    }
+
+    #[test]
+    fn keeps_order() {
+        let mut cells = [gray(10), gray(200), gray(11), gray(12)];
+        equalize(&mut cells);
+        assert!(cells[0].luminosity < cells[2].luminosity);
+        assert!(cells[2].luminosity < cells[3].luminosity);
+        assert!(cells[3].luminosity < cells[1].luminosity);
+    }
+
+    #[test]
+    fn single_luminosity_unchanged() {
+        let mut cells = [gray(128); 4];
+        equalize(&mut cells);
+        assert_eq!([gray(128); 4], cells);
+    }
+
+    #[test]
+    fn colors_unchanged() {
+        let mut cells = [gray(100), gray(110)];
+        equalize(&mut cells);
+        assert_eq!(110, cells[1].red);
+    }
+}
+
+/// Merge the colors of adjacent cells, so the row has at most `max_runs` runs of the same color.
+///
+/// A run continues as long as the cells differ by at most a threshold in each channel from the first cell of the run,
+/// all cells of a run then use its color. The threshold starts at 0 and is raised until the row has few enough runs.
+/// The luminosity of the cells is not changed, so the characters stay the same.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::{limit_color_runs, Cell};
+///
+/// let mut cells = [
+///     Cell { red: 100, green: 0, blue: 0, alpha: 255, luminosity: 21f32 },
+///     Cell { red: 104, green: 0, blue: 0, alpha: 255, luminosity: 22f32 },
+/// ];
+/// limit_color_runs(&mut cells, 1);
+/// assert_eq!(100, cells[1].red);
+/// ```
+pub fn limit_color_runs(cells: &mut [Cell], max_runs: usize) {
+    let color = |cell: &Cell| [cell.red, cell.green, cell.blue];
+    //the first cell of each run, using the threshold
+    let run_starts = |threshold: u8| {
+        let mut starts: Vec<usize> = Vec::new();
+        for (index, cell) in cells.iter().enumerate() {
+            let start = starts.last().map(|start| color(&cells[*start]));
+            let differs = start.map_or(true, |start| {
+                start
+                    .iter()
+                    .zip(color(cell))
+                    .any(|(a, b)| (*a as i16 - b as i16).abs() > threshold as i16)
+            });
+            if differs {
+                starts.push(index);
+            }
+        }
+        starts
+    };
+
+    let mut threshold = 0u8;
+    let mut starts = run_starts(threshold);
+    //a threshold of 255 merges all cells into a single run
+    while starts.len() > max_runs.max(1) && threshold < u8::MAX {
+        threshold = threshold.saturating_mul(2).max(1);
+        starts = run_starts(threshold);
+    }
+
+    for (run, start) in starts.iter().enumerate() {
+        let end = starts.get(run + 1).copied().unwrap_or(cells.len());
+        let [red, green, blue] = color(&cells[*start]);
+        for cell in &mut cells[*start..end] {
+            cell.red = red;
+            cell.green = green;
+            cell.blue = blue;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_limit_color_runs {
+    use super::*;
+
+    fn red(value: u8) -> Cell {
+        Cell {
+            red: value,
+            green: 0,
+            blue: 0,
+            alpha: 255,
+            luminosity: luminosity(value, 0, 0, options::LuminanceWeights::DEFAULT),
+        }
+    }
+
+    /// Count the runs of the same color.
+    fn runs(cells: &[Cell]) -> usize {
+        1 + cells
+            .windows(2)
+            .filter(|pair| {
+                (pair[0].red, pair[0].green, pair[0].blue)
+                    != (pair[1].red, pair[1].green, pair[1].blue)
+            })
+            .count()
+    }
+
+    #[test]
+    fn noisy_row_is_limited() {
+        let mut cells: Vec<Cell> = (0..40).map(|index| red((index * 37 % 256) as u8)).collect();
+        limit_color_runs(&mut cells, 5);
+        assert!(runs(&cells) <= 5);
+    }
+
+    #[test]
+    fn few_runs_unchanged() {
+        let mut cells = [red(10), red(10), red(200), red(200)];
+        limit_color_runs(&mut cells, 2);
+        assert_eq!([red(10), red(10), red(200), red(200)], cells);
+    }
+
+    #[test]
+    fn similar_colors_merged_first() {
+        let mut cells = [red(10), red(12), red(200), red(202)];
+        limit_color_runs(&mut cells, 2);
+        assert_eq!(10, cells[1].red);
+        assert_eq!(200, cells[3].red);
+    }
+
+    #[test]
+    fn luminosity_unchanged() {
+        let mut cells = [red(10), red(12)];
+        limit_color_runs(&mut cells, 1);
+        assert_eq!(
+            luminosity(12, 0, 0, options::LuminanceWeights::DEFAULT),
+            cells[1].luminosity
+        );
+    }
+}
+
+/// Compress the highlights of the luminosity using the Reinhard tone mapping operator.
+///
+/// The luminosity is converted to linear light, mapped with `L / (1 + L)` and converted back.
+/// This pulls blown out highlights down, so they use more of the characters, instead of only the brightest ones.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::tone_map;
+///
+/// assert_eq!(0f32, tone_map(0f32));
+/// assert!(tone_map(255f32) < 200f32);
+/// ```
+pub fn tone_map(luminosity: f32) -> f32 {
+    let linear = srgb_to_linear(luminosity / u8::MAX as f32);
+    linear_to_srgb(linear / (1f32 + linear)) * u8::MAX as f32
+}
+
+#[cfg(test)]
+mod test_tone_map {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let mapped: Vec<f32> = (0..=255).step_by(5).map(|v| tone_map(v as f32)).collect();
+        assert!(mapped.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn highlights_are_compressed() {
+        //the highlights are pulled down more than the shadows
+        assert!(255f32 - tone_map(255f32) > 20f32 - tone_map(20f32));
+    }
+
+    #[test]
+    fn white_is_mapped_to_half_linear() {
+        assert_eq!(188, tone_map(255f32).round() as u8);
+    }
+}
+
+/// Convert a srgb encoded value between 0.0 and 1.0 to linear light.
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light value between 0.0 and 1.0 to srgb.
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1f32 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod test_srgb {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for value in [0f32, 0.002, 0.2, 0.5, 1f32] {
+            assert!((value - linear_to_srgb(srgb_to_linear(value))).abs() < 1e-5);
+        }
+    }
+}
+
+/// Round the color channel to the nearest multiple of the step.
+///
+/// Values which would be rounded above 255 are clamped to the largest multiple of the step.
+/// A step of 0 or 1 returns the value unchanged.
+///
+/// # Examples
+///
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!(16, quantize(13, 8));
+/// ```
+fn quantize(value: u8, step: u8) -> u8 {
+    if step <= 1 {
+        return value;
+    }
+
+    let step = step as u16;
+    let rounded = (value as u16 + step / 2) / step * step;
+    if rounded > 255 {
+        (255 / step * step) as u8
+    } else {
+        rounded as u8
+    }
+}
+
+#[cfg(test)]
+mod test_quantize {
+    use super::*;
+
+    #[test]
+    fn rounds_to_nearest_step() {
+        assert_eq!(16, quantize(13, 8));
+        assert_eq!(8, quantize(11, 8));
+    }
+
+    #[test]
+    fn clamps_to_largest_step() {
+        assert_eq!(240, quantize(255, 16));
+    }
+
+    #[test]
+    fn step_zero_and_one_unchanged() {
+        assert_eq!(123, quantize(123, 0));
+        assert_eq!(123, quantize(123, 1));
+    }
+}
+
+/// Round the color to the nearest web-safe color, using the `metric`.
+///
+/// The web-safe values are the multiples of 51, so the rgb color is one of the 216 web-safe colors.
+///
+/// # Examples
+///
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!([102, 153, 0], web_safe([90, 170, 0], ColorDistance::Euclidean));
+/// ```
+fn web_safe(color: [u8; 3], metric: options::ColorDistance) -> [u8; 3] {
+    snap(color, 51, metric)
+}
+
+#[cfg(test)]
+mod test_web_safe {
+    use super::*;
+    use crate::options::ColorDistance;
+
+    #[test]
+    fn rounds_to_nearest_value() {
+        assert_eq!(
+            [102, 153, 102],
+            web_safe([90, 170, 90], ColorDistance::Euclidean)
+        );
+    }
+
+    #[test]
+    fn keeps_bounds() {
+        assert_eq!(
+            [0, 255, 255],
+            web_safe([0, 255, 240], ColorDistance::Euclidean)
+        );
+    }
+}
+
+/// Lift the color to the given minimum luminance.
+///
+/// If the luminance of the color is below the `min_luminance`, the difference is added to every channel, which
+/// raises the luminance to the minimum while keeping the hue. Brighter colors are returned unchanged.
+///
+/// # Examples
+///
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!((64, 64, 64), brighten(2, 2, 2, 64f32, LuminanceWeights::DEFAULT));
+/// ```
+fn brighten(
+    red: u8,
+    green: u8,
+    blue: u8,
+    min_luminance: f32,
+    weights: options::LuminanceWeights,
+) -> (u8, u8, u8) {
+    let difference = min_luminance - luminosity(red, green, blue, weights);
+    if difference <= 0f32 {
+        return (red, green, blue);
+    }
+
+    let lift = |channel: u8| (channel as f32 + difference).round().min(255f32) as u8;
+    (lift(red), lift(green), lift(blue))
+}
+
+#[cfg(test)]
+mod test_brighten {
+    use super::*;
+
+    #[test]
+    fn near_black_is_lifted_to_floor() {
+        assert_eq!(
+            (64, 64, 64),
+            brighten(2, 2, 2, 64f32, options::LuminanceWeights::DEFAULT)
+        );
+    }
+
+    #[test]
+    fn bright_color_unchanged() {
+        assert_eq!(
+            (154, 85, 54),
+            brighten(154, 85, 54, 64f32, options::LuminanceWeights::DEFAULT)
+        );
+    }
+
+    #[test]
+    fn disabled_with_zero() {
+        assert_eq!(
+            (0, 0, 0),
+            brighten(0, 0, 0, 0f32, options::LuminanceWeights::DEFAULT)
+        );
+    }
+}
+
+/// Blend the pixel over the background color, using its opacity.
+///
+/// Transparent pixels take the color of the background, opaque pixels are not changed.
+/// The alpha channel is kept, so the opacity can still be used afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::composite;
+/// use image::{Rgb, Rgba};
+///
+/// assert_eq!(Rgba([255, 255, 255, 0]), composite(Rgba([0, 0, 0, 0]), Rgb([255, 255, 255])));
+/// assert_eq!(Rgba([10, 20, 30, 255]), composite(Rgba([10, 20, 30, 255]), Rgb([255, 255, 255])));
+/// ```
+pub fn composite(pixel: Rgba<u8>, background: Rgb<u8>) -> Rgba<u8> {
+    let alpha = pixel.0[3] as u32;
+    let blend = |channel: usize| {
+        ((pixel.0[channel] as u32 * alpha + background.0[channel] as u32 * (255 - alpha) + 127)
+            / 255) as u8
+    };
+    Rgba([blend(0), blend(1), blend(2), pixel.0[3]])
+}
+
+#[cfg(test)]
+mod test_composite {
+    use super::*;
+
+    #[test]
+    fn half_transparent() {
+        assert_eq!(
+            Rgba([127, 127, 127, 128]),
+            composite(Rgba([0, 0, 0, 128]), Rgb([255, 255, 255]))
+        );
+    }
+
+    #[test]
+    fn background_color() {
+        assert_eq!(
+            Rgba([10, 20, 30, 0]),
+            composite(Rgba([200, 0, 0, 0]), Rgb([10, 20, 30]))
+        );
+    }
 }
 
 /// Returns the average rbg color of multiple pixel.
 ///
 /// If the input block is empty, all pixels are seen and calculated as if there were black.
+/// The alpha channel is ignored, transparent pixels have to be blended with [`composite`] first.
+/// When the `simd` feature is enabled, `average_color_simd` is used, otherwise [`average_color_scalar`].
+/// Both produce the same result.
 ///
 /// # Examples
 ///
-/// ```compile_fail, compile will fail, this is an internal example
+/// ```
+/// use artem::pixel::average_color;
+/// use image::Rgba;
+///
 /// let pixels: Vec<Rgba<u8>> = Vec::new();
-/// assert_eq!((0, 0, 0, 0.0), get_pixel_color_luminosity(&pixels));
+/// assert_eq!((0, 0, 0), average_color(&pixels));
 /// ```
 ///
 /// The formula for calculating the rbg colors is based an a minutephysics video <https://www.youtube.com/watch?v=LKnqECcg6Gw>
-fn average_color(block: &[Rgba<u8>]) -> (u8, u8, u8) {
-    let sum = block
-        .iter()
-        .map(|pixel| {
-            (
-                pixel.0[0] as f32 * pixel.0[0] as f32,
-                pixel.0[1] as f32 * pixel.0[1] as f32,
-                pixel.0[2] as f32 * pixel.0[2] as f32,
-            )
-        })
-        .fold((0f32, 0f32, 0f32), |acc, value| {
-            (acc.0 + value.0, acc.1 + value.1, acc.2 + value.2)
+pub fn average_color(block: &[Rgba<u8>]) -> (u8, u8, u8) {
+    #[cfg(feature = "simd")]
+    {
+        average_color_simd(block)
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        average_color_scalar(block)
+    }
+}
+
+/// Returns the average rbg color of multiple pixel, processing one pixel at a time.
+///
+/// The squared channels are summed up as integers, so the result does not depend on the order of the pixels.
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::average_color_scalar;
+/// use image::Rgba;
+///
+/// let pixels = vec![Rgba::<u8>::from([255, 0, 0, 255]), Rgba::<u8>::from([0, 255, 0, 255])];
+/// assert_eq!((180, 180, 0), average_color_scalar(&pixels));
+/// ```
+pub fn average_color_scalar(block: &[Rgba<u8>]) -> (u8, u8, u8) {
+    let sum = block.iter().fold([0u64; 3], |mut acc, pixel| {
+        for (channel, value) in acc.iter_mut().zip(pixel.0) {
+            *channel += value as u64 * value as u64;
+        }
+        acc
+    });
+    root_mean(sum, block.len())
+}
+
+/// Returns the average rbg color of multiple pixel, processing all channels of a pixel at once.
+///
+/// This uses 128-bit wide SIMD registers, for example SSE2 on x86_64 or NEON on aarch64. On CPUs without them,
+/// it falls back to a scalar emulation, which is slower than [`average_color_scalar`].
+/// The result is identical to [`average_color_scalar`].
+///
+/// # Examples
+///
+/// ```
+/// use artem::pixel::average_color_simd;
+/// use image::Rgba;
+///
+/// let pixels = vec![Rgba::<u8>::from([255, 0, 0, 255]), Rgba::<u8>::from([0, 255, 0, 255])];
+/// assert_eq!((180, 180, 0), average_color_simd(&pixels));
+/// ```
+#[cfg(feature = "simd")]
+pub fn average_color_simd(block: &[Rgba<u8>]) -> (u8, u8, u8) {
+    use wide::u32x4;
+
+    //a squared channel is at most 255², so the lanes can hold the sum of 66051 pixels before overflowing
+    const CHUNK_SIZE: usize = 65536;
+
+    let mut sum = [0u64; 3];
+    for chunk in block.chunks(CHUNK_SIZE) {
+        let lanes = chunk.iter().fold(u32x4::ZERO, |acc, pixel| {
+            let value = u32x4::new([pixel.0[0] as u32, pixel.0[1] as u32, pixel.0[2] as u32, 0]);
+            acc + value * value
         });
+        for (channel, lane) in sum.iter_mut().zip(lanes.to_array()) {
+            *channel += lane as u64;
+        }
+    }
+    root_mean(sum, block.len())
+}
+
+/// Returns the square root of the mean of the summed squared channels.
+fn root_mean(sum: [u64; 3], len: usize) -> (u8, u8, u8) {
     (
-        (sum.0 / block.len() as f32).sqrt() as u8,
-        (sum.1 / block.len() as f32).sqrt() as u8,
-        (sum.2 / block.len() as f32).sqrt() as u8,
+        (sum[0] as f32 / len as f32).sqrt() as u8,
+        (sum[1] as f32 / len as f32).sqrt() as u8,
+        (sum[2] as f32 / len as f32).sqrt() as u8,
     )
 }
 
@@ -360,25 +2038,46 @@ mod test_avg_color {
         assert_eq!(0, g);
         assert_eq!(0, b);
     }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn simd_equals_scalar() {
+        //large enough to use multiple chunks
+        let pixels: Vec<Rgba<u8>> = (0..70_000u32)
+            .map(|i| {
+                Rgba::<u8>::from([
+                    (i % 256) as u8,
+                    (i * 7 % 256) as u8,
+                    (i * 13 % 256) as u8,
+                    255,
+                ])
+            })
+            .collect();
+        assert_eq!(average_color_scalar(&pixels), average_color_simd(&pixels));
+        assert_eq!(
+            average_color_scalar(&pixels[..123]),
+            average_color_simd(&pixels[..123])
+        );
+    }
 }
 
 /// Returns the luminosity of the given rgb colors as an float.
 ///
-/// It converts the rgb values to floats, adds them with weightings and then returns them
+/// It converts the rgb values to floats, adds them with the given weights and then returns them
 /// as a float value.
 ///
 /// # Examples
 ///
-/// ```compile_fail, compile will fail, this is an internal example
-/// use artem::pixel;
+/// ```
+/// use artem::{options::LuminanceWeights, pixel::luminosity};
 ///
-/// let luminosity = luminosity(154, 85, 54);
-/// assert_eq!(97f32, luminosity);
+/// let luminosity = luminosity(154, 85, 54, LuminanceWeights::DEFAULT);
+/// assert_eq!(97.32f32, luminosity);
 /// ```
 ///
-/// The formula/weighting for the colors comes from <http://www.johndcook.com/blog/2009/08/24/algorithms-convert-color-grayscale/>
-pub fn luminosity(red: u8, green: u8, blue: u8) -> f32 {
-    (0.21 * red as f32) + (0.72 * green as f32) + (0.07 * blue as f32)
+/// The default weighting for the colors comes from <http://www.johndcook.com/blog/2009/08/24/algorithms-convert-color-grayscale/>
+pub fn luminosity(red: u8, green: u8, blue: u8, weights: options::LuminanceWeights) -> f32 {
+    (weights.red * red as f32) + (weights.green * green as f32) + (weights.blue * blue as f32)
 }
 
 #[cfg(test)]
@@ -387,16 +2086,46 @@ mod tests {
 
     #[test]
     fn luminosity_black_is_zero() {
-        assert_eq!(0f32, luminosity(0, 0, 0))
+        assert_eq!(
+            0f32,
+            luminosity(0, 0, 0, options::LuminanceWeights::DEFAULT)
+        )
     }
 
     #[test]
     fn luminosity_white_is_255() {
-        assert_eq!(255.00002, luminosity(255, 255, 255))
+        assert_eq!(
+            255.00002,
+            luminosity(255, 255, 255, options::LuminanceWeights::DEFAULT)
+        )
     }
 
     #[test]
     fn luminosity_rust_color_is_255() {
-        assert_eq!(97.32f32, luminosity(154, 85, 54))
+        assert_eq!(
+            97.32f32,
+            luminosity(154, 85, 54, options::LuminanceWeights::DEFAULT)
+        )
+    }
+
+    #[test]
+    fn luminosity_uses_weights() {
+        assert_eq!(
+            255f32,
+            luminosity(
+                255,
+                0,
+                0,
+                options::LuminanceWeights {
+                    red: 1f32,
+                    green: 0f32,
+                    blue: 0f32
+                }
+            )
+        );
+        assert_ne!(
+            luminosity(154, 85, 54, options::LuminanceWeights::DEFAULT),
+            luminosity(154, 85, 54, options::LuminanceWeights::REC_601)
+        );
     }
 }
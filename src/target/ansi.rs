@@ -0,0 +1,498 @@
+use colored::{Color, ColoredString, Colorize};
+
+use crate::{options::AnsiTieBreak, util};
+
+/// Returns an colored string with the given colors.
+///
+/// Checks if true_colors are supported, by checking the `COLORTERM` environnement variable,
+/// it then returns the given char as a colored string, either using true colors or ansi colors as a fallback.
+/// Background colors are only supported when true colors are enabled.
+/// The `tie_break` selects the ansi color, if the color is equally close to two of them.
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", get_colored_string(100, 100, 100, 'x', false, AnsiTieBreak::Standard));
+/// ```
+pub fn colored_char(
+    red: u8,
+    green: u8,
+    blue: u8,
+    char: char,
+    background_color: bool,
+    tie_break: AnsiTieBreak,
+) -> String {
+    colored_str(
+        red,
+        green,
+        blue,
+        &char.to_string(),
+        background_color,
+        tie_break,
+    )
+}
+
+/// Returns the text as a single colored string with the given colors.
+///
+/// This is the same as [`colored_char`], but colors multiple chars at once, so they only use a single color sequence.
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", colored_str(100, 100, 100, "xyz", false, AnsiTieBreak::Standard));
+/// ```
+pub fn colored_str(
+    red: u8,
+    green: u8,
+    blue: u8,
+    text: &str,
+    background_color: bool,
+    tie_break: AnsiTieBreak,
+) -> String {
+    if util::supports_truecolor() {
+        //return true color string
+        if background_color {
+            text.on_truecolor(red, green, blue).to_string()
+        } else {
+            text.truecolor(red, green, blue).to_string()
+        }
+    } else {
+        //otherwise use basic (8 color) ansi color
+        rgb_to_ansi(text, red, green, blue, tie_break).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_colored_string {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn rust_color_no_background() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(
+            "x".truecolor(154, 85, 54).to_string(),
+            colored_char(154, 85, 54, 'x', false, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn rust_color_with_background() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(
+            "x".on_truecolor(154, 85, 54).to_string(),
+            colored_char(154, 85, 54, 'x', true, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn rust_color_ansi_no_background() {
+        //set true color support to false
+        env::set_var("COLORTERM", "false");
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(
+            "\u{1b}[33mx\u{1b}[0m",
+            colored_char(154, 85, 54, 'x', false, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn rust_color_ansi_with_background() {
+        //set true color support to false
+        env::set_var("COLORTERM", "false");
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        //ansi does not support background, so it is the same as without
+        assert_eq!(
+            "\u{1b}[33mx\u{1b}[0m",
+            colored_char(154, 85, 54, 'x', true, AnsiTieBreak::Standard)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_colored_str {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn single_sequence() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(
+            "xyz".truecolor(154, 85, 54).to_string(),
+            colored_str(154, 85, 54, "xyz", false, AnsiTieBreak::Standard)
+        );
+    }
+}
+
+/// Upper half block, which is colored with the top color as foreground and the bottom color as background.
+pub const HALF_BLOCK: char = '▀';
+
+/// Returns an upper half block with the `top` color as foreground and the `bottom` color as background color.
+///
+/// With true colors, both colors are set using the `38;2` and `48;2` sequences,
+/// otherwise the closest basic ansi colors are used for both.
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", half_block([255, 0, 0], [0, 0, 255], AnsiTieBreak::Standard));
+/// ```
+pub fn half_block(top: [u8; 3], bottom: [u8; 3], tie_break: AnsiTieBreak) -> String {
+    let block = HALF_BLOCK.to_string();
+    if util::supports_truecolor() {
+        block
+            .truecolor(top[0], top[1], top[2])
+            .on_truecolor(bottom[0], bottom[1], bottom[2])
+            .to_string()
+    } else {
+        //the closest ansi color of the bottom is used as the background
+        let background = nearest_ansi_color(bottom[0], bottom[1], bottom[2], tie_break);
+        rgb_to_ansi(&block, top[0], top[1], top[2], tie_break)
+            .on_color(background)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_half_block {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn truecolor_foreground_and_background() {
+        //ensure that colors will be used
+        env::set_var("COLORTERM", "truecolor");
+        env::set_var("CLICOLOR_FORCE", "1");
+        let block = half_block([154, 85, 54], [10, 20, 30], AnsiTieBreak::Standard);
+        assert!(block.contains("38;2;154;85;54"));
+        assert!(block.contains("48;2;10;20;30"));
+        assert!(block.contains(HALF_BLOCK));
+    }
+
+    #[test]
+    fn ansi_foreground_and_background() {
+        //set true color support to false
+        env::set_var("COLORTERM", "false");
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(
+            "▀".red().on_blue().to_string(),
+            half_block([170, 0, 0], [0, 0, 170], AnsiTieBreak::Standard)
+        );
+    }
+}
+
+/// Returns the char colored with the 8-bit ansi grayscale ramp.
+///
+/// The luminosity (0 - 255) is mapped onto the 24 gray steps (232 - 255) of the 256 color palette,
+/// which gives a finer grayscale than the basic ansi colors, without requiring true color support.
+/// If colors are disabled (for example by `NO_COLOR`), the char is returned without any escape codes.
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", grayscale_char(128f32, 'x', false));
+/// ```
+pub fn grayscale_char(luminosity: f32, char: char, background_color: bool) -> String {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        return char.to_string();
+    }
+
+    //the gray steps start at 8 and increase by 10 for each index
+    let index = 232 + ((luminosity - 8f32) / 10f32).round().clamp(0f32, 23f32) as u8;
+    format!(
+        "\x1b[{};5;{}m{}\x1b[0m",
+        if background_color { 48 } else { 38 },
+        index,
+        char
+    )
+}
+
+#[cfg(test)]
+mod test_grayscale_char {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn mid_gray_foreground() {
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!("\x1b[38;5;244mx\x1b[0m", grayscale_char(128f32, 'x', false));
+    }
+
+    #[test]
+    fn mid_gray_background() {
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!("\x1b[48;5;244mx\x1b[0m", grayscale_char(128f32, 'x', true));
+    }
+
+    #[test]
+    fn clamps_to_ramp() {
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!("\x1b[38;5;232mx\x1b[0m", grayscale_char(0f32, 'x', false));
+        assert_eq!("\x1b[38;5;255mx\x1b[0m", grayscale_char(255f32, 'x', false));
+    }
+}
+
+/// Returns the ANSI sequence, which sets the background to the given color.
+///
+/// This does not contain any chars, the background has to be reset using [`RESET`].
+/// If colors are disabled (for example by `NO_COLOR`), an empty string is returned.
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}x{}", backdrop_sequence([30, 30, 30]), RESET);
+/// ```
+pub fn backdrop_sequence(color: [u8; 3]) -> String {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        return String::new();
+    }
+
+    format!("\x1b[48;2;{};{};{}m", color[0], color[1], color[2])
+}
+
+/// ANSI sequence to reset all colors.
+pub const RESET: &str = "\x1b[0m";
+
+#[cfg(test)]
+mod test_backdrop_sequence {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn sets_background() {
+        //ensure that colors will be used
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!("\x1b[48;2;30;40;50m", backdrop_sequence([30, 40, 50]));
+    }
+}
+
+///Converts the given input string to an ansi colored string
+///
+/// It tries to match the ANSI-Color as closely as possible by calculating the distance between all
+/// 8 colors and the given input color from `r`, `b` and `b`, then returning the nearest.
+/// It will not be 100% accurate, since every terminal has slightly different
+/// ANSI-Colors. It used the VGA-Colors as ANSI-Color.
+/// If the color is equally close to a standard and a bright color, the `tie_break` decides which one is used.
+/// Other ties are won by the color, which comes first in the ansi color order (black, red, green, yellow, blue, magenta, cyan, white).
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// //convert black to ansi black color
+/// assert_eq!("input".black(), rgb_to_ansi("input", 0, 0, 0, AnsiTieBreak::Standard));
+/// ```
+fn rgb_to_ansi(input: &str, r: u8, g: u8, b: u8, tie_break: AnsiTieBreak) -> ColoredString {
+    input.color(nearest_ansi_color(r, g, b, tie_break))
+}
+
+/// Returns the ansi color, which is closest to the given color.
+///
+/// See [`rgb_to_ansi`] for how the color is selected.
+fn nearest_ansi_color(r: u8, g: u8, b: u8, tie_break: AnsiTieBreak) -> Color {
+    //get rgb values and convert them to i32, since later on the could negative when subtracting
+    let r = r as i32;
+    let g = g as i32;
+    let b = b as i32;
+
+    //vga colors as example ansi color
+    //from https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
+    let vga_colors = [
+        [0, 0, 0],       //black
+        [170, 0, 0],     //red
+        [0, 170, 0],     //green
+        [170, 85, 0],    //yellow
+        [0, 0, 170],     //blue
+        [170, 0, 170],   //magenta
+        [0, 170, 170],   //cyan
+        [170, 170, 170], //white
+        [128, 128, 128], //bright black/gray
+        [255, 0, 0],     //bright red
+        [0, 255, 0],     //bright green
+        [255, 255, 0],   //bright yellow
+        [0, 0, 255],     //bright blue
+        [255, 0, 255],   //bright magenta
+        [0, 255, 255],   //bright cyan
+        [255, 255, 255], //bright white
+    ];
+
+    //find nearest color
+    let mut smallest_distance = i32::MAX;
+    let mut smallest_distance_index: u8 = 7;
+    //maybe there is a better method for this
+    for (index, vga_color) in vga_colors.iter().enumerate() {
+        let distance =
+            (r - vga_color[0]).pow(2) + (g - vga_color[1]).pow(2) + (b - vga_color[2]).pow(2);
+
+        //the bright colors come after the standard colors, so they only win a tie if they are preferred
+        let preferred = distance == smallest_distance
+            && tie_break == AnsiTieBreak::Bright
+            && index >= 8
+            && smallest_distance_index < 8;
+        if distance < smallest_distance || preferred {
+            smallest_distance = distance;
+            smallest_distance_index = index as u8;
+        }
+    }
+
+    //convert index to matching color
+    match smallest_distance_index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::White,
+        8 => Color::BrightBlack,
+        9 => Color::BrightRed,
+        10 => Color::BrightGreen,
+        11 => Color::BrightYellow,
+        12 => Color::BrightBlue,
+        13 => Color::BrightMagenta,
+        14 => Color::BrightCyan,
+        _ => Color::BrightWhite,
+    }
+}
+
+#[cfg(test)]
+mod test_convert_rgb_ansi {
+    use super::*;
+
+    #[test]
+    fn convert_vga_normal_values() {
+        //convert black to ansi black color
+        assert_eq!(
+            "input".black(),
+            rgb_to_ansi("input", 0, 0, 0, AnsiTieBreak::Standard)
+        );
+        //convert red to ansi red color
+        assert_eq!(
+            "input".red(),
+            rgb_to_ansi("input", 170, 0, 0, AnsiTieBreak::Standard)
+        );
+        //convert green to ansi green color
+        assert_eq!(
+            "input".green(),
+            rgb_to_ansi("input", 0, 170, 0, AnsiTieBreak::Standard)
+        );
+        //convert yellow to ansi yellow color
+        assert_eq!(
+            "input".yellow(),
+            rgb_to_ansi("input", 170, 85, 0, AnsiTieBreak::Standard)
+        );
+        //convert blue to ansi blue color
+        assert_eq!(
+            "input".blue(),
+            rgb_to_ansi("input", 0, 0, 170, AnsiTieBreak::Standard)
+        );
+        //convert magenta to ansi magenta color
+        assert_eq!(
+            "input".magenta(),
+            rgb_to_ansi("input", 170, 0, 170, AnsiTieBreak::Standard)
+        );
+        //convert cyan to ansi cyan color
+        assert_eq!(
+            "input".cyan(),
+            rgb_to_ansi("input", 0, 170, 170, AnsiTieBreak::Standard)
+        );
+        //convert white to ansi white color
+        assert_eq!(
+            "input".white(),
+            rgb_to_ansi("input", 170, 170, 170, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn convert_vga_bright_values() {
+        //convert bright black to ansi bright black color
+        assert_eq!(
+            "input".bright_black(),
+            rgb_to_ansi("input", 128, 128, 128, AnsiTieBreak::Standard)
+        );
+        //convert bright red to ansi bright red color
+        assert_eq!(
+            "input".bright_red(),
+            rgb_to_ansi("input", 255, 0, 0, AnsiTieBreak::Standard)
+        );
+        //convert bright green to ansi bright green color
+        assert_eq!(
+            "input".bright_green(),
+            rgb_to_ansi("input", 0, 255, 0, AnsiTieBreak::Standard)
+        );
+        //convert bright yellow to ansi bright yellow color
+        assert_eq!(
+            "input".bright_yellow(),
+            rgb_to_ansi("input", 255, 255, 0, AnsiTieBreak::Standard)
+        );
+        //convert bright blue to ansi bright blue color
+        assert_eq!(
+            "input".bright_blue(),
+            rgb_to_ansi("input", 0, 0, 255, AnsiTieBreak::Standard)
+        );
+        //convert bright magenta to ansi bright magenta color
+        assert_eq!(
+            "input".bright_magenta(),
+            rgb_to_ansi("input", 255, 0, 255, AnsiTieBreak::Standard)
+        );
+        //convert bright cyan to ansi bright cyan color
+        assert_eq!(
+            "input".bright_cyan(),
+            rgb_to_ansi("input", 0, 255, 255, AnsiTieBreak::Standard)
+        );
+        //convert bright white to ansi bright white color
+        assert_eq!(
+            "input".bright_white(),
+            rgb_to_ansi("input", 255, 255, 255, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn tie_prefers_standard() {
+        //equally close to black and bright black
+        assert_eq!(
+            "input".black(),
+            rgb_to_ansi("input", 64, 64, 64, AnsiTieBreak::Standard)
+        );
+        //equally close to white and bright black
+        assert_eq!(
+            "input".white(),
+            rgb_to_ansi("input", 149, 149, 149, AnsiTieBreak::Standard)
+        );
+    }
+
+    #[test]
+    fn tie_prefers_bright() {
+        assert_eq!(
+            "input".bright_black(),
+            rgb_to_ansi("input", 64, 64, 64, AnsiTieBreak::Bright)
+        );
+        assert_eq!(
+            "input".bright_black(),
+            rgb_to_ansi("input", 149, 149, 149, AnsiTieBreak::Bright)
+        );
+    }
+
+    #[test]
+    fn no_tie_ignores_preference() {
+        assert_eq!(
+            "input".black(),
+            rgb_to_ansi("input", 63, 63, 63, AnsiTieBreak::Bright)
+        );
+    }
+
+    #[test]
+    fn rgb_blue() {
+        //convert a blue rgb tone to ansi blue
+        assert_eq!(
+            "input".blue(),
+            rgb_to_ansi("input", 0, 0, 88, AnsiTieBreak::Standard)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+/// Returns a BBCode string representation of the given char.
+///
+/// Wraps the char in a `[color]` tag, which sets the color to the given rgb inputs.
+/// Whitespace is returned without a tag, since the color would not be visible anyway.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", colored_char(100, 100, 100, 'x'));
+/// ```
+pub fn colored_char(red: u8, green: u8, blue: u8, char: char) -> String {
+    if char.is_whitespace() {
+        return char.to_string();
+    }
+
+    format!(
+        "[color=#{:02X?}{:02X?}{:02X?}]{}[/color]",
+        red, green, blue, char
+    )
+}
+
+#[cfg(test)]
+mod test_bbcode_string {
+    use super::*;
+
+    #[test]
+    fn black() {
+        assert_eq!("[color=#000000]x[/color]", colored_char(0, 0, 0, 'x'))
+    }
+
+    #[test]
+    fn rust_color() {
+        assert_eq!("[color=#9A5536]x[/color]", colored_char(154, 85, 54, 'x'))
+    }
+
+    #[test]
+    fn whitespace_is_bare() {
+        assert_eq!(" ", colored_char(154, 85, 54, ' '))
+    }
+}
@@ -0,0 +1,301 @@
+use crate::options::HtmlColorFormat;
+
+///Returns the top part of the output html file.
+///
+/// This contains the html elements needed for a correct html file.
+/// The title will be set to `Artem Ascii Image`, whilst the will be set to `Courier` ( a monospace font)
+/// It will also have the pre tag for correct spacing/line breaking.
+/// When a `line_height` is given, it is used as the line-height of the pre tag, otherwise the default of the browser is used.
+/// A `fragment` only contains the pre tag, without the rest of the document, so it can be embedded in another page.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// use artem::target::html;
+///
+/// let string = String::new();
+/// string.push_str(&html_top(None, false))
+/// ```
+pub fn html_top(line_height: Option<f32>, fragment: bool) -> String {
+    if fragment {
+        return match line_height {
+            Some(line_height) => format!("<pre style=\"line-height: {line_height};\">"),
+            None => "<pre>".to_string(),
+        };
+    }
+
+    let style = match line_height {
+        Some(line_height) => {
+            format!("* {{font-family: Courier;}} pre {{line-height: {line_height};}}")
+        }
+        None => "* {font-family: Courier;}".to_string(),
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+    <html lang="en">
+    
+    <head>
+        <meta charset="UTF-8">
+        <meta http-equiv="X-UA-Compatible" content="IE=edge">
+        <meta name="viewport" content="width=device-width, initial-scale=1.0">
+        <title>Artem Ascii Image</title>
+        <style>{style}</style>
+    </head>
+    
+    <body>
+        <pre>"#
+    )
+}
+
+#[cfg(test)]
+mod test_push_html_top {
+    use super::*;
+    #[test]
+    fn push_top_html_returns_correct_string() {
+        assert_eq!(
+            r#"<!DOCTYPE html>
+    <html lang="en">
+    
+    <head>
+        <meta charset="UTF-8">
+        <meta http-equiv="X-UA-Compatible" content="IE=edge">
+        <meta name="viewport" content="width=device-width, initial-scale=1.0">
+        <title>Artem Ascii Image</title>
+        <style>* {font-family: Courier;}</style>
+    </head>
+    
+    <body>
+        <pre>"#,
+            html_top(None, false)
+        )
+    }
+
+    #[test]
+    fn line_height_is_set() {
+        assert!(html_top(Some(0.8), false)
+            .contains("<style>* {font-family: Courier;} pre {line-height: 0.8;}</style>"));
+    }
+
+    #[test]
+    fn fragment_only_pre() {
+        assert_eq!("<pre>", html_top(None, true));
+    }
+
+    #[test]
+    fn fragment_line_height() {
+        assert_eq!(
+            "<pre style=\"line-height: 0.8;\">",
+            html_top(Some(0.8), true)
+        );
+    }
+}
+
+///Returns the bottom part of the output html file.
+///
+/// The matching closing tags fro [`html_top`]. It will close
+/// the pres, body and html tag. A `fragment` only closes the pre tag.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// use artem::target::html;
+///
+/// let string = String::new();
+/// string.push_str(&html_top(None, false))
+/// string.push_str(&html_bottom(false))
+/// ```
+pub fn html_bottom(fragment: bool) -> String {
+    if fragment {
+        "</pre>".to_string()
+    } else {
+        "</pre></body></html>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_push_html_bottom {
+    use super::*;
+
+    #[test]
+    fn push_bottom_html_returns_correct_string() {
+        assert_eq!("</pre></body></html>", html_bottom(false))
+    }
+
+    #[test]
+    fn fragment_closes_pre() {
+        assert_eq!("</pre>", html_bottom(true))
+    }
+}
+
+/// Returns an html string representation of the given char with optional background color support.
+///
+/// Creates an <span> element with style attribute, which sets the (background) color to the
+/// given rgb inputs, using the given color format.
+/// Technically the span can have more than a single char, but the complexity needed for a system to group
+/// characters with the same color would be unnecessary and out of scope.
+/// A whitespace char only gets a span, when the background is colored, otherwise its color would not be visible,
+/// so it is returned as it is, keeping the output compact.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// println!("{}", get_html(100, 100, 100, 'x', false, HtmlColorFormat::Hex));
+/// ```
+pub fn colored_char(
+    red: u8,
+    green: u8,
+    blue: u8,
+    char: char,
+    background_color: bool,
+    color_format: HtmlColorFormat,
+) -> String {
+    if char.is_whitespace() && !background_color {
+        return char.to_string();
+    }
+
+    let color = match color_format {
+        HtmlColorFormat::Hex => format!("#{:02X?}{:02X?}{:02X?}", red, green, blue),
+        HtmlColorFormat::Hsl => {
+            let (hue, saturation, lightness) = rgb_to_hsl(red, green, blue);
+            format!("hsl({hue}, {saturation}%, {lightness}%)")
+        }
+    };
+
+    if background_color {
+        format!(
+            "<span style=\"background-color: {}\">{}</span>",
+            color, char
+        )
+    } else {
+        format!("<span style=\"color: {}\">{}</span>", color, char)
+    }
+}
+
+#[cfg(test)]
+mod test_html_string {
+    use super::*;
+
+    #[test]
+    fn black_no_background() {
+        assert_eq!(
+            "<span style=\"color: #000000\">x</span>",
+            colored_char(0, 0, 0, 'x', false, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn black_with_background() {
+        assert_eq!(
+            "<span style=\"background-color: #000000\">x</span>",
+            colored_char(0, 0, 0, 'x', true, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn rust_color_no_background() {
+        assert_eq!(
+            "<span style=\"color: #9A5536\">x</span>",
+            colored_char(154, 85, 54, 'x', false, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn rust_color_with_background() {
+        assert_eq!(
+            "<span style=\"background-color: #9A5536\">x</span>",
+            colored_char(154, 85, 54, 'x', true, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn whitespace_no_background() {
+        assert_eq!(
+            " ",
+            colored_char(154, 85, 54, ' ', false, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn whitespace_with_background() {
+        assert_eq!(
+            "<span style=\"background-color: #9A5536\"> </span>",
+            colored_char(154, 85, 54, ' ', true, HtmlColorFormat::Hex)
+        )
+    }
+
+    #[test]
+    fn rust_color_hsl() {
+        assert_eq!(
+            "<span style=\"color: hsl(19, 48%, 41%)\">x</span>",
+            colored_char(154, 85, 54, 'x', false, HtmlColorFormat::Hsl)
+        )
+    }
+
+    #[test]
+    fn rust_color_hsl_with_background() {
+        assert_eq!(
+            "<span style=\"background-color: hsl(19, 48%, 41%)\">x</span>",
+            colored_char(154, 85, 54, 'x', true, HtmlColorFormat::Hsl)
+        )
+    }
+}
+
+/// Convert the rgb color to hue (0 - 359), saturation (0 - 100) and lightness (0 - 100).
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!((0, 100, 50), rgb_to_hsl(255, 0, 0));
+/// ```
+fn rgb_to_hsl(red: u8, green: u8, blue: u8) -> (u16, u8, u8) {
+    let (red, green, blue) = (
+        red as f32 / 255f32,
+        green as f32 / 255f32,
+        blue as f32 / 255f32,
+    );
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+    let lightness = (max + min) / 2f32;
+
+    if delta == 0f32 {
+        //gray has neither a hue nor a saturation
+        return (0, 0, (lightness * 100f32).round() as u8);
+    }
+
+    let saturation = delta / (1f32 - (2f32 * lightness - 1f32).abs());
+    let hue = if max == red {
+        60f32 * ((green - blue) / delta).rem_euclid(6f32)
+    } else if max == green {
+        60f32 * ((blue - red) / delta + 2f32)
+    } else {
+        60f32 * ((red - green) / delta + 4f32)
+    };
+
+    (
+        hue.round() as u16 % 360,
+        (saturation * 100f32).round() as u8,
+        (lightness * 100f32).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod test_rgb_to_hsl {
+    use super::*;
+
+    #[test]
+    fn primary_colors() {
+        assert_eq!((0, 100, 50), rgb_to_hsl(255, 0, 0));
+        assert_eq!((120, 100, 50), rgb_to_hsl(0, 255, 0));
+        assert_eq!((240, 100, 50), rgb_to_hsl(0, 0, 255));
+    }
+
+    #[test]
+    fn gray() {
+        assert_eq!((0, 0, 0), rgb_to_hsl(0, 0, 0));
+        assert_eq!((0, 0, 100), rgb_to_hsl(255, 255, 255));
+        assert_eq!((0, 0, 50), rgb_to_hsl(128, 128, 128));
+    }
+
+    #[test]
+    fn mixed_color() {
+        assert_eq!((19, 48, 41), rgb_to_hsl(154, 85, 54));
+    }
+}
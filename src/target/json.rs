@@ -0,0 +1,45 @@
+/// Returns the row as a single line of JSON.
+///
+/// The line is an object with the index of the `row` and its `cells`, where every cell has its `char`
+/// and its `color` as an array of the red, green and blue values, for example
+/// `{"cells":[{"char":"M","color":[255,0,0]}],"row":0}`.
+/// The chars and colors are given in the same order, additional colors are ignored.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!(r#"{"cells":[{"char":"M","color":[255,0,0]}],"row":1}"#, row_line(1, "M", &[[255, 0, 0]]));
+/// ```
+pub fn row_line(row: usize, chars: &str, colors: &[[u8; 3]]) -> String {
+    let cells: Vec<serde_json::Value> = chars
+        .chars()
+        .zip(colors)
+        .map(|(char, color)| serde_json::json!({ "char": char, "color": color }))
+        .collect();
+    serde_json::json!({ "row": row, "cells": cells }).to_string()
+}
+
+#[cfg(test)]
+mod test_row_line {
+    use super::*;
+
+    #[test]
+    fn single_cell() {
+        assert_eq!(
+            r#"{"cells":[{"char":"M","color":[255,0,0]}],"row":1}"#,
+            row_line(1, "M", &[[255, 0, 0]])
+        );
+    }
+
+    #[test]
+    fn chars_are_escaped() {
+        let line = row_line(0, "\"\\", &[[0, 0, 0], [0, 0, 0]]);
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!("\"", value["cells"][0]["char"]);
+        assert_eq!("\\", value["cells"][1]["char"]);
+    }
+
+    #[test]
+    fn empty_row() {
+        assert_eq!(r#"{"cells":[],"row":0}"#, row_line(0, "", &[]));
+    }
+}
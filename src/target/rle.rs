@@ -0,0 +1,76 @@
+/// Returns the run-length encoded representation of the given line.
+///
+/// Every run of identical chars is written as a `<count>x<char>` token, for example `"###  "` becomes `"3x#2x "`.
+/// The count is a decimal number, followed by a literal `x` and the repeated char, which can be any char except
+/// a line break, including digits and `x` itself. The tokens of a line directly follow each other, lines are
+/// separated by line breaks, so the output has the same number of lines as the plain text.
+///
+/// # Examples
+/// ```compile_fail, compile will fail, this is an internal example
+/// assert_eq!("3x#2x ", encode_line("###  "));
+/// ```
+pub fn encode_line(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&char).is_some() {
+            count += 1;
+        }
+        output.push_str(&count.to_string());
+        output.push('x');
+        output.push(char);
+    }
+    output
+}
+
+#[cfg(test)]
+mod test_encode_line {
+    use super::*;
+
+    /// Expand the tokens of a run-length encoded line.
+    fn decode_line(line: &str) -> String {
+        let mut output = String::new();
+        let mut chars = line.chars();
+        let mut count = String::new();
+
+        while let Some(char) = chars.next() {
+            if char == 'x' && !count.is_empty() {
+                let run = chars.next().unwrap();
+                output.push_str(&run.to_string().repeat(count.parse().unwrap()));
+                count.clear();
+            } else {
+                count.push(char);
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("", encode_line(""));
+    }
+
+    #[test]
+    fn identical_chars() {
+        assert_eq!("10x#", encode_line("##########"));
+    }
+
+    #[test]
+    fn single_chars() {
+        assert_eq!("1x.1x:1x.", encode_line(".:."));
+    }
+
+    #[test]
+    fn digit_and_x_chars() {
+        assert_eq!("2x11x32xx", encode_line("113xx"));
+    }
+
+    #[test]
+    fn round_trip() {
+        for line in ["##########", "###  :::.", "113xx 0x", ""] {
+            assert_eq!(line, decode_line(&encode_line(line)));
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Querying the background color and the cell size of the terminal.
+//!
+//! Many terminals answer the OSC 11 escape sequence with their background color, for example
+//! `\x1b]11;rgb:1e1e/1e1e/1e1e\x1b\\`, and the `CSI 16 t` sequence with the size of a cell in pixels,
+//! for example `\x1b[6;19;8t`. The terminal is put into raw mode using `stty` while waiting for the answer,
+//! so no additional dependencies are needed.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Write},
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+/// Query the background color of the terminal.
+///
+/// Returns `None`, if the terminal does not answer within the `timeout` or the answer can not be parsed.
+pub fn query_background(timeout: Duration) -> Option<[u8; 3]> {
+    parse_response(&query(b"\x1b]11;?\x1b\\", timeout)?)
+}
+
+/// Query the width and height of a terminal cell in pixels.
+///
+/// Returns `None`, if the terminal does not answer within the `timeout` or the answer can not be parsed.
+pub fn query_cell_size(timeout: Duration) -> Option<(u32, u32)> {
+    parse_cell_size(&query(b"\x1b[16t", timeout)?)
+}
+
+/// Send the `request` to the terminal and return its answer.
+///
+/// Returns `None`, if the terminal can not be opened or its settings can not be changed.
+fn query(request: &[u8], timeout: Duration) -> Option<String> {
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+
+    //save the current settings, so they can be restored afterwards
+    let settings = stty(&["-g"])?;
+    //read without waiting for a line break, the time is given in tenths of a second
+    let tenths = (timeout.as_millis() / 100).clamp(1, 255).to_string();
+    stty(&["raw", "-echo", "min", "0", "time", &tenths])?;
+
+    let response = tty
+        .write_all(request)
+        .and_then(|_| tty.flush())
+        .ok()
+        .map(|_| read_response(&mut tty));
+
+    stty(&[settings.trim()]);
+    response
+}
+
+/// Run `stty` with the given arguments on the terminal and return its output.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(File::open("/dev/tty").ok()?)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        None
+    }
+}
+
+/// Read the answer of the terminal, until it is terminated or no more bytes arrive.
+fn read_response(tty: &mut File) -> String {
+    let mut response = Vec::new();
+    let mut byte = [0u8];
+    //a read of 0 bytes means that the timeout was reached
+    while let Ok(1) = tty.read(&mut byte) {
+        response.push(byte[0]);
+        //the answer ends with either BEL or ST (ESC \), reports of the window end with t
+        if byte[0] == 0x07
+            || response.ends_with(b"\x1b\\")
+            || (response.starts_with(b"\x1b[") && byte[0] == b't')
+        {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+/// Parse the rgb color of an OSC 11 answer.
+///
+/// Each channel can have 1 to 4 hex digits, which are scaled to 8 bit.
+///
+/// # Examples
+/// ```
+/// assert_eq!(Some([255, 0, 128]), parse_response("\x1b]11;rgb:ffff/0000/8080\x07"));
+/// ```
+fn parse_response(response: &str) -> Option<[u8; 3]> {
+    let start = response.find("rgb:")? + 4;
+    let end = response[start..]
+        .find(['\x07', '\x1b'])
+        .map_or(response.len(), |end| start + end);
+
+    let mut channels = response[start..end].split('/').map(|channel| {
+        if channel.is_empty() || channel.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        let max = 16u32.pow(channel.len() as u32) - 1;
+        Some((value * 255 + max / 2) / max)
+    });
+
+    let mut color = [0u8; 3];
+    for value in &mut color {
+        *value = channels.next()?? as u8;
+    }
+    if channels.next().is_some() {
+        return None;
+    }
+    Some(color)
+}
+
+#[cfg(test)]
+mod test_parse_response {
+    use super::*;
+
+    #[test]
+    fn four_digits_bel() {
+        assert_eq!(
+            Some([255, 0, 128]),
+            parse_response("\x1b]11;rgb:ffff/0000/8080\x07")
+        );
+    }
+
+    #[test]
+    fn two_digits_st() {
+        assert_eq!(
+            Some([30, 30, 30]),
+            parse_response("\x1b]11;rgb:1e/1e/1e\x1b\\")
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(None, parse_response(""));
+        assert_eq!(None, parse_response("\x1b]11;rgb:ffff/0000\x07"));
+        assert_eq!(None, parse_response("\x1b]11;rgb:gg/00/00\x07"));
+    }
+}
+
+/// Parse the width and height of a cell from a `CSI 16 t` answer.
+///
+/// The answer contains the height before the width, cells without a size are not valid.
+///
+/// # Examples
+/// ```
+/// assert_eq!(Some((8, 19)), parse_cell_size("\x1b[6;19;8t"));
+/// ```
+fn parse_cell_size(response: &str) -> Option<(u32, u32)> {
+    let values = response.strip_prefix("\x1b[6;")?.strip_suffix('t')?;
+    let (height, width) = values.split_once(';')?;
+    let (width, height) = (width.parse::<u32>().ok()?, height.parse::<u32>().ok()?);
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some((width, height))
+}
+
+#[cfg(test)]
+mod test_parse_cell_size {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(Some((8, 19)), parse_cell_size("\x1b[6;19;8t"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(None, parse_cell_size(""));
+        assert_eq!(None, parse_cell_size("\x1b[6;19t"));
+        assert_eq!(None, parse_cell_size("\x1b[6;0;8t"));
+        assert_eq!(None, parse_cell_size("\x1b[4;600;800t"));
+    }
+}
+
+/// Return the scale, at which the pixels of the image are square on the screen.
+///
+/// The scale is the ratio between the width and the height of a cell, limited to the same range as the `--ratio` argument.
+/// If the size of the cells is unknown, the default scale of 0.42 is used.
+///
+/// # Examples
+/// ```
+/// assert_eq!(0.5, square_scale(Some((10, 20))));
+/// ```
+pub fn square_scale(cell_size: Option<(u32, u32)>) -> f32 {
+    match cell_size {
+        Some((width, height)) => (width as f32 / height as f32).clamp(0.1f32, 1f32),
+        None => 0.42f32,
+    }
+}
+
+#[cfg(test)]
+mod test_square_scale {
+    use super::*;
+
+    #[test]
+    fn reported_geometry() {
+        assert_eq!(0.5, square_scale(Some((10, 20))));
+        let scale = square_scale(parse_cell_size("\x1b[6;19;8t"));
+        assert!((scale - 8f32 / 19f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn unknown_geometry() {
+        assert_eq!(0.42, square_scale(None));
+    }
+
+    #[test]
+    fn wide_cells_are_limited() {
+        assert_eq!(1f32, square_scale(Some((20, 10))));
+    }
+}
+
+/// Return the invert and minimum color luminance settings, which suit the background color.
+///
+/// A dark background inverts the characters and lifts dark colors, so they are still visible.
+/// A light background uses neither.
+pub fn settings(background: [u8; 3]) -> (bool, f32) {
+    let luminosity = artem::pixel::luminosity(
+        background[0],
+        background[1],
+        background[2],
+        artem::options::LuminanceWeights::DEFAULT,
+    );
+    if luminosity < 128f32 {
+        (true, 64f32)
+    } else {
+        (false, 0f32)
+    }
+}
+
+#[cfg(test)]
+mod test_settings {
+    use super::*;
+
+    #[test]
+    fn dark_background_inverts() {
+        let background = parse_response("\x1b]11;rgb:1e1e/1e1e/1e1e\x1b\\").unwrap();
+        assert_eq!((true, 64f32), settings(background));
+    }
+
+    #[test]
+    fn light_background() {
+        let background = parse_response("\x1b]11;rgb:ffff/ffff/ffff\x07").unwrap();
+        assert_eq!((false, 0f32), settings(background));
+    }
+}
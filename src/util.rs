@@ -1,6 +1,4 @@
-use std::{env, process};
-
-use log::error;
+use std::env;
 
 ///Returns if the terminal supports truecolor mode.
 ///
@@ -96,32 +94,50 @@ mod test_map_range {
     }
 }
 
-///Function for fatal errors.
-///
-///A fatal error is an error, from which the program can no recover, meaning the only option left ist to print
-/// an error message letting the user know what went wrong. For example if a non-existing file was passed in,
-/// this program can not work correctly and should print an error message and exit.
+/// Parse a hex color string into its RGB values.
 ///
-/// This function will print the passed in error message as well as a exit message, then it will exit the program with the exit code.
-/// If non is specified, it will use exit code 1 by default.
-/// A list of exit code can be found here: <https://www.freebsd.org/cgi/man.cgi?query=sysexits&apropos=0&sektion=0&manpath=FreeBSD+4.3-RELEASE&format=html>
+/// The color has to consist of six hexadecimal digits, optionally prefixed with a `#`.
+/// If the string is not a valid color, `None` is returned.
 ///
 /// # Examples
-/// ```no_run
-/// use std::fs::File;
-/// use artem::util::fatal_error;
+/// ```
+/// use artem::util::parse_hex_color;
 ///
-/// let f = File::open("hello.txt");
-/// let f = match f {
-///     Ok(file) => file,
-///     Err(error) => fatal_error(error.to_string().as_str(), Some(66)),
-/// };
+/// assert_eq!(Some([30, 144, 255]), parse_hex_color("#1e90ff"));
 /// ```
-pub fn fatal_error(message: &str, code: Option<i32>) -> ! {
-    //This function never returns, since it always exit the program
-    error!("{}", message);
-    error!("Artem exited with code: {}", code.unwrap_or(1));
-    process::exit(code.unwrap_or(1));
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let value = value.strip_prefix('#').unwrap_or(value);
+    if value.len() != 6 || !value.is_ascii() {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&value[index..index + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[cfg(test)]
+mod test_parse_hex_color {
+    use super::*;
+
+    #[test]
+    fn with_prefix() {
+        assert_eq!(Some([255, 0, 16]), parse_hex_color("#FF0010"));
+    }
+
+    #[test]
+    fn without_prefix() {
+        assert_eq!(Some([30, 144, 255]), parse_hex_color("1e90ff"));
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert_eq!(None, parse_hex_color("#fff"));
+    }
+
+    #[test]
+    fn invalid_digits() {
+        assert_eq!(None, parse_hex_color("#gg0000"));
+    }
 }
 
 /// Calculate image dimension related values.
@@ -164,7 +180,8 @@ pub fn calculate_dimensions(
 
             //calculate tiles
             let tile_width = width / columns;
-            let tile_height = (tile_width as f32 / scale).floor() as u32;
+            //a large scale would floor to a tile without any rows
+            let tile_height = ((tile_width as f32 / scale).floor() as u32).max(1);
 
             let rows = height / tile_height;
 
@@ -173,6 +190,83 @@ pub fn calculate_dimensions(
             (columns.max(1), rows.max(1), tile_width, tile_height)
         }
 
+        ResizingDimension::Fit(max_rows) => {
+            //try to use the full width first, the border takes up two additional rows
+            let by_width = calculate_dimensions(
+                target_size,
+                height,
+                width,
+                scale,
+                border,
+                ResizingDimension::Width,
+            );
+            let used_rows = by_width.1 + if border { 2 } else { 0 };
+
+            // minus 1, since the user input line is included
+            if used_rows < max_rows {
+                by_width
+            } else {
+                //the image is too high, so use the full height instead
+                //the height leaves a row for the user input line, so at least two rows are needed
+                calculate_dimensions(
+                    max_rows.max(2),
+                    height,
+                    width,
+                    scale,
+                    border,
+                    ResizingDimension::Height,
+                )
+            }
+        }
+
+        ResizingDimension::Contain(max_rows) => {
+            //use the full width, if the rows still fit, the border takes up two additional rows
+            let by_width = calculate_dimensions(
+                target_size,
+                height,
+                width,
+                scale,
+                border,
+                ResizingDimension::Width,
+            );
+            let used_rows = by_width.1 + if border { 2 } else { 0 };
+
+            if used_rows <= max_rows {
+                by_width
+            } else {
+                //the height is more constraining,
+                //plus 1, since the height calculation leaves a row for the user input line, which is not needed here
+                calculate_dimensions(
+                    max_rows + 1,
+                    height,
+                    width,
+                    scale,
+                    border,
+                    ResizingDimension::Height,
+                )
+            }
+        }
+
+        ResizingDimension::Exact { columns, rows } => {
+            //the grid is given, so the target size and scale are not used
+            let (mut columns, mut rows) = (columns, rows);
+
+            if border {
+                //remove a bit of space for the border
+                columns = columns.saturating_sub(2);
+                rows = rows.saturating_sub(2);
+            }
+            let (columns, rows) = (columns.max(1), rows.max(1));
+
+            //a grid larger than the image uses a single pixel for every tile, the image is enlarged to fill it
+            (
+                columns,
+                rows,
+                (width / columns).max(1),
+                (height / rows).max(1),
+            )
+        }
+
         ResizingDimension::Height => {
             let mut rows = if height > target_size {
                 // minus 1, since the user input line is included
@@ -220,6 +314,14 @@ mod test_calculate_dimensions {
         );
     }
 
+    #[test]
+    fn calculate_dimensions_width_large_scale() {
+        assert_eq!(
+            (80, 100, 1, 1),
+            calculate_dimensions(80, 100, 100, 5f32, false, ResizingDimension::Width)
+        );
+    }
+
     #[test]
     fn calculate_dimensions_height() {
         assert_eq!(
@@ -282,6 +384,146 @@ mod test_calculate_dimensions {
         );
     }
 
+    #[test]
+    fn calculate_dimensions_fit_wide_image() {
+        //the full width can be used, since the rows still fit
+        assert_eq!(
+            (100, 22, 10, 23),
+            calculate_dimensions(100, 512, 1024, 0.42, false, ResizingDimension::Fit(50))
+        );
+    }
+
+    #[test]
+    fn calculate_dimensions_fit_tall_image() {
+        //the full width would use too many rows
+        let (columns, rows, _, _) =
+            calculate_dimensions(100, 1024, 512, 0.42, false, ResizingDimension::Fit(50));
+        assert!(columns <= 100);
+        assert!(rows < 50);
+        assert_eq!(49, rows);
+    }
+
+    #[test]
+    fn calculate_dimensions_fit_with_border() {
+        for (height, width) in [(512, 512), (1024, 256), (256, 1024), (1080, 1920)] {
+            let (columns, rows, _, _) =
+                calculate_dimensions(80, height, width, 0.42, true, ResizingDimension::Fit(24));
+            //the border takes up two columns and rows
+            assert!(columns + 2 <= 80);
+            assert!(rows + 2 < 24);
+        }
+    }
+
+    #[test]
+    fn calculate_dimensions_fit_tiny_terminal() {
+        //a terminal with a single row still results in a single row
+        for max_rows in [0, 1, 2] {
+            assert_eq!(
+                (2, 1, 431, 1024),
+                calculate_dimensions(
+                    100,
+                    1024,
+                    1024,
+                    0.42,
+                    false,
+                    ResizingDimension::Fit(max_rows)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn calculate_dimensions_contain_wide_image() {
+        //the width is more constraining
+        assert_eq!(
+            (100, 22, 10, 23),
+            calculate_dimensions(100, 512, 1024, 0.42, false, ResizingDimension::Contain(60))
+        );
+    }
+
+    #[test]
+    fn calculate_dimensions_contain_tall_image() {
+        //the height is more constraining, the full width would use 512 rows
+        let (columns, rows, _, _) =
+            calculate_dimensions(200, 2048, 512, 0.42, false, ResizingDimension::Contain(60));
+        assert_eq!(60, rows);
+        assert!(columns <= 200);
+        //the aspect ratio is kept
+        assert_eq!(34, columns);
+    }
+
+    #[test]
+    fn calculate_dimensions_contain_with_border() {
+        for (height, width) in [(512, 512), (2048, 512), (512, 2048), (1080, 1920)] {
+            let (columns, rows, _, _) = calculate_dimensions(
+                200,
+                height,
+                width,
+                0.42,
+                true,
+                ResizingDimension::Contain(60),
+            );
+            //the border takes up two columns and rows
+            assert!(columns + 2 <= 200);
+            assert!(rows + 2 <= 60);
+        }
+    }
+
+    #[test]
+    fn calculate_dimensions_exact() {
+        //the scale and target size are ignored
+        assert_eq!(
+            (80, 24, 6, 21),
+            calculate_dimensions(
+                100,
+                512,
+                512,
+                0.42,
+                false,
+                ResizingDimension::Exact {
+                    columns: 80,
+                    rows: 24
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn calculate_dimensions_exact_with_border() {
+        let (columns, rows, _, _) = calculate_dimensions(
+            100,
+            512,
+            512,
+            0.42,
+            true,
+            ResizingDimension::Exact {
+                columns: 80,
+                rows: 24,
+            },
+        );
+        //the border takes up two columns and rows
+        assert_eq!((78, 22), (columns, rows));
+    }
+
+    #[test]
+    fn calculate_dimensions_exact_larger_than_image() {
+        //the tiles are at least a single pixel
+        assert_eq!(
+            (80, 24, 1, 1),
+            calculate_dimensions(
+                100,
+                10,
+                20,
+                0.42,
+                false,
+                ResizingDimension::Exact {
+                    columns: 80,
+                    rows: 24
+                }
+            )
+        );
+    }
+
     #[test]
     fn calculate_border_smaller_columns() {
         assert_eq!(
@@ -291,12 +533,104 @@ mod test_calculate_dimensions {
     }
 }
 
+/// Calculate the scale, at which the ascii image has the closest aspect ratio to the source image.
+///
+/// The `cell_ratio` is the ratio between the width and height of a terminal character. Since the tiles can only have
+/// whole pixel dimensions, a fixed scale can distort images with unusual aspect ratios. Instead, the number of rows
+/// is chosen first, so that the aspect ratio of the ascii image matches the image, and the scale is derived from it.
+/// The columns are calculated the same way as [`calculate_dimensions`] with the [`ResizingDimension::Width`].
+///
+/// # Examples
+/// ```
+/// use artem::util::{auto_scale, calculate_dimensions, ResizingDimension};
+///
+/// let scale = auto_scale(80, 100, 1000, 0.42, false);
+/// assert_eq!(
+///     (80, 3, 12, 33),
+///     calculate_dimensions(80, 100, 1000, scale, false, ResizingDimension::Width)
+/// );
+/// ```
+pub fn auto_scale(target_size: u32, height: u32, width: u32, cell_ratio: f32, border: bool) -> f32 {
+    let mut columns = target_size.min(width);
+    if border {
+        //remove a bit of space for the border
+        columns = columns.saturating_sub(2).max(1);
+    }
+    let tile_width = width / columns;
+
+    //the number of rows, which results in the same aspect ratio as the image
+    let rows = (columns as f32 * cell_ratio * height as f32 / width as f32)
+        .round()
+        .max(1f32);
+    let tile_height = (height as f32 / rows).floor().max(1f32);
+
+    //the tile height is calculated by flooring the tile width divided by the scale,
+    //so add half a pixel to prevent rounding errors from removing a whole pixel
+    tile_width as f32 / (tile_height + 0.5)
+}
+
+#[cfg(test)]
+mod test_auto_scale {
+    use super::*;
+
+    /// Return how much the aspect ratio of the ascii image differs from the image.
+    fn distortion(height: u32, width: u32, scale: f32, cell_ratio: f32) -> f32 {
+        let (columns, rows, _, _) =
+            calculate_dimensions(80, height, width, scale, false, ResizingDimension::Width);
+        let ascii_ratio = columns as f32 * cell_ratio / rows as f32;
+        (ascii_ratio / (width as f32 / height as f32) - 1f32).abs()
+    }
+
+    #[test]
+    fn very_wide_image_less_distorted() {
+        //the fixed scale only uses 2 rows, instead of almost 3
+        let auto = auto_scale(80, 100, 1200, 0.42, false);
+        assert!(distortion(100, 1200, auto, 0.42) < distortion(100, 1200, 0.42, 0.42));
+    }
+
+    #[test]
+    fn tiles_fit_into_image() {
+        //with a fixed scale, a single tile would be higher than the image
+        let (_, rows, _, tile_height) =
+            calculate_dimensions(80, 100, 4000, 0.42, false, ResizingDimension::Width);
+        assert!(rows * tile_height > 100);
+
+        let scale = auto_scale(80, 100, 4000, 0.42, false);
+        let (_, rows, _, tile_height) =
+            calculate_dimensions(80, 100, 4000, scale, false, ResizingDimension::Width);
+        assert_eq!(100, rows * tile_height);
+    }
+
+    #[test]
+    fn square_image_similar_to_fixed() {
+        let scale = auto_scale(100, 512, 512, 0.42, false);
+        assert_eq!(
+            (100, 42, 5, 12),
+            calculate_dimensions(100, 512, 512, scale, false, ResizingDimension::Width)
+        );
+    }
+
+    #[test]
+    fn border_columns() {
+        let scale = auto_scale(80, 100, 1000, 0.42, true);
+        let (columns, rows, _, _) =
+            calculate_dimensions(80, 100, 1000, scale, true, ResizingDimension::Width);
+        assert_eq!(78, columns);
+        assert_eq!(3, rows);
+    }
+}
+
 ///Preferred image resize direction
 ///
 ///This changes which dimensions should be used when resizing the image.
 ///For example, to fully use one dimension (e.g. width), the height can not be scaled
 ///up as well, since it already would be larger than the maximum terminal height.
 ///By default width will be used.
+///Fit uses as much of the width as possible, while the number of rows stays below the given maximum,
+///so the image fits into both dimensions of the terminal.
+///Contain uses the target size as the maximum width and the given value as the maximum number of rows,
+///the more constraining one of them is used.
+///Exact uses the given number of columns and rows, the tiles are stretched to fill the grid, ignoring the aspect ratio.
 ///
 /// # Examples
 /// ```
@@ -305,9 +639,13 @@ mod test_calculate_dimensions {
 /// assert_eq!(ResizingDimension::Width, ResizingDimension::default());
 /// ```
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResizingDimension {
     Width,
     Height,
+    Fit(u32),
+    Contain(u32),
+    Exact { columns: u32, rows: u32 },
 }
 //Implement `Default` as Width
 impl Default for ResizingDimension {
@@ -326,21 +664,188 @@ mod test_dimensions_enum {
     }
 }
 
-/// Return a spacer string, which can be used to center the ascii image in the middle of the terminal.
+///Placement of the image along the horizontal axis of the terminal
 ///
+///The image can either be placed at the start (left), in the center or at the end (right)
+///of the terminal. By default it will be placed at the start, which does not add any spacing.
+///
+/// # Examples
+/// ```
+/// use artem::util::HorizontalAnchor;
+///
+/// assert_eq!(HorizontalAnchor::Start, HorizontalAnchor::default());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HorizontalAnchor {
+    Start,
+    Center,
+    End,
+}
+
+//Implement `Default` as Start
+impl Default for HorizontalAnchor {
+    fn default() -> Self {
+        HorizontalAnchor::Start
+    }
+}
+
+impl HorizontalAnchor {
+    /// Split the free space into the (leading, trailing) padding.
+    ///
+    /// When centering an odd amount of free space, the remaining cell is added to the trailing padding.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::util::HorizontalAnchor;
+    ///
+    /// assert_eq!((10, 0), HorizontalAnchor::End.padding(10));
+    /// ```
+    pub fn padding(self, free: u32) -> (u32, u32) {
+        match self {
+            HorizontalAnchor::Start => (0, 0),
+            HorizontalAnchor::Center => (free / 2, free - free / 2),
+            HorizontalAnchor::End => (free, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_horizontal_anchor {
+    use super::*;
+
+    #[test]
+    fn default_is_start() {
+        assert_eq!(HorizontalAnchor::Start, HorizontalAnchor::default());
+    }
+
+    #[test]
+    fn start_has_no_padding() {
+        assert_eq!((0, 0), HorizontalAnchor::Start.padding(11));
+    }
+
+    #[test]
+    fn center_pads_both_sides() {
+        assert_eq!((5, 5), HorizontalAnchor::Center.padding(10));
+    }
+
+    #[test]
+    fn center_odd_pads_trailing_more() {
+        assert_eq!((5, 6), HorizontalAnchor::Center.padding(11));
+    }
+
+    #[test]
+    fn end_pads_only_left() {
+        assert_eq!((11, 0), HorizontalAnchor::End.padding(11));
+    }
+}
+
+///Placement of the image along the vertical axis of the terminal
+///
+///The image can either be placed at the start (top), in the center or at the end (bottom)
+///of the terminal. By default it will be placed at the start, which does not add any spacing.
+///
+/// # Examples
+/// ```
+/// use artem::util::VerticalAnchor;
+///
+/// assert_eq!(VerticalAnchor::Start, VerticalAnchor::default());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerticalAnchor {
+    Start,
+    Center,
+    End,
+}
+
+//Implement `Default` as Start
+impl Default for VerticalAnchor {
+    fn default() -> Self {
+        VerticalAnchor::Start
+    }
+}
+
+impl VerticalAnchor {
+    /// Split the free space into the (leading, trailing) padding.
+    ///
+    /// When centering an odd amount of free space, the remaining cell is added to the trailing padding.
+    ///
+    /// # Examples
+    /// ```
+    /// use artem::util::VerticalAnchor;
+    ///
+    /// assert_eq!((10, 0), VerticalAnchor::End.padding(10));
+    /// ```
+    pub fn padding(self, free: u32) -> (u32, u32) {
+        match self {
+            VerticalAnchor::Start => (0, 0),
+            VerticalAnchor::Center => (free / 2, free - free / 2),
+            VerticalAnchor::End => (free, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_vertical_anchor {
+    use super::*;
+
+    #[test]
+    fn default_is_start() {
+        assert_eq!(VerticalAnchor::Start, VerticalAnchor::default());
+    }
+
+    #[test]
+    fn start_has_no_padding() {
+        assert_eq!((0, 0), VerticalAnchor::Start.padding(11));
+    }
+
+    #[test]
+    fn center_pads_both_sides() {
+        assert_eq!((5, 5), VerticalAnchor::Center.padding(10));
+    }
+
+    #[test]
+    fn center_odd_pads_trailing_more() {
+        assert_eq!((5, 6), VerticalAnchor::Center.padding(11));
+    }
+
+    #[test]
+    fn end_pads_only_top() {
+        assert_eq!((11, 0), VerticalAnchor::End.padding(11));
+    }
+}
+
+/// Return a spacer string, which can be used to move the ascii image horizontally in the terminal.
+///
+/// Only the leading spacing is returned, since spaces after the image are not visible.
 /// When the terminal width is not existing, for example when the output is not a terminal, the returned string will be empty.
 ///
 /// # Example
 /// ```
-/// # use artem::util::spacing_horizontal;
-/// let  spacing = spacing_horizontal(10);
+/// # use artem::util::{spacing_horizontal, HorizontalAnchor};
+/// let  spacing = spacing_horizontal(10, HorizontalAnchor::Center);
 /// ```
-pub fn spacing_horizontal(width: u32) -> String {
+pub fn spacing_horizontal(width: u32, anchor: HorizontalAnchor) -> String {
     let term_width = match terminal_size::terminal_size() {
         Some(value) => value.0 .0 as u32,
         None => 0,
     };
-    " ".repeat(term_width.saturating_sub(width).saturating_div(2) as usize)
+    spacing_horizontal_in(term_width, width, anchor)
+}
+
+/// Return a spacer string, which moves an ascii image of the given width horizontally in a terminal of the given width.
+///
+/// This is the same as [`spacing_horizontal`], but does not query the size of the current terminal.
+///
+/// # Example
+/// ```
+/// # use artem::util::{spacing_horizontal_in, HorizontalAnchor};
+/// assert_eq!("    ", spacing_horizontal_in(20, 12, HorizontalAnchor::Center));
+/// ```
+pub fn spacing_horizontal_in(term_width: u32, width: u32, anchor: HorizontalAnchor) -> String {
+    let (leading, _) = anchor.padding(term_width.saturating_sub(width));
+    " ".repeat(leading as usize)
 }
 
 #[cfg(test)]
@@ -351,26 +856,49 @@ mod test_spacing_horizontal {
 
     #[test]
     fn empty_return_large_input() {
-        assert_eq!("", spacing_horizontal(u32::MAX))
+        assert_eq!("", spacing_horizontal(u32::MAX, HorizontalAnchor::Center))
     }
 }
 
-/// Return a spacer string, which can be used to center the ascii image in the middle of the terminal.
+/// Return the spacer strings, which can be used to move the ascii image vertically in the terminal.
 ///
-/// When the terminal height is not existing, for example when the output is not a terminal, the returned string will be empty.
+/// The first string should be placed above the image, the second one below it.
+/// When the terminal height is not existing, for example when the output is not a terminal, the returned strings will be empty.
 ///
 /// # Example
 /// ```
-/// # use artem::util::spacing_vertical;
-/// let  spacing = spacing_vertical(10);
+/// # use artem::util::{spacing_vertical, VerticalAnchor};
+/// let  (top, bottom) = spacing_vertical(10, VerticalAnchor::Center);
 /// ```
-pub fn spacing_vertical(height: u32) -> String {
+pub fn spacing_vertical(height: u32, anchor: VerticalAnchor) -> (String, String) {
     let term_height = match terminal_size::terminal_size() {
         Some(value) => value.1 .0 as u32,
         None => 0,
     };
+    spacing_vertical_in(term_height, height, anchor)
+}
+
+/// Return the spacer strings, which move an ascii image of the given height vertically in a terminal of the given height.
+///
+/// This is the same as [`spacing_vertical`], but does not query the size of the current terminal.
+///
+/// # Example
+/// ```
+/// # use artem::util::{spacing_vertical_in, VerticalAnchor};
+/// let (top, bottom) = spacing_vertical_in(20, 7, VerticalAnchor::Center);
+/// assert_eq!((6, 7), (top.len(), bottom.len()));
+/// ```
+pub fn spacing_vertical_in(
+    term_height: u32,
+    height: u32,
+    anchor: VerticalAnchor,
+) -> (String, String) {
     log::trace!("H: {term_height}, h: {height}");
-    "\n".repeat(term_height.saturating_sub(height).saturating_div(2) as usize)
+    let (leading, trailing) = anchor.padding(term_height.saturating_sub(height));
+    (
+        "\n".repeat(leading as usize),
+        "\n".repeat(trailing as usize),
+    )
 }
 
 #[cfg(test)]
@@ -381,6 +909,156 @@ mod test_spacing_vertical {
 
     #[test]
     fn empty_return_large_input() {
-        assert_eq!("", spacing_vertical(u32::MAX))
+        assert_eq!(
+            (String::new(), String::new()),
+            spacing_vertical(u32::MAX, VerticalAnchor::Center)
+        )
+    }
+}
+
+/// Return the width of the ruler column left of the image, including the space separating it from the image.
+///
+/// It is large enough to contain the index of the last marked row.
+///
+/// # Example
+/// ```
+/// # use artem::util::ruler_width;
+/// assert_eq!(3, ruler_width(46));
+/// ```
+pub fn ruler_width(rows: u32) -> usize {
+    //only every 10th row is marked
+    (rows.saturating_sub(1) / 10 * 10).to_string().len() + 1
+}
+
+#[cfg(test)]
+mod test_ruler_width {
+    use super::*;
+
+    #[test]
+    fn single_digit() {
+        assert_eq!(2, ruler_width(10));
+    }
+
+    #[test]
+    fn two_digits() {
+        assert_eq!(3, ruler_width(11));
+    }
+
+    #[test]
+    fn three_digits() {
+        assert_eq!(4, ruler_width(230));
+    }
+}
+
+/// Return the ruler row above the image, which marks every 10th column with its index.
+///
+/// The `offset` is the number of spaces before the first column, for example for the ruler column or the border.
+/// The ruler is cut off after the last column.
+///
+/// # Example
+/// ```
+/// # use artem::util::ruler_columns;
+/// assert_eq!("  0         10", ruler_columns(15, 2));
+/// ```
+pub fn ruler_columns(columns: u32, offset: usize) -> String {
+    let mut ruler = vec![' '; columns as usize];
+    for column in (0..columns as usize).step_by(10) {
+        for (char, position) in column.to_string().chars().zip(column..ruler.len()) {
+            ruler[position] = char;
+        }
+    }
+    let ruler: String = ruler.into_iter().collect();
+    format!("{}{}", " ".repeat(offset), ruler.trim_end())
+}
+
+#[cfg(test)]
+mod test_ruler_columns {
+    use super::*;
+
+    #[test]
+    fn marks_every_10th_column() {
+        assert_eq!("0         10        20        30", ruler_columns(35, 0));
+    }
+
+    #[test]
+    fn label_is_cut_off() {
+        assert_eq!("0         1", ruler_columns(11, 0));
+    }
+
+    #[test]
+    fn with_offset() {
+        assert_eq!("   0", ruler_columns(5, 3));
+    }
+}
+
+/// Return the ruler for the start of a row, which contains the index of every 10th row.
+///
+/// The other rows only contain spaces, so all rows have the same `width`.
+///
+/// # Example
+/// ```
+/// # use artem::util::ruler_row;
+/// assert_eq!("10 ", ruler_row(10, 3));
+/// assert_eq!("   ", ruler_row(11, 3));
+/// ```
+pub fn ruler_row(row: u32, width: usize) -> String {
+    if row % 10 == 0 {
+        format!("{:>width$} ", row, width = width.saturating_sub(1))
+    } else {
+        " ".repeat(width)
+    }
+}
+
+#[cfg(test)]
+mod test_ruler_row {
+    use super::*;
+
+    #[test]
+    fn marked_row_is_aligned() {
+        assert_eq!("  0 ", ruler_row(0, 4));
+        assert_eq!("120 ", ruler_row(120, 4));
+    }
+
+    #[test]
+    fn unmarked_row_is_empty() {
+        assert_eq!("    ", ruler_row(7, 4));
+    }
+}
+
+/// Return true, if any of the characters is wide, so it takes up two columns in the terminal.
+///
+/// Wide characters are, for example, the full-width forms of the east asian characters.
+/// Characters with an unknown width, such as control characters, are not counted as wide.
+///
+/// # Examples
+/// ```
+/// use artem::util::has_wide_chars;
+///
+/// assert!(has_wide_chars("\u{FF2D}\u{FF37} "));
+/// assert!(!has_wide_chars("MW "));
+/// ```
+pub fn has_wide_chars(characters: &str) -> bool {
+    characters
+        .chars()
+        .any(|char| unicode_width::UnicodeWidthChar::width(char) == Some(2))
+}
+
+#[cfg(test)]
+mod test_has_wide_chars {
+    use super::*;
+
+    #[test]
+    fn ascii_is_narrow() {
+        assert!(!has_wide_chars("MWNXK0Okxdolc:;,'...   "));
+    }
+
+    #[test]
+    fn full_width_is_wide() {
+        assert!(has_wide_chars("\u{FF2D}\u{FF37}\u{3000}"));
+    }
+
+    #[test]
+    fn single_wide_char() {
+        assert!(has_wide_chars("MW\u{FF2D} "));
     }
 }
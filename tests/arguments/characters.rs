@@ -44,7 +44,7 @@ pub mod characters {
                 .args(["-c", arg]);
             //only check first line
             cmd.assert().success().stdout(predicate::str::starts_with(
-                "aaaaaaaaaa6666666654444444432222222200000000b++++++++---------........aaaaaaaaaa",
+                "aaaaaaaaaa6666666665555555542222222211111111b:::::::+=========,,,,,,,,aaaaaaaaaa",
             ));
         }
     }
@@ -74,4 +74,416 @@ pub mod characters {
             ));
         }
     }
+
+    #[test]
+    fn arg_preset_3_braille_b() {
+        for arg in ["braille", "b", "3"] {
+            let mut cmd = Command::cargo_bin("artem").unwrap();
+            cmd.arg("assets/images/standard_test_img.png")
+                .args(["-c", arg]);
+            let output = cmd.assert().success().get_output().stdout.clone();
+            //only check first line
+            let output = String::from_utf8(output).unwrap();
+            let first_line = output.lines().next().unwrap();
+            assert!(first_line
+                .chars()
+                .all(|char| char == ' ' || ('\u{2800}'..='\u{28FF}').contains(&char)));
+        }
+    }
+}
+
+pub mod characters_file {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--characters-file");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--characters-file <characters-file>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn file_does_not_exist() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--characters-file", "non-existing/ramp.txt"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not read characters file non-existing/ramp.txt",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_characters() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--characters-file",
+            "ramp.txt",
+            "-c",
+            "0",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--characters-file <characters-file>' cannot be used with '--characters <characters>'",
+        ));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn arg_is_correct() {
+        //only the first line should be used
+        fs::write("/tmp/artem_ramp.txt", "M0123-.\nunused line").unwrap();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--characters-file", "/tmp/artem_ramp.txt"]);
+        //only check first line, it should be the same as using -c
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            "333333333311111111111111111122222222222222223-----------------........3333333333",
+        ));
+
+        //delete ramp file
+        fs::remove_file("/tmp/artem_ramp.txt").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn empty_file() {
+        fs::write("/tmp/artem_empty_ramp.txt", "").unwrap();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--characters-file", "/tmp/artem_empty_ramp.txt"]);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("Characters cannot be empty"));
+
+        //delete ramp file
+        fs::remove_file("/tmp/artem_empty_ramp.txt").unwrap();
+    }
+}
+
+pub mod ramp_cycle {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--ramp-cycle");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--ramp-cycle <ramp-cycle>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_empty() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--ramp-cycle",
+            "MW. ",
+            "--ramp-cycle",
+            "",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Characters cannot be empty",
+        ));
+    }
+
+    #[test]
+    fn rows_alternate() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--ramp-cycle",
+            "ABC ",
+            "--ramp-cycle",
+            "xyz ",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        for (index, line) in output.lines().enumerate() {
+            let ramp = if index % 2 == 0 { "ABC " } else { "xyz " };
+            assert!(line.chars().all(|char| ramp.contains(char)));
+        }
+    }
+}
+
+pub mod pad_characters {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--pad-characters", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn single_character_warns() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--characters", "#", "--no-color"]);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "[WARN ] A single character results in a solid block",
+            ))
+            .stdout(predicate::str::contains(" ").not());
+    }
+
+    #[test]
+    fn single_character_is_padded() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--characters",
+            "#",
+            "--no-color",
+            "--pad-characters",
+        ]);
+        let output = cmd
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("A single character").not())
+            .get_output()
+            .stdout
+            .clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains('#'));
+        assert!(output.contains(' '));
+    }
+}
+
+pub mod density_channel {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--density-channel", "cyan"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"cyan\" isn't a valid value for '--density-channel <density-channel>'",
+        ));
+    }
+
+    #[test]
+    fn luminosity_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--density-channel", "luminosity"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn red_changes_characters() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--density-channel", "red"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod rounding {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--rounding", "ceil"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"ceil\" isn't a valid value for '--rounding <rounding>'",
+        ));
+    }
+
+    #[test]
+    fn floor_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--rounding", "floor"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn round_changes_characters() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--rounding", "round"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod pattern_match {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--pattern-match", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--characters", "MW. ", "--no-color"]);
+        let plain = cmd.assert().success().get_output().stdout.clone();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--characters",
+            "MW. ",
+            "--no-color",
+            "--pattern-match",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        assert_ne!(plain, output.as_bytes());
+        //the edges of the image use the glyphs
+        assert!(output.chars().any(|char| !"MW. \n".contains(char)));
+        assert_eq!(
+            String::from_utf8(plain).unwrap().lines().count(),
+            output.lines().count()
+        );
+    }
+}
+
+pub mod braille {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--braille", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_pattern_match() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--braille", "--pattern-match"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--braille' cannot be used with '--pattern-match'",
+        ));
+    }
+
+    #[test]
+    fn threshold_requires_braille() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--braille-threshold", "100"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The following required arguments were not provided",
+        ));
+    }
+
+    #[test]
+    fn invalid_threshold() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--braille",
+            "--braille-threshold",
+            "300",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with braille threshold input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--braille", "--no-color"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output
+            .chars()
+            .all(|char| char == '\n' || ('\u{2800}'..='\u{28FF}').contains(&char)));
+    }
+}
+
+pub mod check_width {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--check-width", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn narrow_characters_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--check-width");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn wide_characters_warn() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--characters",
+            "\u{FF2D}\u{FF37}\u{3000}",
+            "--no-color",
+            "--check-width",
+        ]);
+        cmd.assert().success().stderr(predicate::str::contains(
+            "[WARN ] The characters contain wide characters, which take up two columns, the number of columns is halved",
+        ));
+    }
 }
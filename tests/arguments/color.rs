@@ -25,6 +25,101 @@ pub mod invert {
     }
 }
 
+pub mod auto_invert {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_conflict_invert() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--auto-invert", "--invert"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--auto-invert' cannot be used with '--invert'",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_both_themes() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--auto-invert", "--both-themes"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--auto-invert' cannot be used with '--both-themes'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--auto-invert");
+        cmd.assert().success();
+    }
+}
+
+pub mod detect_background {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_conflict_invert() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--detect-background", "--invert"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--detect-background' cannot be used with '--invert'",
+        ));
+    }
+
+    #[test]
+    fn not_interactive_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //the output is not a terminal, so the settings are not changed
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--detect-background");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod both_themes {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--both-themes", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--both-themes");
+        //the normal image is followed by the inverted one
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()))
+            .stdout(predicate::str::contains(
+                "\n--- inverted ---\ndddddddddd'''''''',,,,,,,,,;::::::::ccccccccx00000000KKKKKKKKKNNNNNNNNdddddddddd",
+            ));
+    }
+}
+
 pub mod no_color {
     use assert_cmd::prelude::*;
     use predicates::prelude::*;
@@ -102,3 +197,587 @@ pub mod background_color {
             .stdout(predicate::str::starts_with(load_correct_file()));
     }
 }
+
+pub mod grayscale {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--grayscale", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--grayscale", "--no-color"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--grayscale' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--grayscale");
+        //colors are not used when not printing to a terminal
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod ansi_tie_break {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--ansi-tie-break", "dark"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"dark\" isn't a valid value for '--ansi-tie-break <ansi-tie-break>'",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--ansi-tie-break",
+            "bright",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--ansi-tie-break <ansi-tie-break>' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--ansi-tie-break", "bright"]);
+        //colors are not used when not printing to a terminal
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod backdrop {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--backdrop");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--backdrop <backdrop>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_invalid_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--backdrop", "#12345z"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with backdrop color input value",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--backdrop",
+            "#1e1e1e",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--backdrop <backdrop>' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--backdrop", "#1e1e1e"]);
+        //colors are not used when not printing to a terminal
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn every_row_starts_with_backdrop() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--backdrop", "#1e1e1e"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        for line in output.lines() {
+            assert!(line.starts_with("\x1b[48;2;30;30;30m"));
+            assert!(line.ends_with("\x1b[0m"));
+        }
+    }
+}
+
+pub mod color_distance {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-distance", "manhattan"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"manhattan\" isn't a valid value for '--color-distance <color-distance>'",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--color-distance",
+            "lab",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--color-distance <color-distance>' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--color-distance",
+            "weighted",
+            "--web-safe",
+        ]);
+        //the characters are not changed by the distance
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod color_step {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--color-step");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--color-step <color-step>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-step", "string"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with color step input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_larger_max() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-step", "256"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with color step input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-step", "16"]);
+        //the characters are not changed by the color step
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod color_fg {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-fg", "green"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with foreground color input value",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--color-fg",
+            "#00ff00",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--color-fg <color-fg>' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn all_chars_fixed_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force true colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .env("COLORTERM", "truecolor")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--color-fg", "#00ff00"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        let colors: Vec<&str> = output
+            .split("\x1b[38;2;")
+            .skip(1)
+            .map(|sequence| &sequence[..sequence.find('m').unwrap()])
+            .collect();
+        assert!(!colors.is_empty());
+        assert!(colors.iter().all(|color| *color == "0;255;0"));
+    }
+}
+
+pub mod web_safe {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--web-safe", "--no-color"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--web-safe' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn colors_are_web_safe() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force true colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .env("COLORTERM", "truecolor")
+            .arg("assets/images/standard_test_img.png")
+            .arg("--web-safe");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        let colors: Vec<u8> = output
+            .split("\x1b[38;2;")
+            .skip(1)
+            .flat_map(|sequence| {
+                let end = sequence.find('m').unwrap();
+                sequence[..end]
+                    .split(';')
+                    .map(|value| value.parse::<u8>().unwrap())
+                    .collect::<Vec<u8>>()
+            })
+            .collect();
+        assert!(!colors.is_empty());
+        assert!(colors.iter().all(|value| value % 51 == 0));
+    }
+}
+
+pub mod min_brightness {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--min-brightness");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--min-brightness <min-brightness>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--min-brightness", "string"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with min brightness input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--min-brightness", "64"]);
+        //the characters are not changed by the brightness
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod palette {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--palette");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--palette <palette>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_invalid_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--palette", "#000000,#12345z"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with palette input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--palette", "#000000,#ffffff"]);
+        //the characters are not changed by the palette
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn only_palette_colors() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--palette", "#000000,#ffffff", "--color-dither"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        for color in output.split("\x1b[38;2;").skip(1) {
+            assert!(color.starts_with("0;0;0m") || color.starts_with("255;255;255m"));
+        }
+    }
+}
+
+pub mod color_dither {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_requires_palette() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--color-dither");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The following required arguments were not provided:",
+        ));
+    }
+}
+
+pub mod edge_color {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--edge-color", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--edge-color", "--no-color"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--edge-color' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--edge-color");
+        //the characters are not changed
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod max_color_switches {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--max-color-switches");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--max-color-switches <max-color-switches>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_zero() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-color-switches", "0"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not work with max color switches input value",
+        ));
+    }
+
+    #[test]
+    fn arg_conflicts_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--max-color-switches",
+            "4",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--max-color-switches <max-color-switches>' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-color-switches", "4"]);
+        //the characters are not changed by merging the colors
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod half_blocks {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--half-blocks", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflicts_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--half-blocks", "--no-color"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--half-blocks' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn plain_file_is_rejected() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--half-blocks",
+            "--output",
+            "/tmp/half_blocks.txt",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Half blocks are only supported in the terminal and in .ans files\n[ERROR] Artem exited with code: 64\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--half-blocks");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .all(|line| line.matches('▀').count() == 80));
+    }
+}
@@ -98,6 +98,86 @@ pub mod input {
     }
 }
 
+pub mod stdin_input {
+    //the command of assert_cmd can write to stdin
+    use assert_cmd::Command;
+    use predicates::prelude::*;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn correct_input() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+
+        cmd.arg("-")
+            .write_stdin(std::fs::read("assets/images/standard_test_img.png").unwrap());
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn empty_input() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+
+        cmd.arg("-").write_stdin("");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] No image data was given on stdin",
+        ));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+
+        cmd.arg("-").write_stdin("not an image");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("Artem exited with code: 66"));
+    }
+
+    #[test]
+    fn combined_with_file() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+
+        cmd.args(["assets/images/standard_test_img.png", "-"])
+            .write_stdin(std::fs::read("assets/images/standard_test_img.png").unwrap());
+
+        let mut ascii_img = String::new();
+        //the file is converted first, then the image from stdin
+        ascii_img.push_str(&load_correct_file());
+        ascii_img.push('\n');
+        ascii_img.push_str(&load_correct_file());
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(ascii_img));
+    }
+
+    #[test]
+    fn animate_input() {
+        let mut file_cmd = Command::cargo_bin("artem").unwrap();
+        file_cmd
+            .arg("assets/images/animated_test_img.gif")
+            .arg("--animate");
+        let expected = file_cmd.assert().success().get_output().stdout.clone();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.args(["-", "--animate"])
+            .write_stdin(std::fs::read("assets/images/animated_test_img.gif").unwrap());
+        cmd.assert().success().stdout(expected);
+    }
+
+    #[test]
+    fn animate_not_a_gif() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.args(["-", "--animate"])
+            .write_stdin(std::fs::read("assets/images/standard_test_img.png").unwrap());
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not read frames of stdin",
+        ));
+    }
+}
+
 #[cfg(feature = "web_image")]
 pub mod url_input {
     use assert_cmd::prelude::*; // Add methods on commands
@@ -175,3 +255,240 @@ pub mod url_input {
         ));
     }
 }
+
+#[cfg(feature = "config")]
+pub mod config {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--config");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--config <config>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn file_does_not_exist() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_does_not_exist.toml"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not read config file /tmp/artem_does_not_exist.toml",
+        ));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn unknown_key() {
+        fs::write("/tmp/artem_config_unknown.toml", "unknown = 40\n").unwrap();
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_unknown.toml"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Unknown config key unknown",
+        ));
+        fs::remove_file("/tmp/artem_config_unknown.toml").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn file_values_are_used() {
+        fs::write("/tmp/artem_config_used.json", r#"{"size": 40}"#).unwrap();
+        let expected = Command::cargo_bin("artem")
+            .unwrap()
+            .arg("assets/images/standard_test_img.png")
+            .args(["--size", "40"])
+            .output()
+            .unwrap()
+            .stdout;
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_used.json"]);
+        cmd.assert().success().stdout(expected);
+        fs::remove_file("/tmp/artem_config_used.json").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn command_line_overrides_file() {
+        fs::write("/tmp/artem_config_override.toml", "size = 40\n").unwrap();
+        let expected = Command::cargo_bin("artem")
+            .unwrap()
+            .arg("assets/images/standard_test_img.png")
+            .args(["--size", "60"])
+            .output()
+            .unwrap()
+            .stdout;
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_override.toml"])
+            .args(["--size", "60"]);
+        cmd.assert().success().stdout(expected);
+        fs::remove_file("/tmp/artem_config_override.toml").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn command_line_overrides_conflicting_keys() {
+        //both keys conflict with --size, so they are ignored instead of failing
+        fs::write(
+            "/tmp/artem_config_conflict.toml",
+            "width = true\nmax-width = 30\n",
+        )
+        .unwrap();
+        let expected = Command::cargo_bin("artem")
+            .unwrap()
+            .arg("assets/images/standard_test_img.png")
+            .args(["--size", "60"])
+            .output()
+            .unwrap()
+            .stdout;
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_conflict.toml"])
+            .args(["--size", "60"]);
+        cmd.assert().success().stdout(expected);
+        fs::remove_file("/tmp/artem_config_conflict.toml").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn library_options_are_used() {
+        fs::write(
+            "/tmp/artem_config_library.toml",
+            "target_size = 40\ninvert = true\n",
+        )
+        .unwrap();
+        let expected = Command::cargo_bin("artem")
+            .unwrap()
+            .arg("assets/images/standard_test_img.png")
+            .args(["--size", "40", "--invert"])
+            .output()
+            .unwrap()
+            .stdout;
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_library.toml"]);
+        cmd.assert().success().stdout(expected);
+        fs::remove_file("/tmp/artem_config_library.toml").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn command_line_overrides_library_options() {
+        fs::write(
+            "/tmp/artem_config_library_override.json",
+            r#"{"target_size": 40, "invert": true}"#,
+        )
+        .unwrap();
+        let expected = Command::cargo_bin("artem")
+            .unwrap()
+            .arg("assets/images/standard_test_img.png")
+            .args(["--size", "60", "--invert"])
+            .output()
+            .unwrap()
+            .stdout;
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_library_override.json"])
+            .args(["--size", "60"]);
+        cmd.assert().success().stdout(expected);
+        fs::remove_file("/tmp/artem_config_library_override.json").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn invalid_library_option() {
+        fs::write(
+            "/tmp/artem_config_library_invalid.toml",
+            "target_size = \"large\"\n",
+        )
+        .unwrap();
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--config", "/tmp/artem_config_library_invalid.toml"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Could not parse config file /tmp/artem_config_library_invalid.toml",
+        ));
+        fs::remove_file("/tmp/artem_config_library_invalid.toml").unwrap();
+    }
+}
+
+pub mod frame {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frame", "string"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with frame input value",
+        ));
+    }
+
+    #[test]
+    fn arg_out_of_range() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frame", "3"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Frame 3 is out of range, the image has 3 frames",
+        ));
+    }
+
+    #[test]
+    fn arg_not_a_gif() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--frame", "1"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Selecting a frame is only supported for gif images",
+        ));
+    }
+
+    #[test]
+    fn frame_differs() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif");
+        let first = cmd.assert().success().get_output().stdout.clone();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frame", "1"]);
+        let second = cmd.assert().success().get_output().stdout.clone();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn frame_zero_is_default() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif");
+        let default = cmd.assert().success().get_output().stdout.clone();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frame", "0"]);
+        cmd.assert().success().stdout(default);
+    }
+}
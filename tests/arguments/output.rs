@@ -3,6 +3,8 @@ pub mod output_file {
     use predicates::prelude::*;
     use std::{fs, process::Command};
 
+    use crate::common::load_correct_file;
+
     #[test]
     fn arg_is_none() {
         let mut cmd = Command::cargo_bin("artem").unwrap();
@@ -36,12 +38,64 @@ pub mod output_file {
             .args(["-o", "/tmp/ascii.html"]);
         //only check first line
         cmd.assert().success().stdout(predicate::str::starts_with(
-            "Written 77361 bytes to /tmp/ascii.html",
+            "Written 62673 bytes to /tmp/ascii.html",
         ));
         //delete output file
         fs::remove_file("/tmp/ascii.html").unwrap();
     }
 
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn file_is_bbcode() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["-o", "/tmp/ascii.bbcode"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with("Written"));
+        let content = fs::read_to_string("/tmp/ascii.bbcode").unwrap();
+        assert!(content.starts_with("[color=#"));
+        //delete output file
+        fs::remove_file("/tmp/ascii.bbcode").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn file_is_rle() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["-o", "/tmp/ascii.rle"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with("Written"));
+        let content = fs::read_to_string("/tmp/ascii.rle").unwrap();
+        assert!(content.starts_with("10x:8xO9xk1xx8xd8xo1x;17x.8x 10x:\n"));
+        //delete output file
+        fs::remove_file("/tmp/ascii.rle").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn file_is_json_lines() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["-o", "/tmp/ascii.jsonl"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with("Written"));
+        let content = fs::read_to_string("/tmp/ascii.jsonl").unwrap();
+        //the same number of rows as the plain text
+        assert_eq!(load_correct_file().lines().count(), content.lines().count());
+        assert!(content
+            .lines()
+            .all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok()));
+        //delete output file
+        fs::remove_file("/tmp/ascii.jsonl").unwrap();
+    }
+
     #[test]
     //windows does not like this test, it can not create the file
     #[cfg(not(target_os = "windows"))]
@@ -106,3 +160,933 @@ pub mod verbosity {
             .stderr(predicate::str::contains("ERROR"));
     }
 }
+
+pub mod clear {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--clear", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--clear");
+        //the image starts after the clear sequence
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(format!(
+                "\x1b[2J\x1b[H{}",
+                load_correct_file()
+            )));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn file_is_not_cleared() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--clear",
+            "-o",
+            "/tmp/artem_clear.html",
+        ]);
+        cmd.assert().success();
+        let content = fs::read_to_string("/tmp/artem_clear.html").unwrap();
+        assert!(!content.contains("\x1b[2J\x1b[H"));
+        //delete output file
+        fs::remove_file("/tmp/artem_clear.html").unwrap();
+    }
+}
+
+pub mod animate {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--animate", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_both_themes() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--animate", "--both-themes"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--animate' cannot be used with '--both-themes'",
+        ));
+    }
+
+    #[test]
+    fn not_a_gif() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--animate");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not read frames of assets/images/standard_test_img.png",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .arg("--animate");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("#!/bin/sh\n"));
+        //every frame keeps its delay
+        assert_eq!(
+            vec!["sleep 0.1", "sleep 0.25", "sleep 0.04"],
+            output
+                .lines()
+                .filter(|line| line.starts_with("sleep"))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+pub mod frames {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_conflict_animate() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frames", "--animate"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--frames' cannot be used with '--animate'",
+        ));
+    }
+
+    #[test]
+    fn not_a_gif() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--frames");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not read frames of assets/images/standard_test_img.png",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frames", "--size", "10"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        //the terminal is cleared before every frame
+        assert_eq!(3, output.matches("\x1b[2J\x1b[H").count());
+        assert!(output.starts_with("\x1b[2J\x1b[H"));
+    }
+
+    #[test]
+    fn delimiter() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif").args([
+            "--frames",
+            "--size",
+            "10",
+            "--frame-delimiter",
+            "---",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(2, output.lines().filter(|line| *line == "---").count());
+        assert!(!output.contains("\x1b[2J"));
+    }
+
+    #[test]
+    fn delimiter_requires_frames() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--frame-delimiter", "---"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The following required arguments were not provided",
+        ));
+    }
+}
+
+pub mod ruler {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--ruler", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--ruler");
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            "   0         10        20        30        40        50        60        70\n 0 ",
+        ));
+    }
+}
+
+pub mod inline_image {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--inline-image");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--inline-image <inline-image>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_invalid_protocol() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--inline-image", "sixel"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"sixel\" isn't a valid value for '--inline-image <inline-image>'",
+        ));
+    }
+
+    #[test]
+    fn forced_iterm() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--inline-image", "iterm"]);
+        //the png signature is encoded at the start of the data
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with("\x1b]1337;File=inline=1;size="))
+            .stdout(predicate::str::contains(":iVBORw0KGgo"))
+            .stdout(predicate::str::ends_with("\x07\n"));
+    }
+
+    #[test]
+    fn forced_kitty() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--inline-image", "kitty"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(
+                "\x1b_Gf=100,a=T,m=1;iVBORw0KGgo",
+            ))
+            .stdout(predicate::str::contains("\x1b_Gm=0;"));
+    }
+
+    #[test]
+    fn auto_falls_back_to_ascii() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.env_remove("TERM_PROGRAM")
+            .env_remove("LC_TERMINAL")
+            .env_remove("KITTY_WINDOW_ID")
+            .env("TERM", "xterm-256color")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--inline-image", "auto"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod line_height {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--line-height");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--line-height <line-height>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_zero() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--line-height", "0"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not work with line height input value",
+        ));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn css_is_set() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--line-height",
+            "0.8",
+            "-o",
+            "/tmp/line_height.html",
+        ]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with("Written"));
+        let content = fs::read_to_string("/tmp/line_height.html").unwrap();
+        assert!(content.contains("pre {line-height: 0.8;}"));
+        //delete output file
+        fs::remove_file("/tmp/line_height.html").unwrap();
+    }
+}
+
+pub mod dump_codepoints {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--dump-codepoints", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn space_is_reported() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--dump-codepoints");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("U+0020 ' '\n"))
+            .stdout(predicate::str::contains("U+002E '.'\n"));
+    }
+
+    #[test]
+    fn no_color_sequences() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .arg("assets/images/standard_test_img.png")
+            .arg("--dump-codepoints");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("U+001B").not());
+    }
+}
+
+pub mod label {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_conflict_animate() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--label", "--animate"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--label' cannot be used with '--animate'",
+        ));
+    }
+
+    #[test]
+    fn labels_above_images() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.args([
+            "assets/images/standard_test_img.png",
+            "assets/images/moth.jpg",
+            "--label",
+            "--no-color",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        //the first label is the first line
+        assert!(output.starts_with("assets/images/standard_test_img.png\n"));
+        //the second image follows the first one
+        let second = output.find("\nassets/images/moth.jpg\n").unwrap();
+        assert!(second > "assets/images/standard_test_img.png\n".len());
+    }
+
+    #[test]
+    fn no_label_in_html() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--label", "-o", "/tmp/label.html"]);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains("Html files do not support labels"));
+        let content = fs::read_to_string("/tmp/label.html").unwrap();
+        assert!(!content.contains("standard_test_img.png"));
+        //delete output file
+        fs::remove_file("/tmp/label.html").unwrap();
+    }
+}
+
+pub mod comment {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--comment");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--comment <comment>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn lines_are_prefixed() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--comment", ";; ", "--border"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.lines().all(|line| line.starts_with(";; ")));
+        //the colors would break the comment
+        assert!(!output.contains('\x1b'));
+    }
+}
+
+pub mod encoding {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn output_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--encoding", "ascii"]);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--output"));
+    }
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--encoding",
+            "utf16",
+            "-o",
+            "/tmp/encoding_invalid.txt",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"utf16\" isn't a valid value for '--encoding <encoding>'",
+        ));
+    }
+
+    #[test]
+    fn utf8_bom() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--encoding",
+            "utf8-bom",
+            "-o",
+            "/tmp/encoding_bom.txt",
+        ]);
+        cmd.assert().success();
+        let content = fs::read("/tmp/encoding_bom.txt").unwrap();
+        assert_eq!([0xEF, 0xBB, 0xBF], content[..3]);
+        //delete output file
+        fs::remove_file("/tmp/encoding_bom.txt").unwrap();
+    }
+
+    #[test]
+    fn utf8_without_bom() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--encoding",
+            "utf8",
+            "-o",
+            "/tmp/encoding_utf8.txt",
+        ]);
+        cmd.assert().success();
+        let content = fs::read("/tmp/encoding_utf8.txt").unwrap();
+        assert_ne!([0xEF, 0xBB, 0xBF], content[..3]);
+        //delete output file
+        fs::remove_file("/tmp/encoding_utf8.txt").unwrap();
+    }
+
+    #[test]
+    fn ascii_border() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--border",
+            "--encoding",
+            "ascii",
+            "-o",
+            "/tmp/encoding_ascii.txt",
+        ]);
+        cmd.assert().success();
+        let content = fs::read_to_string("/tmp/encoding_ascii.txt").unwrap();
+        assert!(content.is_ascii());
+        assert!(content.starts_with("+-"));
+        assert!(content.lines().nth(1).unwrap().starts_with('|'));
+        //delete output file
+        fs::remove_file("/tmp/encoding_ascii.txt").unwrap();
+    }
+}
+
+pub mod dry_run {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{path::Path, process::Command};
+
+    #[test]
+    fn arg_conflict_animate() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif")
+            .args(["--dry-run", "--animate"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--dry-run' cannot be used with '--animate'",
+        ));
+    }
+
+    #[test]
+    fn prints_summary() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--dry-run",
+            "--size",
+            "40",
+            "--no-color",
+        ]);
+        cmd.assert().success().stdout(predicate::str::diff(
+            "Input: assets/images/standard_test_img.png\nSize: 40\nTarget: Shell(false, false)\nColors: none\nColumns: 40\nRows: 13\n",
+        ));
+    }
+
+    #[test]
+    fn no_output_file() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--dry-run",
+            "-o",
+            "/tmp/dry_run.txt",
+        ]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Target: File\n"));
+        assert!(!Path::new("/tmp/dry_run.txt").exists());
+    }
+}
+
+pub mod html_color {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--html-color", "rgb"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"rgb\" isn't a valid value for '--html-color <html-color>'",
+        ));
+    }
+
+    #[test]
+    fn hsl_colors() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--html-color",
+            "hsl",
+            "-o",
+            "/tmp/html_color_hsl.html",
+        ]);
+        cmd.assert().success();
+        let content = fs::read_to_string("/tmp/html_color_hsl.html").unwrap();
+        assert!(content.contains("<span style=\"color: hsl("));
+        assert!(!content.contains("color: #"));
+        //delete output file
+        fs::remove_file("/tmp/html_color_hsl.html").unwrap();
+    }
+}
+
+pub mod html_fragment {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--html-fragment", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn only_pre() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--html-fragment",
+            "-o",
+            "/tmp/html_fragment.html",
+        ]);
+        cmd.assert().success();
+        let content = fs::read_to_string("/tmp/html_fragment.html").unwrap();
+        assert!(content.starts_with("<pre><span style=\"color: #"));
+        assert!(content.ends_with("</pre>"));
+        assert!(!content.contains("<!DOCTYPE html>"));
+        assert!(!content.contains("<head>"));
+        //delete output file
+        fs::remove_file("/tmp/html_fragment.html").unwrap();
+    }
+}
+
+pub mod legend {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--legend", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn legend_after_image() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--legend", "--characters", "MWk. "]);
+        cmd.assert().success().stdout(predicate::str::ends_with(
+            "\nLegend (densest to lightest): \"MWk. \"\n",
+        ));
+    }
+}
+
+pub mod color_map {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--color-map");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--color-map <color-map>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn entry_per_char() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--color-map", "/tmp/ascii.map"]);
+        //the ascii itself is not changed
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+
+        let content = fs::read_to_string("/tmp/ascii.map").unwrap();
+        let chars = load_correct_file()
+            .chars()
+            .filter(|char| !char.is_whitespace())
+            .count();
+        assert_eq!(chars, content.lines().count());
+        assert!(content
+            .lines()
+            .all(|line| line.split(' ').nth(2).unwrap().starts_with('#')));
+        //delete color map file
+        fs::remove_file("/tmp/ascii.map").unwrap();
+    }
+}
+
+pub mod dump_luminance {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, num::NonZeroU32, process::Command};
+
+    use artem::options::OptionBuilder;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--dump-luminance");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--dump-luminance <dump-luminance>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn pixel_per_cell() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--dump-luminance", "/tmp/luminance.pgm"]);
+        //the ascii itself is not changed
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+
+        let content = fs::read("/tmp/luminance.pgm").unwrap();
+        let correct = load_correct_file();
+        let columns = correct.lines().next().unwrap().chars().count();
+        let rows = correct.lines().count();
+        let header = format!("P5\n{columns} {rows}\n255\n");
+        assert!(content.starts_with(header.as_bytes()));
+
+        let img = image::open("assets/images/standard_test_img.png").unwrap();
+        let options = OptionBuilder::new()
+            .target_size(NonZeroU32::new(80).unwrap())
+            .build();
+        let (_, _, grid) = artem::luminance_grid(img, &options);
+        assert_eq!(grid, content[header.len()..]);
+        //delete luminance file
+        fs::remove_file("/tmp/luminance.pgm").unwrap();
+    }
+}
+
+pub mod sauce {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::{fs, process::Command};
+
+    #[test]
+    fn output_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--sauce-title", "Test"]);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--output <output-file>"));
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn record_is_appended() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "-o",
+            "/tmp/sauce.ans",
+            "--sauce-title",
+            "Test image",
+            "--sauce-author",
+            "artem",
+        ]);
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            "Written 2234 bytes to /tmp/sauce.ans",
+        ));
+        let content = fs::read("/tmp/sauce.ans").unwrap();
+        let (art, record) = content.split_at(content.len() - 128);
+        //the art is followed by the end of file character
+        assert_eq!(Some(&0x1A), art.last());
+        assert_eq!(b"SAUCE00Test image", &record[..17]);
+        assert_eq!(b"artem ", &record[42..48]);
+        //file size, data type and file type
+        assert_eq!([0x39, 0x08, 0, 0, 1, 1], record[90..96]);
+        //80 columns and 26 rows
+        assert_eq!([80, 0, 26, 0], record[96..100]);
+        //delete output file
+        fs::remove_file("/tmp/sauce.ans").unwrap();
+    }
+
+    #[test]
+    //windows does not like this test, it can not create the file
+    #[cfg(not(target_os = "windows"))]
+    fn plain_text_has_no_record() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "-o",
+            "/tmp/sauce.txt",
+            "--sauce-title",
+            "Test image",
+        ]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(
+                "Written 2105 bytes to /tmp/sauce.txt",
+            ))
+            .stderr(predicate::str::contains(
+                "Only .ans files support SAUCE records",
+            ));
+        //delete output file
+        fs::remove_file("/tmp/sauce.txt").unwrap();
+    }
+}
+
+pub mod archive {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    #[cfg(feature = "archive")]
+    fn entry_for_every_frame() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif").args([
+            "--animate",
+            "-o",
+            "/tmp/artem_frames.zip",
+        ]);
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            "Written 3 frames to /tmp/artem_frames.zip",
+        ));
+
+        let file = std::fs::File::open("/tmp/artem_frames.zip").unwrap();
+        let archive = zip::ZipArchive::new(file).unwrap();
+        assert_eq!(
+            vec!["frame_0001.txt", "frame_0002.txt", "frame_0003.txt"],
+            archive.file_names().collect::<Vec<_>>()
+        );
+        //delete output file
+        std::fs::remove_file("/tmp/artem_frames.zip").unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "archive")]
+    fn entries_use_inner_extension() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif").args([
+            "--animate",
+            "-o",
+            "/tmp/artem_frames.html.zip",
+        ]);
+        cmd.assert().success();
+
+        let file = std::fs::File::open("/tmp/artem_frames.html.zip").unwrap();
+        let archive = zip::ZipArchive::new(file).unwrap();
+        assert_eq!(3, archive.len());
+        assert!(archive.file_names().all(|name| name.ends_with(".html")));
+        //delete output file
+        std::fs::remove_file("/tmp/artem_frames.html.zip").unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "archive")]
+    fn requires_animate() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["-o", "/tmp/artem_no_frames.zip"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Zip archives are only supported for animations, use --animate",
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "archive"))]
+    fn requires_feature() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/animated_test_img.gif").args([
+            "--animate",
+            "-o",
+            "/tmp/artem_frames.zip",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Zip archives are only supported with the archive feature",
+        ));
+    }
+}
+
+pub mod clipboard {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_conflicts_output() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--clipboard",
+            "-o",
+            "/tmp/artem_clipboard.txt",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--clipboard' cannot be used with '--output <output-file>'",
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "clipboard", target_os = "linux"))]
+    fn headless_fails() {
+        //without a display server, there is no clipboard
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--clipboard")
+            .env_remove("DISPLAY")
+            .env_remove("WAYLAND_DISPLAY");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "[ERROR] Could not copy to the clipboard",
+            ))
+            .stderr(predicate::str::contains("Artem exited with code: 69"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "clipboard"))]
+    fn requires_feature() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--clipboard");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Copying to the clipboard is only supported with the clipboard feature",
+        ));
+    }
+}
@@ -69,3 +69,60 @@ pub mod scale {
         ));
     }
 }
+
+pub mod auto_ratio {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--auto-ratio", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--auto-ratio");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        //the fixed ratio only uses 26 rows, since the tile height is rounded down
+        assert_eq!(80, output.lines().next().unwrap().chars().count());
+        assert_eq!(28, output.lines().count());
+    }
+}
+
+pub mod square_pixels {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_conflict_ratio() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--square-pixels", "--ratio", "0.5"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--square-pixels' cannot be used with '--ratio <scale>'",
+        ));
+    }
+
+    #[test]
+    fn not_interactive_default_ratio() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //the output is not a terminal, so the default ratio is used
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--square-pixels");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
@@ -130,6 +130,18 @@ pub mod width {
         ));
     }
 
+    #[test]
+    fn env_fallback() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.env("COLUMNS", "100")
+            .arg("assets/images/standard_test_img.png")
+            .arg("--width");
+        //the width is read from the environment, since STDOUT is not a tty
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.lines().all(|line| line.chars().count() == 100));
+    }
+
     #[test]
     #[should_panic]
     fn arg_is_correct() {
@@ -194,3 +206,336 @@ pub mod height {
         ));
     }
 }
+
+pub mod fit_terminal {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--fit-terminal", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_size() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--fit-terminal")
+            .args(["-s", "75"]);
+        //should panic when trying using both args
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--fit-terminal' cannot be used with '--size <size>'",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_width() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--fit-terminal")
+            .arg("-w");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--fit-terminal' cannot be used with '--width'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.env_remove("COLUMNS")
+            .env_remove("LINES")
+            .arg("assets/images/standard_test_img.png")
+            .arg("--fit-terminal");
+        //fails in the test case, since STDOUT is not a tty
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] Failed to read terminal size, STDOUT is not a tty\n[ERROR] Artem exited with code: 72\n",
+        ));
+    }
+
+    #[test]
+    fn env_fallback() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.env("COLUMNS", "200")
+            .env("LINES", "20")
+            .arg("assets/images/standard_test_img.png")
+            .arg("--fit-terminal");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        //the height is more constraining
+        assert!(output.lines().count() < 20);
+    }
+}
+
+pub mod max_width {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--max-width");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--max-width <max-width>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-width", "string"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with max width input value",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_size() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-width", "75"])
+            .args(["-s", "75"]);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-width", "75"]);
+        //same as using the size, since the height is not capped
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            ":::::::::dOOOOOOOkkkkkkkkxdddddddoooooooo:................       ':::::::::",
+        ));
+    }
+
+    #[test]
+    fn arg_below_min_size() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-width", "10"]);
+        //the cap is not raised to the minimum size
+        cmd.assert()
+            .success()
+            .stdout(predicate::function(|output: &str| {
+                output.lines().all(|line| line.chars().count() <= 10)
+            }));
+    }
+}
+
+pub mod max_height {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--max-height");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--max-height <max-height>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-height", "string"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with max height input value",
+        ));
+    }
+
+    #[test]
+    fn fits_into_both_caps() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--max-width",
+            "200",
+            "--max-height",
+            "30",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        //the height is more constraining
+        assert_eq!(30, output.lines().count());
+        assert!(output.lines().all(|line| line.chars().count() <= 200));
+    }
+}
+
+pub mod columns_rows {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--columns",
+            "string",
+            "--rows",
+            "24",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with columns input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_zero() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--columns", "80", "--rows", "0"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with rows input value",
+        ));
+    }
+
+    #[test]
+    fn columns_requires_rows() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--columns", "80"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The following required arguments were not provided",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_size() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--columns",
+            "80",
+            "--rows",
+            "24",
+            "--size",
+            "50",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--columns <columns>' cannot be used with '--size <size>'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--columns",
+            "80",
+            "--rows",
+            "24",
+            "--no-color",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(24, output.lines().count());
+        assert!(output.lines().all(|line| line.chars().count() == 80));
+    }
+
+    #[test]
+    fn larger_than_image() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--columns",
+            "1000",
+            "--rows",
+            "1000",
+            "--no-color",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(1000, output.lines().count());
+        assert!(output.lines().all(|line| line.chars().count() == 1000));
+    }
+}
+
+pub mod max_display_width {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-display-width", "string"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with max display width input value",
+        ));
+    }
+
+    #[test]
+    fn narrow_output_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--max-display-width", "100"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn wide_output_is_shrunk() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--size",
+            "120",
+            "--border",
+            "--max-display-width",
+            "60",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.lines().all(|line| line.chars().count() == 60));
+    }
+
+    #[test]
+    fn no_wrap_warn_keeps_size() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--size",
+            "120",
+            "--max-display-width",
+            "60",
+            "--no-wrap-warn",
+        ]);
+        let output = cmd
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("120 columns wide"))
+            .get_output()
+            .stdout
+            .clone();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.lines().all(|line| line.chars().count() == 120));
+    }
+
+    #[test]
+    fn no_wrap_warn_requires_max_display_width() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--no-wrap-warn");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--max-display-width"));
+    }
+}
@@ -52,6 +52,42 @@ pub mod flip_y {
     }
 }
 
+pub mod transpose {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--transpose", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--transpose");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+
+        //the rows and columns are swapped
+        let correct = load_correct_file();
+        let columns = correct.lines().next().unwrap().chars().count();
+        assert_eq!(columns, output.lines().count());
+        assert_eq!(
+            correct.lines().count(),
+            output.lines().next().unwrap().chars().count()
+        );
+    }
+}
+
 pub mod flip_x_y {
     use assert_cmd::prelude::*;
     use predicates::prelude::*;
@@ -136,6 +172,248 @@ pub mod hysteresis {
     }
 }
 
+pub mod outline_normalize {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn outline_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--outline-normalize");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--outline"));
+    }
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--outline",
+            "--outline-normalize",
+            "123",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn uses_more_characters() {
+        //a faint vertical edge between two similar grays
+        image::GrayImage::from_fn(200, 100, |x, _| {
+            if x < 100 {
+                image::Luma([100])
+            } else {
+                image::Luma([110])
+            }
+        })
+        .save("/tmp/artem_faint_edge.png")
+        .unwrap();
+
+        //the characters are ordered from the densest to the lightest, so the strongest edge uses the smallest one
+        let strongest_char = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("artem").unwrap();
+            cmd.arg("/tmp/artem_faint_edge.png")
+                .args([
+                    "--outline",
+                    "--no-color",
+                    "--characters",
+                    "ABCDEFGHIJKLMNOP ",
+                ])
+                .args(args);
+            let output = cmd.assert().success().get_output().stdout.clone();
+            String::from_utf8(output)
+                .unwrap()
+                .chars()
+                .filter(char::is_ascii_uppercase)
+                .min()
+        };
+        let outline = strongest_char(&[]);
+        let normalized = strongest_char(&["--outline-normalize"]);
+        //delete the image
+        std::fs::remove_file("/tmp/artem_faint_edge.png").unwrap();
+        assert!(outline > normalized);
+    }
+}
+
+pub mod hysteresis_gamma {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn hysteresis_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--outline",
+            "--hysteresis-gamma",
+            "2.2",
+        ]);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--hysteresis"));
+    }
+
+    #[test]
+    fn arg_is_zero() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--outline",
+            "--hys",
+            "--hysteresis-gamma",
+            "0",
+        ]);
+        //the hysteresis warning is printed before the error
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not work with hysteresis gamma input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_one() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--outline",
+            "--hys",
+            "--hysteresis-gamma",
+            "1.0",
+        ]);
+        //a gamma of 1.0 does not change the outline
+        cmd.assert().success().stdout(predicate::str::starts_with(
+            "         ll                O               ;x                O       ll         ",
+        ));
+    }
+}
+
+pub mod sharpen {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--sharpen");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--sharpen <sharpen>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_nan() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--sharpen", "string"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with sharpen input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_zero() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--sharpen", "0"]);
+        //no sharpening, so the result is the same
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--sharpen", "2"]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod kernel_size {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--kernel-size");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "The argument '--kernel-size <kernel-size>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_even() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--kernel-size", "4"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Could not work with kernel size input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--kernel-size",
+            "5",
+            "--sharpen",
+            "2",
+        ]);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod smooth {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--smooth", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--smooth");
+        //the hard edges of the test image are softened
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
 pub mod border {
     use assert_cmd::prelude::*;
     use predicates::prelude::*;
@@ -223,3 +501,482 @@ pub mod center_y {
             .stdout(predicate::str::contains("\n"));
     }
 }
+
+pub mod align_x {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--align-x");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--align-x <align-x>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_invalid_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--align-x", "middle"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"middle\" isn't a valid value for '--align-x <align-x>'",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_center_x() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--align-x", "end", "--centerX"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--align-x <align-x>' cannot be used with '--centerX'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--align-x", "end"]);
+
+        //this is more or less a placeholder test, since the terminal size can and will be different during tests
+        cmd.assert().success();
+    }
+}
+
+pub mod align_y {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--align-y");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--align-y <align-y>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_conflict_center_y() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--align-y", "end", "--centerY"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--align-y <align-y>' cannot be used with '--centerY'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--align-y", "end"]);
+
+        //this is more or less a placeholder test, since the terminal size can and will be different during tests
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("\n"));
+    }
+}
+
+pub mod tone_map {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--tone-map", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--tone-map");
+        //the highlights use different characters
+        let output = cmd.assert().success().get_output().stdout.clone();
+        assert_ne!(load_correct_file(), String::from_utf8(output).unwrap());
+    }
+}
+
+pub mod equalize {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--equalize", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--equalize");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        assert_ne!(load_correct_file(), String::from_utf8(output).unwrap());
+    }
+}
+
+pub mod min_contrast {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_is_none() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--min-contrast");
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--min-contrast <min-contrast>' requires a value but none was supplied",
+        ));
+    }
+
+    #[test]
+    fn arg_is_larger_max() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--min-contrast", "256"]);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "[ERROR] Could not work with min contrast input value",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--min-contrast", "64"]);
+        //the test image already uses the full range
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod color_border {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn border_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--color-border");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--border"));
+    }
+
+    #[test]
+    fn arg_conflict_no_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args([
+            "--border",
+            "--color-border",
+            "--no-color",
+        ]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--color-border' cannot be used with '--no-color'",
+        ));
+    }
+
+    #[test]
+    fn border_has_color() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        //force colors, since the output is not a terminal
+        cmd.env("CLICOLOR_FORCE", "1")
+            .arg("assets/images/standard_test_img.png")
+            .args(["--border", "--color-border"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        //the border chars are wrapped in color sequences
+        assert!(output.starts_with("\x1b["));
+        assert!(output.contains("╔\x1b[0m"));
+        assert!(output.contains("║\x1b[0m\n"));
+    }
+}
+
+pub mod border_outside {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    /// Return the width of the first line of the output in chars.
+    fn first_line_width(args: &[&str]) -> usize {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--size", "20"])
+            .args(args);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let output = String::from_utf8(output).unwrap();
+        output.lines().next().unwrap().chars().count()
+    }
+
+    #[test]
+    fn border_is_required() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--border-outside");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::starts_with(
+                "error: The following required arguments were not provided:",
+            ))
+            .stderr(predicate::str::contains("--border"));
+    }
+
+    #[test]
+    fn border_inside_size() {
+        assert_eq!(20, first_line_width(&["--border"]));
+    }
+
+    #[test]
+    fn border_outside_size() {
+        assert_eq!(22, first_line_width(&["--border", "--border-outside"]));
+    }
+}
+
+pub mod tile {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--tile", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn large_image_unchanged() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").arg("--tile");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()));
+    }
+}
+
+pub mod linear_downscale {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--linear-downscale", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--linear-downscale");
+        //the edges between the bars are averaged brighter
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod pixel_art {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--pixel-art", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflicts_linear_downscale() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--pixel-art", "--linear-downscale"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--pixel-art' cannot be used with '--linear-downscale'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--pixel-art");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod dither {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    use crate::common::load_correct_file;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--dither", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn arg_conflicts_outline() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--dither", "--outline"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: The argument '--dither' cannot be used with '--outline'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .arg("--dither");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(load_correct_file()).not());
+    }
+}
+
+pub mod edge_mode {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_is_invalid() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--edge-mode", "wrap"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "error: \"wrap\" isn't a valid value for '--edge-mode <edge-mode>'",
+        ));
+    }
+
+    #[test]
+    fn arg_is_correct() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--edge-mode", "pad"]);
+        cmd.assert().success();
+    }
+
+    #[test]
+    fn pad_conflicts_pixel_art() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--edge-mode", "pad"])
+            .arg("--pixel-art");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Padding the edges can not be combined with pixel art or linear downscaling",
+        ));
+    }
+}
+
+pub mod auto_rotate {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn arg_with_value() {
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(["--auto-rotate", "123"]);
+        cmd.assert().failure().stderr(predicate::str::starts_with(
+            "[ERROR] File 123 does not exist\n[ERROR] Artem exited with code: 66\n",
+        ));
+    }
+
+    #[test]
+    fn landscape_in_wide_area() {
+        let args = ["--max-width", "200", "--max-height", "40"];
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args(args);
+        let expected = cmd.assert().success().get_output().stdout.clone();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(args)
+            .arg("--auto-rotate");
+        cmd.assert().success().stdout(expected);
+    }
+
+    #[test]
+    fn landscape_in_high_area() {
+        let args = ["--max-width", "20", "--max-height", "100"];
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png").args(args);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let rows = String::from_utf8(output).unwrap().lines().count();
+
+        let mut cmd = Command::cargo_bin("artem").unwrap();
+        cmd.arg("assets/images/standard_test_img.png")
+            .args(args)
+            .arg("--auto-rotate");
+        let output = cmd.assert().success().get_output().stdout.clone();
+        //the rotated image uses more of the height
+        assert!(String::from_utf8(output).unwrap().lines().count() > rows);
+    }
+}